@@ -1,36 +1,164 @@
 import {PublicKey} from "@solana/web3.js";
-import {BN} from "@coral-xyz/anchor";
+import {createHash} from "crypto";
 
-export const deriveGameStateSeedPDA = (programId: PublicKey): [PublicKey, number] => {
+export const DELEGATION_PROGRAM_ID = new PublicKey("DELeGGvXpWV2fqJUhqcF5ZSYMS4JTLjteaAMARRSaeSh");
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+const u32Le = (value: number): Buffer => {
+    const buffer = Buffer.alloc(4);
+    buffer.writeUInt32LE(value);
+    return buffer;
+};
+
+// Name claims are keyed by the hashed lowercase name, see name_registry_seed
+export const nameRegistrySeed = (name: string): Buffer =>
+    createHash("sha256").update(name.toLowerCase(), "utf8").digest();
+
+export const deriveConfigSeedPDA = (programId: PublicKey): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("config"),
+        ],
+        programId,
+    )
+};
+
+export const deriveLeaderboardSeedPDA = (programId: PublicKey): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("leaderboard"),
+        ],
+        programId,
+    )
+};
+
+export const deriveRatingLeaderboardSeedPDA = (programId: PublicKey): [PublicKey, number] => {
     return PublicKey.findProgramAddressSync(
         [
-            Buffer.from("game_state"),
+            Buffer.from("rating_leaderboard"),
+        ],
+        programId,
+    )
+};
+
+export type SeasonLeaderboardSeedSeeds = {
+    season: number,
+};
+
+export const deriveSeasonLeaderboardSeedPDA = (
+    seeds: SeasonLeaderboardSeedSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("season_leaderboard"),
+            u32Le(seeds.season),
+        ],
+        programId,
+    )
+};
+
+export const deriveQuestionBankSeedPDA = (programId: PublicKey): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("question_bank"),
+        ],
+        programId,
+    )
+};
+
+export type UserProfileSeedSeeds = {
+    owner: PublicKey,
+};
+
+export const deriveUserProfileSeedPDA = (
+    seeds: UserProfileSeedSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("user_profile"),
+            seeds.owner.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type UserAchievementsSeedSeeds = {
+    owner: PublicKey,
+};
+
+export const deriveUserAchievementsSeedPDA = (
+    seeds: UserAchievementsSeedSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("user_achievements"),
+            seeds.owner.toBuffer(),
         ],
         programId,
     )
 };
 
 export type WarriorSeedSeeds = {
-    owner: PublicKey, 
-    name: string, 
+    creator: PublicKey,
+    warriorIndex: number,
 };
 
+// warriorIndex is the creator's UserProfile.next_warrior_index before creation, 0 without a profile
 export const deriveWarriorSeedPDA = (
     seeds: WarriorSeedSeeds,
     programId: PublicKey
 ): [PublicKey, number] => {
     return PublicKey.findProgramAddressSync(
         [
-            Buffer.from("warrior"),
+            Buffer.from("undead_warrior"),
+            seeds.creator.toBuffer(),
+            u32Le(seeds.warriorIndex),
+        ],
+        programId,
+    )
+};
+
+export type NameRegistrySeedSeeds = {
+    name: string,
+};
+
+export const deriveNameRegistrySeedPDA = (
+    seeds: NameRegistrySeedSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("name_registry"),
+            nameRegistrySeed(seeds.name),
+        ],
+        programId,
+    )
+};
+
+export type WarriorLookupSeedSeeds = {
+    owner: PublicKey,
+    warrior: PublicKey,
+};
+
+export const deriveWarriorLookupSeedPDA = (
+    seeds: WarriorLookupSeedSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("warrior_lookup"),
             seeds.owner.toBuffer(),
-            Buffer.from(seeds.name, "utf8"),
+            seeds.warrior.toBuffer(),
         ],
         programId,
     )
 };
 
 export type BattleRoomSeedSeeds = {
-    roomId: string, 
+    roomId: Array<number>,
 };
 
 export const deriveBattleRoomSeedPDA = (
@@ -39,10 +167,192 @@ export const deriveBattleRoomSeedPDA = (
 ): [PublicKey, number] => {
     return PublicKey.findProgramAddressSync(
         [
-            Buffer.from("battle_room"),
-            Buffer.from(seeds.roomId, "utf8"),
+            Buffer.from("battleroom"),
+            Buffer.from(seeds.roomId),
+        ],
+        programId,
+    )
+};
+
+export type WagerEscrowSeedSeeds = {
+    roomId: Array<number>,
+};
+
+export const deriveWagerEscrowSeedPDA = (
+    seeds: WagerEscrowSeedSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("wager_escrow"),
+            Buffer.from(seeds.roomId),
+        ],
+        programId,
+    )
+};
+
+export type WagerVaultSeedSeeds = {
+    roomId: Array<number>,
+};
+
+export const deriveWagerVaultSeedPDA = (
+    seeds: WagerVaultSeedSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("wager_vault"),
+            Buffer.from(seeds.roomId),
+        ],
+        programId,
+    )
+};
+
+export const deriveMatchQueueSeedPDA = (programId: PublicKey): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("match_queue"),
+        ],
+        programId,
+    )
+};
+
+export type BattleHistorySeedSeeds = {
+    warrior: PublicKey,
+};
+
+export const deriveBattleHistorySeedPDA = (
+    seeds: BattleHistorySeedSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("battle_history"),
+            seeds.warrior.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type GraveyardSeedSeeds = {
+    owner: PublicKey,
+};
+
+export const deriveGraveyardSeedPDA = (
+    seeds: GraveyardSeedSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("graveyard"),
+            seeds.owner.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type WarriorNftMintSeedSeeds = {
+    warrior: PublicKey,
+};
+
+export const deriveWarriorNftMintSeedPDA = (
+    seeds: WarriorNftMintSeedSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("warrior_nft_mint"),
+            seeds.warrior.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export const deriveNftAuthoritySeedPDA = (programId: PublicKey): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("nft_authority"),
         ],
         programId,
     )
 };
 
+export type NftMetadataSeedSeeds = {
+    mint: PublicKey,
+};
+
+export const deriveNftMetadataSeedPDA = (
+    seeds: NftMetadataSeedSeeds,
+    programId: PublicKey = TOKEN_METADATA_PROGRAM_ID
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("metadata"),
+            programId.toBuffer(),
+            seeds.mint.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export const deriveProgramIdentitySeedPDA = (programId: PublicKey): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("identity"),
+        ],
+        programId,
+    )
+};
+
+export const deriveEventAuthoritySeedPDA = (programId: PublicKey): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("__event_authority"),
+        ],
+        programId,
+    )
+};
+
+export type DelegatedAccountSeedSeeds = {
+    account: PublicKey,
+};
+
+// buffer used while delegating, owned by the delegating program
+export const deriveDelegateBufferSeedPDA = (
+    seeds: DelegatedAccountSeedSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("buffer"),
+            seeds.account.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export const deriveDelegationRecordSeedPDA = (
+    seeds: DelegatedAccountSeedSeeds,
+    programId: PublicKey = DELEGATION_PROGRAM_ID
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("delegation"),
+            seeds.account.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export const deriveDelegationMetadataSeedPDA = (
+    seeds: DelegatedAccountSeedSeeds,
+    programId: PublicKey = DELEGATION_PROGRAM_ID
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("delegation-metadata"),
+            seeds.account.toBuffer(),
+        ],
+        programId,
+    )
+};
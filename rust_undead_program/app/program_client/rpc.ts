@@ -2,6 +2,7 @@ import {
   AnchorProvider,
   BN,
   IdlAccounts,
+  IdlTypes,
   Program,
   web3,
 } from "@coral-xyz/anchor";
@@ -10,106 +11,3490 @@ import { RustUndead } from "../../target/types/rust_undead";
 import idl from "../../target/idl/rust_undead.json";
 import * as pda from "./pda";
 
+export const TOKEN_PROGRAM_ID = new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+export const ASSOCIATED_TOKEN_PROGRAM_ID = new web3.PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+export const VRF_PROGRAM_ID = new web3.PublicKey("Vrf1RNUjXmQGjmQrQLvJHs9SNkvDJEsRVFPkfSQUwGz");
+export const ORACLE_QUEUE = new web3.PublicKey("Cuj97ggrhhidhbu39TijNVqE74xvKJ69gDervRUXAxGh");
+export const MAGIC_PROGRAM_ID = new web3.PublicKey("Magic11111111111111111111111111111111111111");
+export const MAGIC_CONTEXT_ID = new web3.PublicKey("MagicContext1111111111111111111111111111111");
 
+let _program: Program<RustUndead>;
+
+
+export const initializeClient = (
+    anchorProvider = AnchorProvider.env(),
+) => {
+    _program = new Program<RustUndead>(
+        idl as never,
+        anchorProvider,
+    );
+
+
+};
+
+export type InitializeArgs = {
+  authority: web3.PublicKey;
+  cooldownTime: bigint;
+  questionAuthority: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Initialize the game config and its leaderboards
+ *
+ * Accounts:
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ * 2. `[writable]` leaderboard: {@link Leaderboard}
+ * 3. `[writable]` rating_leaderboard: {@link Leaderboard}
+ * 4. `[writable]` season_leaderboard: {@link Leaderboard}
+ * 5. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - cooldown_time: {@link BigInt}
+ * - question_authority: {@link PublicKey}
+ */
+export const initializeBuilder = (
+	args: InitializeArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const leaderboardPubkey = pda.deriveLeaderboardSeedPDA(_program.programId)[0];
+  const ratingLeaderboardPubkey = pda.deriveRatingLeaderboardSeedPDA(_program.programId)[0];
+  const seasonLeaderboardPubkey = pda.deriveSeasonLeaderboardSeedPDA({ season: 1 }, _program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .initialize(
+      new BN(args.cooldownTime.toString()),
+      args.questionAuthority,
+    )
+    .accountsStrict({
+      authority: args.authority,
+      config: configPubkey,
+      leaderboard: leaderboardPubkey,
+      ratingLeaderboard: ratingLeaderboardPubkey,
+      seasonLeaderboard: seasonLeaderboardPubkey,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Initialize the game config and its leaderboards
+ *
+ * Accounts:
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ * 2. `[writable]` leaderboard: {@link Leaderboard}
+ * 3. `[writable]` rating_leaderboard: {@link Leaderboard}
+ * 4. `[writable]` season_leaderboard: {@link Leaderboard}
+ * 5. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - cooldown_time: {@link BigInt}
+ * - question_authority: {@link PublicKey}
+ */
+export const initialize = (
+	args: InitializeArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    initializeBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Initialize the game config and its leaderboards
+ *
+ * Accounts:
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ * 2. `[writable]` leaderboard: {@link Leaderboard}
+ * 3. `[writable]` rating_leaderboard: {@link Leaderboard}
+ * 4. `[writable]` season_leaderboard: {@link Leaderboard}
+ * 5. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - cooldown_time: {@link BigInt}
+ * - question_authority: {@link PublicKey}
+ */
+export const initializeSendAndConfirm = async (
+  args: Omit<InitializeArgs, "authority"> & {
+    signers: {
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return initializeBuilder({
+      ...args,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.authority])
+    .rpc();
+}
+
+export type PauseGameArgs = {
+  admin: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Pause the game, blocking new warriors, rooms and battles
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ */
+export const pauseGameBuilder = (
+	args: PauseGameArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+
+  return _program
+    .methods
+    .pauseGame()
+    .accountsStrict({
+      admin: args.admin,
+      config: configPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Pause the game, blocking new warriors, rooms and battles
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ */
+export const pauseGame = (
+	args: PauseGameArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    pauseGameBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Pause the game, blocking new warriors, rooms and battles
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ */
+export const pauseGameSendAndConfirm = async (
+  args: Omit<PauseGameArgs, "admin"> & {
+    signers: {
+      admin: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return pauseGameBuilder({
+      ...args,
+      admin: args.signers.admin.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.admin])
+    .rpc();
+}
+
+export type UnpauseGameArgs = {
+  admin: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Resume a paused game
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ */
+export const unpauseGameBuilder = (
+	args: UnpauseGameArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+
+  return _program
+    .methods
+    .unpauseGame()
+    .accountsStrict({
+      admin: args.admin,
+      config: configPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Resume a paused game
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ */
+export const unpauseGame = (
+	args: UnpauseGameArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    unpauseGameBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Resume a paused game
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ */
+export const unpauseGameSendAndConfirm = async (
+  args: Omit<UnpauseGameArgs, "admin"> & {
+    signers: {
+      admin: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return unpauseGameBuilder({
+      ...args,
+      admin: args.signers.admin.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.admin])
+    .rpc();
+}
+
+export type ProposeAdminArgs = {
+  admin: web3.PublicKey;
+  newAdmin: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Propose a new admin, who has to accept before taking over
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ *
+ * Data:
+ * - new_admin: {@link PublicKey}
+ */
+export const proposeAdminBuilder = (
+	args: ProposeAdminArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+
+  return _program
+    .methods
+    .proposeAdmin(
+      args.newAdmin,
+    )
+    .accountsStrict({
+      admin: args.admin,
+      config: configPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Propose a new admin, who has to accept before taking over
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ *
+ * Data:
+ * - new_admin: {@link PublicKey}
+ */
+export const proposeAdmin = (
+	args: ProposeAdminArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    proposeAdminBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Propose a new admin, who has to accept before taking over
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ *
+ * Data:
+ * - new_admin: {@link PublicKey}
+ */
+export const proposeAdminSendAndConfirm = async (
+  args: Omit<ProposeAdminArgs, "admin"> & {
+    signers: {
+      admin: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return proposeAdminBuilder({
+      ...args,
+      admin: args.signers.admin.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.admin])
+    .rpc();
+}
+
+export type AcceptAdminArgs = {
+  newAdmin: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Accept a pending admin proposal
+ *
+ * Accounts:
+ * 0. `[signer]` new_admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ */
+export const acceptAdminBuilder = (
+	args: AcceptAdminArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+
+  return _program
+    .methods
+    .acceptAdmin()
+    .accountsStrict({
+      newAdmin: args.newAdmin,
+      config: configPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Accept a pending admin proposal
+ *
+ * Accounts:
+ * 0. `[signer]` new_admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ */
+export const acceptAdmin = (
+	args: AcceptAdminArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    acceptAdminBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Accept a pending admin proposal
+ *
+ * Accounts:
+ * 0. `[signer]` new_admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ */
+export const acceptAdminSendAndConfirm = async (
+  args: Omit<AcceptAdminArgs, "newAdmin"> & {
+    signers: {
+      newAdmin: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return acceptAdminBuilder({
+      ...args,
+      newAdmin: args.signers.newAdmin.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.newAdmin])
+    .rpc();
+}
+
+export type UpdateConfigArgs = {
+  admin: web3.PublicKey;
+  update: IdlTypes<RustUndead>["configUpdate"];
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Update game tunables, unset fields are left as they are
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ *
+ * Data:
+ * - update: {@link ConfigUpdate}
+ */
+export const updateConfigBuilder = (
+	args: UpdateConfigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+
+  return _program
+    .methods
+    .updateConfig(
+      args.update,
+    )
+    .accountsStrict({
+      admin: args.admin,
+      config: configPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Update game tunables, unset fields are left as they are
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ *
+ * Data:
+ * - update: {@link ConfigUpdate}
+ */
+export const updateConfig = (
+	args: UpdateConfigArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    updateConfigBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Update game tunables, unset fields are left as they are
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ *
+ * Data:
+ * - update: {@link ConfigUpdate}
+ */
+export const updateConfigSendAndConfirm = async (
+  args: Omit<UpdateConfigArgs, "admin"> & {
+    signers: {
+      admin: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return updateConfigBuilder({
+      ...args,
+      admin: args.signers.admin.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.admin])
+    .rpc();
+}
+
+export type GrowLeaderboardArgs = {
+  payer: web3.PublicKey;
+  leaderboard: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Extend a leaderboard toward its full size
+ *
+ * Accounts:
+ * 0. `[writable, signer]` payer: {@link PublicKey}
+ * 1. `[writable]` leaderboard: {@link Leaderboard}
+ * 2. `[]` system_program: {@link PublicKey}
+ */
+export const growLeaderboardBuilder = (
+	args: GrowLeaderboardArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .growLeaderboard()
+    .accountsStrict({
+      payer: args.payer,
+      leaderboard: args.leaderboard,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Extend a leaderboard toward its full size
+ *
+ * Accounts:
+ * 0. `[writable, signer]` payer: {@link PublicKey}
+ * 1. `[writable]` leaderboard: {@link Leaderboard}
+ * 2. `[]` system_program: {@link PublicKey}
+ */
+export const growLeaderboard = (
+	args: GrowLeaderboardArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    growLeaderboardBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Extend a leaderboard toward its full size
+ *
+ * Accounts:
+ * 0. `[writable, signer]` payer: {@link PublicKey}
+ * 1. `[writable]` leaderboard: {@link Leaderboard}
+ * 2. `[]` system_program: {@link PublicKey}
+ */
+export const growLeaderboardSendAndConfirm = async (
+  args: Omit<GrowLeaderboardArgs, "payer"> & {
+    signers: {
+      payer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return growLeaderboardBuilder({
+      ...args,
+      payer: args.signers.payer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.payer])
+    .rpc();
+}
+
+export type QueryRankArgs = {
+  leaderboard: web3.PublicKey;
+  player: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Rank of a player on any leaderboard
+ *
+ * Accounts:
+ * 0. `[]` leaderboard: {@link Leaderboard}
+ *
+ * Data:
+ * - player: {@link PublicKey}
+ */
+export const queryRankBuilder = (
+	args: QueryRankArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  return _program
+    .methods
+    .queryRank(
+      args.player,
+    )
+    .accountsStrict({
+      leaderboard: args.leaderboard,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Rank of a player on any leaderboard
+ *
+ * Accounts:
+ * 0. `[]` leaderboard: {@link Leaderboard}
+ *
+ * Data:
+ * - player: {@link PublicKey}
+ */
+export const queryRank = (
+	args: QueryRankArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    queryRankBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns the simulated return value
+ * Rank of a player on any leaderboard
+ *
+ * Accounts:
+ * 0. `[]` leaderboard: {@link Leaderboard}
+ *
+ * Data:
+ * - player: {@link PublicKey}
+ */
+export const queryRankView = (
+	args: QueryRankArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<number | null> =>
+    queryRankBuilder(args, remainingAccounts).view();
+
+export type CreateSeasonLeaderboardArgs = {
+  admin: web3.PublicKey;
+  nextLeaderboard: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create the next season's board ahead of start_new_season
+ *
+ * Accounts:
+ * 0. `[writable, signer]` admin: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` next_leaderboard: {@link Leaderboard}
+ * 3. `[]` system_program: {@link PublicKey}
+ */
+export const createSeasonLeaderboardBuilder = (
+	args: CreateSeasonLeaderboardArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .createSeasonLeaderboard()
+    .accountsStrict({
+      admin: args.admin,
+      config: configPubkey,
+      nextLeaderboard: args.nextLeaderboard,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create the next season's board ahead of start_new_season
+ *
+ * Accounts:
+ * 0. `[writable, signer]` admin: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` next_leaderboard: {@link Leaderboard}
+ * 3. `[]` system_program: {@link PublicKey}
+ */
+export const createSeasonLeaderboard = (
+	args: CreateSeasonLeaderboardArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createSeasonLeaderboardBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create the next season's board ahead of start_new_season
+ *
+ * Accounts:
+ * 0. `[writable, signer]` admin: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` next_leaderboard: {@link Leaderboard}
+ * 3. `[]` system_program: {@link PublicKey}
+ */
+export const createSeasonLeaderboardSendAndConfirm = async (
+  args: Omit<CreateSeasonLeaderboardArgs, "admin"> & {
+    signers: {
+      admin: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createSeasonLeaderboardBuilder({
+      ...args,
+      admin: args.signers.admin.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.admin])
+    .rpc();
+}
+
+export type StartNewSeasonArgs = {
+  admin: web3.PublicKey;
+  previousLeaderboard: web3.PublicKey;
+  nextLeaderboard: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Archive the current season's board and open the next season
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ * 2. `[writable]` previous_leaderboard: {@link Leaderboard}
+ * 3. `[]` next_leaderboard: {@link Leaderboard}
+ */
+export const startNewSeasonBuilder = (
+	args: StartNewSeasonArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+
+  return _program
+    .methods
+    .startNewSeason()
+    .accountsStrict({
+      admin: args.admin,
+      config: configPubkey,
+      previousLeaderboard: args.previousLeaderboard,
+      nextLeaderboard: args.nextLeaderboard,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Archive the current season's board and open the next season
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ * 2. `[writable]` previous_leaderboard: {@link Leaderboard}
+ * 3. `[]` next_leaderboard: {@link Leaderboard}
+ */
+export const startNewSeason = (
+	args: StartNewSeasonArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    startNewSeasonBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Archive the current season's board and open the next season
+ *
+ * Accounts:
+ * 0. `[signer]` admin: {@link PublicKey}
+ * 1. `[writable]` config: {@link Config}
+ * 2. `[writable]` previous_leaderboard: {@link Leaderboard}
+ * 3. `[]` next_leaderboard: {@link Leaderboard}
+ */
+export const startNewSeasonSendAndConfirm = async (
+  args: Omit<StartNewSeasonArgs, "admin"> & {
+    signers: {
+      admin: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return startNewSeasonBuilder({
+      ...args,
+      admin: args.signers.admin.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.admin])
+    .rpc();
+}
+
+export type InitializeQuestionBankArgs = {
+  admin: web3.PublicKey;
+  contentAuthority: web3.PublicKey;
+  merkleRoot: Array<number>;
+  questionCount: number;
+  conceptCount: number;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create the question bank with its merkle root
+ *
+ * Accounts:
+ * 0. `[writable, signer]` admin: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` question_bank: {@link QuestionBank}
+ * 3. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - content_authority: {@link PublicKey}
+ * - merkle_root: {@link number[]}
+ * - question_count: {@link number}
+ * - concept_count: {@link number}
+ */
+export const initializeQuestionBankBuilder = (
+	args: InitializeQuestionBankArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const questionBankPubkey = pda.deriveQuestionBankSeedPDA(_program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .initializeQuestionBank(
+      args.contentAuthority,
+      args.merkleRoot,
+      args.questionCount,
+      args.conceptCount,
+    )
+    .accountsStrict({
+      admin: args.admin,
+      config: configPubkey,
+      questionBank: questionBankPubkey,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create the question bank with its merkle root
+ *
+ * Accounts:
+ * 0. `[writable, signer]` admin: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` question_bank: {@link QuestionBank}
+ * 3. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - content_authority: {@link PublicKey}
+ * - merkle_root: {@link number[]}
+ * - question_count: {@link number}
+ * - concept_count: {@link number}
+ */
+export const initializeQuestionBank = (
+	args: InitializeQuestionBankArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    initializeQuestionBankBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create the question bank with its merkle root
+ *
+ * Accounts:
+ * 0. `[writable, signer]` admin: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` question_bank: {@link QuestionBank}
+ * 3. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - content_authority: {@link PublicKey}
+ * - merkle_root: {@link number[]}
+ * - question_count: {@link number}
+ * - concept_count: {@link number}
+ */
+export const initializeQuestionBankSendAndConfirm = async (
+  args: Omit<InitializeQuestionBankArgs, "admin"> & {
+    signers: {
+      admin: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return initializeQuestionBankBuilder({
+      ...args,
+      admin: args.signers.admin.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.admin])
+    .rpc();
+}
+
+export type UpdateQuestionBankArgs = {
+  authority: web3.PublicKey;
+  merkleRoot: Array<number>;
+  questionCount: number;
+  conceptCount: number;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Publish a new question bank merkle root
+ *
+ * Accounts:
+ * 0. `[signer]` authority: {@link PublicKey}
+ * 1. `[writable]` question_bank: {@link QuestionBank}
+ *
+ * Data:
+ * - merkle_root: {@link number[]}
+ * - question_count: {@link number}
+ * - concept_count: {@link number}
+ */
+export const updateQuestionBankBuilder = (
+	args: UpdateQuestionBankArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const questionBankPubkey = pda.deriveQuestionBankSeedPDA(_program.programId)[0];
+
+  return _program
+    .methods
+    .updateQuestionBank(
+      args.merkleRoot,
+      args.questionCount,
+      args.conceptCount,
+    )
+    .accountsStrict({
+      authority: args.authority,
+      questionBank: questionBankPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Publish a new question bank merkle root
+ *
+ * Accounts:
+ * 0. `[signer]` authority: {@link PublicKey}
+ * 1. `[writable]` question_bank: {@link QuestionBank}
+ *
+ * Data:
+ * - merkle_root: {@link number[]}
+ * - question_count: {@link number}
+ * - concept_count: {@link number}
+ */
+export const updateQuestionBank = (
+	args: UpdateQuestionBankArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    updateQuestionBankBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Publish a new question bank merkle root
+ *
+ * Accounts:
+ * 0. `[signer]` authority: {@link PublicKey}
+ * 1. `[writable]` question_bank: {@link QuestionBank}
+ *
+ * Data:
+ * - merkle_root: {@link number[]}
+ * - question_count: {@link number}
+ * - concept_count: {@link number}
+ */
+export const updateQuestionBankSendAndConfirm = async (
+  args: Omit<UpdateQuestionBankArgs, "authority"> & {
+    signers: {
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return updateQuestionBankBuilder({
+      ...args,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.authority])
+    .rpc();
+}
+
+export type CreateWarriorArgs = {
+  player: web3.PublicKey;
+  warrior: web3.PublicKey;
+  name: string;
+  dna: Array<number>;
+  class: IdlTypes<RustUndead>["warriorClass"];
+  clientSeed: number;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create a warrior and claim its name
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` user_profile: {@link UserProfile}
+ * 2. `[writable]` warrior: {@link UndeadWarrior}
+ * 3. `[writable]` name_registry: {@link NameRegistry}
+ * 4. `[writable]` user_achievements: {@link UserAchievements}
+ * 5. `[]` config: {@link Config}
+ * 6. `[writable]` oracle_queue: {@link PublicKey}
+ * 7. `[]` system_program: {@link PublicKey}
+ * 8. `[]` program_identity: {@link PublicKey}
+ * 9. `[]` vrf_program: {@link PublicKey}
+ * 10. `[]` slot_hashes: {@link PublicKey}
+ *
+ * Data:
+ * - name: {@link string}
+ * - dna: {@link number[]}
+ * - class: {@link WarriorClass}
+ * - client_seed: {@link number}
+ */
+export const createWarriorBuilder = (
+	args: CreateWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const userProfilePubkey = pda.deriveUserProfileSeedPDA({ owner: args.player }, _program.programId)[0];
+  const nameRegistryPubkey = pda.deriveNameRegistrySeedPDA({ name: args.name }, _program.programId)[0];
+  const userAchievementsPubkey = pda.deriveUserAchievementsSeedPDA({ owner: args.player }, _program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const oracleQueuePubkey = ORACLE_QUEUE;
+  const systemProgramPubkey = web3.SystemProgram.programId;
+  const programIdentityPubkey = pda.deriveProgramIdentitySeedPDA(_program.programId)[0];
+  const vrfProgramPubkey = VRF_PROGRAM_ID;
+  const slotHashesPubkey = web3.SYSVAR_SLOT_HASHES_PUBKEY;
+
+  return _program
+    .methods
+    .createWarrior(
+      args.name,
+      args.dna,
+      args.class,
+      args.clientSeed,
+    )
+    .accountsStrict({
+      player: args.player,
+      userProfile: userProfilePubkey,
+      warrior: args.warrior,
+      nameRegistry: nameRegistryPubkey,
+      userAchievements: userAchievementsPubkey,
+      config: configPubkey,
+      oracleQueue: oracleQueuePubkey,
+      systemProgram: systemProgramPubkey,
+      programIdentity: programIdentityPubkey,
+      vrfProgram: vrfProgramPubkey,
+      slotHashes: slotHashesPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create a warrior and claim its name
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` user_profile: {@link UserProfile}
+ * 2. `[writable]` warrior: {@link UndeadWarrior}
+ * 3. `[writable]` name_registry: {@link NameRegistry}
+ * 4. `[writable]` user_achievements: {@link UserAchievements}
+ * 5. `[]` config: {@link Config}
+ * 6. `[writable]` oracle_queue: {@link PublicKey}
+ * 7. `[]` system_program: {@link PublicKey}
+ * 8. `[]` program_identity: {@link PublicKey}
+ * 9. `[]` vrf_program: {@link PublicKey}
+ * 10. `[]` slot_hashes: {@link PublicKey}
+ *
+ * Data:
+ * - name: {@link string}
+ * - dna: {@link number[]}
+ * - class: {@link WarriorClass}
+ * - client_seed: {@link number}
+ */
+export const createWarrior = (
+	args: CreateWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createWarriorBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create a warrior and claim its name
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` user_profile: {@link UserProfile}
+ * 2. `[writable]` warrior: {@link UndeadWarrior}
+ * 3. `[writable]` name_registry: {@link NameRegistry}
+ * 4. `[writable]` user_achievements: {@link UserAchievements}
+ * 5. `[]` config: {@link Config}
+ * 6. `[writable]` oracle_queue: {@link PublicKey}
+ * 7. `[]` system_program: {@link PublicKey}
+ * 8. `[]` program_identity: {@link PublicKey}
+ * 9. `[]` vrf_program: {@link PublicKey}
+ * 10. `[]` slot_hashes: {@link PublicKey}
+ *
+ * Data:
+ * - name: {@link string}
+ * - dna: {@link number[]}
+ * - class: {@link WarriorClass}
+ * - client_seed: {@link number}
+ */
+export const createWarriorSendAndConfirm = async (
+  args: Omit<CreateWarriorArgs, "player"> & {
+    signers: {
+      player: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createWarriorBuilder({
+      ...args,
+      player: args.signers.player.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.player])
+    .rpc();
+}
+
+export type HealWarriorArgs = {
+  owner: web3.PublicKey;
+  warrior: web3.PublicKey;
+  warriorNftAccount?: web3.PublicKey;
+  treasury: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Restore a warrior to full HP
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[]` config: {@link Config}
+ * 4. `[writable]` treasury: {@link PublicKey}
+ * 5. `[]` system_program: {@link PublicKey}
+ */
+export const healWarriorBuilder = (
+	args: HealWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .healWarrior()
+    .accountsStrict({
+      owner: args.owner,
+      warrior: args.warrior,
+      warriorNftAccount: args.warriorNftAccount ?? null,
+      config: configPubkey,
+      treasury: args.treasury,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Restore a warrior to full HP
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[]` config: {@link Config}
+ * 4. `[writable]` treasury: {@link PublicKey}
+ * 5. `[]` system_program: {@link PublicKey}
+ */
+export const healWarrior = (
+	args: HealWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    healWarriorBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Restore a warrior to full HP
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[]` config: {@link Config}
+ * 4. `[writable]` treasury: {@link PublicKey}
+ * 5. `[]` system_program: {@link PublicKey}
+ */
+export const healWarriorSendAndConfirm = async (
+  args: Omit<HealWarriorArgs, "owner"> & {
+    signers: {
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return healWarriorBuilder({
+      ...args,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.owner])
+    .rpc();
+}
+
+export type MintWarriorNftArgs = {
+  owner: web3.PublicKey;
+  warrior: web3.PublicKey;
+  ownerTokenAccount: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Mint the warrior as an NFT once its stats and image are final
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[writable]` nft_mint: {@link PublicKey}
+ * 3. `[]` nft_authority: {@link PublicKey}
+ * 4. `[writable]` owner_token_account: {@link PublicKey}
+ * 5. `[writable]` metadata: {@link PublicKey}
+ * 6. `[]` config: {@link Config}
+ * 7. `[]` token_program: {@link PublicKey}
+ * 8. `[]` token_metadata_program: {@link PublicKey}
+ * 9. `[]` associated_token_program: {@link PublicKey}
+ * 10. `[]` system_program: {@link PublicKey}
+ */
+export const mintWarriorNftBuilder = (
+	args: MintWarriorNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const nftMintPubkey = pda.deriveWarriorNftMintSeedPDA({ warrior: args.warrior }, _program.programId)[0];
+  const nftAuthorityPubkey = pda.deriveNftAuthoritySeedPDA(_program.programId)[0];
+  const metadataPubkey = pda.deriveNftMetadataSeedPDA({ mint: nftMintPubkey })[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const tokenProgramPubkey = TOKEN_PROGRAM_ID;
+  const tokenMetadataProgramPubkey = pda.TOKEN_METADATA_PROGRAM_ID;
+  const associatedTokenProgramPubkey = ASSOCIATED_TOKEN_PROGRAM_ID;
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .mintWarriorNft()
+    .accountsStrict({
+      owner: args.owner,
+      warrior: args.warrior,
+      nftMint: nftMintPubkey,
+      nftAuthority: nftAuthorityPubkey,
+      ownerTokenAccount: args.ownerTokenAccount,
+      metadata: metadataPubkey,
+      config: configPubkey,
+      tokenProgram: tokenProgramPubkey,
+      tokenMetadataProgram: tokenMetadataProgramPubkey,
+      associatedTokenProgram: associatedTokenProgramPubkey,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Mint the warrior as an NFT once its stats and image are final
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[writable]` nft_mint: {@link PublicKey}
+ * 3. `[]` nft_authority: {@link PublicKey}
+ * 4. `[writable]` owner_token_account: {@link PublicKey}
+ * 5. `[writable]` metadata: {@link PublicKey}
+ * 6. `[]` config: {@link Config}
+ * 7. `[]` token_program: {@link PublicKey}
+ * 8. `[]` token_metadata_program: {@link PublicKey}
+ * 9. `[]` associated_token_program: {@link PublicKey}
+ * 10. `[]` system_program: {@link PublicKey}
+ */
+export const mintWarriorNft = (
+	args: MintWarriorNftArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    mintWarriorNftBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Mint the warrior as an NFT once its stats and image are final
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[writable]` nft_mint: {@link PublicKey}
+ * 3. `[]` nft_authority: {@link PublicKey}
+ * 4. `[writable]` owner_token_account: {@link PublicKey}
+ * 5. `[writable]` metadata: {@link PublicKey}
+ * 6. `[]` config: {@link Config}
+ * 7. `[]` token_program: {@link PublicKey}
+ * 8. `[]` token_metadata_program: {@link PublicKey}
+ * 9. `[]` associated_token_program: {@link PublicKey}
+ * 10. `[]` system_program: {@link PublicKey}
+ */
+export const mintWarriorNftSendAndConfirm = async (
+  args: Omit<MintWarriorNftArgs, "owner"> & {
+    signers: {
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return mintWarriorNftBuilder({
+      ...args,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.owner])
+    .rpc();
+}
+
+export type TransferWarriorArgs = {
+  owner: web3.PublicKey;
+  warrior: web3.PublicKey;
+  previousLookup?: web3.PublicKey;
+  newLookup?: web3.PublicKey;
+  matchQueue?: web3.PublicKey;
+  newOwner: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Hand a warrior to another player outside of battle
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[writable]` previous_lookup: {@link PublicKey} (optional)
+ * 3. `[writable]` new_lookup: {@link PublicKey} (optional)
+ * 4. `[writable]` match_queue: {@link MatchQueue} (optional)
+ * 5. `[writable]` owner_profile: {@link UserProfile}
+ * 6. `[writable]` owner_achievements: {@link UserAchievements}
+ * 7. `[writable]` recipient_profile: {@link UserProfile}
+ * 8. `[writable]` recipient_achievements: {@link UserAchievements}
+ * 9. `[]` config: {@link Config}
+ * 10. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - new_owner: {@link PublicKey}
+ */
+export const transferWarriorBuilder = (
+	args: TransferWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const ownerProfilePubkey = pda.deriveUserProfileSeedPDA({ owner: args.owner }, _program.programId)[0];
+  const ownerAchievementsPubkey = pda.deriveUserAchievementsSeedPDA({ owner: args.owner }, _program.programId)[0];
+  const recipientProfilePubkey = pda.deriveUserProfileSeedPDA({ owner: args.newOwner }, _program.programId)[0];
+  const recipientAchievementsPubkey = pda.deriveUserAchievementsSeedPDA({ owner: args.newOwner }, _program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .transferWarrior(
+      args.newOwner,
+    )
+    .accountsStrict({
+      owner: args.owner,
+      warrior: args.warrior,
+      previousLookup: args.previousLookup ?? null,
+      newLookup: args.newLookup ?? null,
+      matchQueue: args.matchQueue ?? null,
+      ownerProfile: ownerProfilePubkey,
+      ownerAchievements: ownerAchievementsPubkey,
+      recipientProfile: recipientProfilePubkey,
+      recipientAchievements: recipientAchievementsPubkey,
+      config: configPubkey,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Hand a warrior to another player outside of battle
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[writable]` previous_lookup: {@link PublicKey} (optional)
+ * 3. `[writable]` new_lookup: {@link PublicKey} (optional)
+ * 4. `[writable]` match_queue: {@link MatchQueue} (optional)
+ * 5. `[writable]` owner_profile: {@link UserProfile}
+ * 6. `[writable]` owner_achievements: {@link UserAchievements}
+ * 7. `[writable]` recipient_profile: {@link UserProfile}
+ * 8. `[writable]` recipient_achievements: {@link UserAchievements}
+ * 9. `[]` config: {@link Config}
+ * 10. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - new_owner: {@link PublicKey}
+ */
+export const transferWarrior = (
+	args: TransferWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    transferWarriorBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Hand a warrior to another player outside of battle
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[writable]` previous_lookup: {@link PublicKey} (optional)
+ * 3. `[writable]` new_lookup: {@link PublicKey} (optional)
+ * 4. `[writable]` match_queue: {@link MatchQueue} (optional)
+ * 5. `[writable]` owner_profile: {@link UserProfile}
+ * 6. `[writable]` owner_achievements: {@link UserAchievements}
+ * 7. `[writable]` recipient_profile: {@link UserProfile}
+ * 8. `[writable]` recipient_achievements: {@link UserAchievements}
+ * 9. `[]` config: {@link Config}
+ * 10. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - new_owner: {@link PublicKey}
+ */
+export const transferWarriorSendAndConfirm = async (
+  args: Omit<TransferWarriorArgs, "owner"> & {
+    signers: {
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return transferWarriorBuilder({
+      ...args,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.owner])
+    .rpc();
+}
+
+export type RetireWarriorArgs = {
+  owner: web3.PublicKey;
+  warrior: web3.PublicKey;
+  warriorNftAccount?: web3.PublicKey;
+  nftMint?: web3.PublicKey;
+  nameRegistry: web3.PublicKey;
+  lookup?: web3.PublicKey;
+  matchQueue?: web3.PublicKey;
+  history?: web3.PublicKey;
+  tokenProgram?: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Close a warrior for good, leaving a memorial entry
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[writable]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` nft_mint: {@link PublicKey} (optional)
+ * 4. `[writable]` name_registry: {@link NameRegistry}
+ * 5. `[writable]` lookup: {@link PublicKey} (optional)
+ * 6. `[writable]` match_queue: {@link MatchQueue} (optional)
+ * 7. `[writable]` history: {@link WarriorHistory} (optional)
+ * 8. `[writable]` graveyard: {@link Graveyard}
+ * 9. `[]` config: {@link Config}
+ * 10. `[]` token_program: {@link PublicKey} (optional)
+ * 11. `[]` system_program: {@link PublicKey}
+ */
+export const retireWarriorBuilder = (
+	args: RetireWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const graveyardPubkey = pda.deriveGraveyardSeedPDA({ owner: args.owner }, _program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .retireWarrior()
+    .accountsStrict({
+      owner: args.owner,
+      warrior: args.warrior,
+      warriorNftAccount: args.warriorNftAccount ?? null,
+      nftMint: args.nftMint ?? null,
+      nameRegistry: args.nameRegistry,
+      lookup: args.lookup ?? null,
+      matchQueue: args.matchQueue ?? null,
+      history: args.history ?? null,
+      graveyard: graveyardPubkey,
+      config: configPubkey,
+      tokenProgram: args.tokenProgram ?? null,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Close a warrior for good, leaving a memorial entry
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[writable]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` nft_mint: {@link PublicKey} (optional)
+ * 4. `[writable]` name_registry: {@link NameRegistry}
+ * 5. `[writable]` lookup: {@link PublicKey} (optional)
+ * 6. `[writable]` match_queue: {@link MatchQueue} (optional)
+ * 7. `[writable]` history: {@link WarriorHistory} (optional)
+ * 8. `[writable]` graveyard: {@link Graveyard}
+ * 9. `[]` config: {@link Config}
+ * 10. `[]` token_program: {@link PublicKey} (optional)
+ * 11. `[]` system_program: {@link PublicKey}
+ */
+export const retireWarrior = (
+	args: RetireWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    retireWarriorBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Close a warrior for good, leaving a memorial entry
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[writable]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` nft_mint: {@link PublicKey} (optional)
+ * 4. `[writable]` name_registry: {@link NameRegistry}
+ * 5. `[writable]` lookup: {@link PublicKey} (optional)
+ * 6. `[writable]` match_queue: {@link MatchQueue} (optional)
+ * 7. `[writable]` history: {@link WarriorHistory} (optional)
+ * 8. `[writable]` graveyard: {@link Graveyard}
+ * 9. `[]` config: {@link Config}
+ * 10. `[]` token_program: {@link PublicKey} (optional)
+ * 11. `[]` system_program: {@link PublicKey}
+ */
+export const retireWarriorSendAndConfirm = async (
+  args: Omit<RetireWarriorArgs, "owner"> & {
+    signers: {
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return retireWarriorBuilder({
+      ...args,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.owner])
+    .rpc();
+}
+
+export type RenameWarriorArgs = {
+  owner: web3.PublicKey;
+  warrior: web3.PublicKey;
+  warriorNftAccount?: web3.PublicKey;
+  nameRegistry: web3.PublicKey;
+  newNameRegistry?: web3.PublicKey;
+  treasury: web3.PublicKey;
+  newName: string;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Claim a new name for a warrior, paid and rate limited
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` name_registry: {@link NameRegistry}
+ * 4. `[writable]` new_name_registry: {@link NameRegistry} (optional)
+ * 5. `[]` config: {@link Config}
+ * 6. `[writable]` treasury: {@link PublicKey}
+ * 7. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - new_name: {@link string}
+ */
+export const renameWarriorBuilder = (
+	args: RenameWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .renameWarrior(
+      args.newName,
+    )
+    .accountsStrict({
+      owner: args.owner,
+      warrior: args.warrior,
+      warriorNftAccount: args.warriorNftAccount ?? null,
+      nameRegistry: args.nameRegistry,
+      newNameRegistry: args.newNameRegistry ?? null,
+      config: configPubkey,
+      treasury: args.treasury,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Claim a new name for a warrior, paid and rate limited
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` name_registry: {@link NameRegistry}
+ * 4. `[writable]` new_name_registry: {@link NameRegistry} (optional)
+ * 5. `[]` config: {@link Config}
+ * 6. `[writable]` treasury: {@link PublicKey}
+ * 7. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - new_name: {@link string}
+ */
+export const renameWarrior = (
+	args: RenameWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    renameWarriorBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Claim a new name for a warrior, paid and rate limited
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` name_registry: {@link NameRegistry}
+ * 4. `[writable]` new_name_registry: {@link NameRegistry} (optional)
+ * 5. `[]` config: {@link Config}
+ * 6. `[writable]` treasury: {@link PublicKey}
+ * 7. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - new_name: {@link string}
+ */
+export const renameWarriorSendAndConfirm = async (
+  args: Omit<RenameWarriorArgs, "owner"> & {
+    signers: {
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return renameWarriorBuilder({
+      ...args,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.owner])
+    .rpc();
+}
+
+export type InitWarriorHistoryArgs = {
+  owner: web3.PublicKey;
+  warrior: web3.PublicKey;
+  warriorNftAccount?: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Opt-in battle log kept when rooms are closed
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` history: {@link WarriorHistory}
+ * 4. `[]` system_program: {@link PublicKey}
+ */
+export const initWarriorHistoryBuilder = (
+	args: InitWarriorHistoryArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const historyPubkey = pda.deriveBattleHistorySeedPDA({ warrior: args.warrior }, _program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .initWarriorHistory()
+    .accountsStrict({
+      owner: args.owner,
+      warrior: args.warrior,
+      warriorNftAccount: args.warriorNftAccount ?? null,
+      history: historyPubkey,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Opt-in battle log kept when rooms are closed
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` history: {@link WarriorHistory}
+ * 4. `[]` system_program: {@link PublicKey}
+ */
+export const initWarriorHistory = (
+	args: InitWarriorHistoryArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    initWarriorHistoryBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Opt-in battle log kept when rooms are closed
+ *
+ * Accounts:
+ * 0. `[writable, signer]` owner: {@link PublicKey}
+ * 1. `[]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` history: {@link WarriorHistory}
+ * 4. `[]` system_program: {@link PublicKey}
+ */
+export const initWarriorHistorySendAndConfirm = async (
+  args: Omit<InitWarriorHistoryArgs, "owner"> & {
+    signers: {
+      owner: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return initWarriorHistoryBuilder({
+      ...args,
+      owner: args.signers.owner.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.owner])
+    .rpc();
+}
+
+export type CreateBattleRoomArgs = {
+  playerA: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorNftAccount?: web3.PublicKey;
+  matchQueue?: web3.PublicKey;
+  questionAuthority: web3.PublicKey;
+  wagerEscrow?: web3.PublicKey;
+  wagerMint?: web3.PublicKey;
+  wagerVault?: web3.PublicKey;
+  playerTokenAccount?: web3.PublicKey;
+  tokenProgram?: web3.PublicKey;
+  roomId: Array<number>;
+  selectedConcepts: Array<number>;
+  selectedTopics: Buffer;
+  selectedQuestions: Array<number>;
+  answerOptions: Buffer;
+  answerCommitments: Array<Array<number>>;
+  wagerAmount: bigint;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create a battle room with its committed questions
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player_a: {@link PublicKey}
+ * 1. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` match_queue: {@link MatchQueue} (optional)
+ * 4. `[signer]` question_authority: {@link PublicKey}
+ * 5. `[]` config: {@link Config}
+ * 6. `[]` question_bank: {@link QuestionBank}
+ * 7. `[writable]` battle_room: {@link BattleRoom}
+ * 8. `[writable]` wager_escrow: {@link PublicKey} (optional)
+ * 9. `[]` wager_mint: {@link PublicKey} (optional)
+ * 10. `[writable]` wager_vault: {@link PublicKey} (optional)
+ * 11. `[writable]` player_token_account: {@link PublicKey} (optional)
+ * 12. `[]` token_program: {@link PublicKey} (optional)
+ * 13. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - selected_concepts: {@link number[]}
+ * - selected_topics: {@link Buffer}
+ * - selected_questions: {@link number[]}
+ * - answer_options: {@link Buffer}
+ * - answer_commitments: {@link number[][]}
+ * - wager_amount: {@link BigInt}
+ */
+export const createBattleRoomBuilder = (
+	args: CreateBattleRoomArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const questionBankPubkey = pda.deriveQuestionBankSeedPDA(_program.programId)[0];
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .createBattleRoom(
+      args.roomId,
+      args.selectedConcepts,
+      args.selectedTopics,
+      args.selectedQuestions,
+      args.answerOptions,
+      args.answerCommitments,
+      new BN(args.wagerAmount.toString()),
+    )
+    .accountsStrict({
+      playerA: args.playerA,
+      warriorA: args.warriorA,
+      warriorNftAccount: args.warriorNftAccount ?? null,
+      matchQueue: args.matchQueue ?? null,
+      questionAuthority: args.questionAuthority,
+      config: configPubkey,
+      questionBank: questionBankPubkey,
+      battleRoom: battleRoomPubkey,
+      wagerEscrow: args.wagerEscrow ?? null,
+      wagerMint: args.wagerMint ?? null,
+      wagerVault: args.wagerVault ?? null,
+      playerTokenAccount: args.playerTokenAccount ?? null,
+      tokenProgram: args.tokenProgram ?? null,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create a battle room with its committed questions
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player_a: {@link PublicKey}
+ * 1. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` match_queue: {@link MatchQueue} (optional)
+ * 4. `[signer]` question_authority: {@link PublicKey}
+ * 5. `[]` config: {@link Config}
+ * 6. `[]` question_bank: {@link QuestionBank}
+ * 7. `[writable]` battle_room: {@link BattleRoom}
+ * 8. `[writable]` wager_escrow: {@link PublicKey} (optional)
+ * 9. `[]` wager_mint: {@link PublicKey} (optional)
+ * 10. `[writable]` wager_vault: {@link PublicKey} (optional)
+ * 11. `[writable]` player_token_account: {@link PublicKey} (optional)
+ * 12. `[]` token_program: {@link PublicKey} (optional)
+ * 13. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - selected_concepts: {@link number[]}
+ * - selected_topics: {@link Buffer}
+ * - selected_questions: {@link number[]}
+ * - answer_options: {@link Buffer}
+ * - answer_commitments: {@link number[][]}
+ * - wager_amount: {@link BigInt}
+ */
+export const createBattleRoom = (
+	args: CreateBattleRoomArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    createBattleRoomBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create a battle room with its committed questions
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player_a: {@link PublicKey}
+ * 1. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` match_queue: {@link MatchQueue} (optional)
+ * 4. `[signer]` question_authority: {@link PublicKey}
+ * 5. `[]` config: {@link Config}
+ * 6. `[]` question_bank: {@link QuestionBank}
+ * 7. `[writable]` battle_room: {@link BattleRoom}
+ * 8. `[writable]` wager_escrow: {@link PublicKey} (optional)
+ * 9. `[]` wager_mint: {@link PublicKey} (optional)
+ * 10. `[writable]` wager_vault: {@link PublicKey} (optional)
+ * 11. `[writable]` player_token_account: {@link PublicKey} (optional)
+ * 12. `[]` token_program: {@link PublicKey} (optional)
+ * 13. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - selected_concepts: {@link number[]}
+ * - selected_topics: {@link Buffer}
+ * - selected_questions: {@link number[]}
+ * - answer_options: {@link Buffer}
+ * - answer_commitments: {@link number[][]}
+ * - wager_amount: {@link BigInt}
+ */
+export const createBattleRoomSendAndConfirm = async (
+  args: Omit<CreateBattleRoomArgs, "playerA" | "questionAuthority"> & {
+    signers: {
+      playerA: web3.Signer,
+      questionAuthority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return createBattleRoomBuilder({
+      ...args,
+      playerA: args.signers.playerA.publicKey,
+      questionAuthority: args.signers.questionAuthority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.playerA, args.signers.questionAuthority])
+    .rpc();
+}
+
+export type AssignRoomQuestionsArgs = {
+  questionAuthority: web3.PublicKey;
+  roomId: Array<number>;
+  selectedConcepts: Array<number>;
+  selectedTopics: Buffer;
+  selectedQuestions: Array<number>;
+  answerOptions: Buffer;
+  answerCommitments: Array<Array<number>>;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Question authority fills in the content of a matched room
+ *
+ * Accounts:
+ * 0. `[signer]` question_authority: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[]` question_bank: {@link QuestionBank}
+ * 3. `[writable]` battle_room: {@link BattleRoom}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - selected_concepts: {@link number[]}
+ * - selected_topics: {@link Buffer}
+ * - selected_questions: {@link number[]}
+ * - answer_options: {@link Buffer}
+ * - answer_commitments: {@link number[][]}
+ */
+export const assignRoomQuestionsBuilder = (
+	args: AssignRoomQuestionsArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const questionBankPubkey = pda.deriveQuestionBankSeedPDA(_program.programId)[0];
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+
+  return _program
+    .methods
+    .assignRoomQuestions(
+      args.roomId,
+      args.selectedConcepts,
+      args.selectedTopics,
+      args.selectedQuestions,
+      args.answerOptions,
+      args.answerCommitments,
+    )
+    .accountsStrict({
+      questionAuthority: args.questionAuthority,
+      config: configPubkey,
+      questionBank: questionBankPubkey,
+      battleRoom: battleRoomPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Question authority fills in the content of a matched room
+ *
+ * Accounts:
+ * 0. `[signer]` question_authority: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[]` question_bank: {@link QuestionBank}
+ * 3. `[writable]` battle_room: {@link BattleRoom}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - selected_concepts: {@link number[]}
+ * - selected_topics: {@link Buffer}
+ * - selected_questions: {@link number[]}
+ * - answer_options: {@link Buffer}
+ * - answer_commitments: {@link number[][]}
+ */
+export const assignRoomQuestions = (
+	args: AssignRoomQuestionsArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    assignRoomQuestionsBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Question authority fills in the content of a matched room
+ *
+ * Accounts:
+ * 0. `[signer]` question_authority: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[]` question_bank: {@link QuestionBank}
+ * 3. `[writable]` battle_room: {@link BattleRoom}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - selected_concepts: {@link number[]}
+ * - selected_topics: {@link Buffer}
+ * - selected_questions: {@link number[]}
+ * - answer_options: {@link Buffer}
+ * - answer_commitments: {@link number[][]}
+ */
+export const assignRoomQuestionsSendAndConfirm = async (
+  args: Omit<AssignRoomQuestionsArgs, "questionAuthority"> & {
+    signers: {
+      questionAuthority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return assignRoomQuestionsBuilder({
+      ...args,
+      questionAuthority: args.signers.questionAuthority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.questionAuthority])
+    .rpc();
+}
+
+export type VerifyRoomQuestionArgs = {
+  questionAuthority: web3.PublicKey;
+  roomId: Array<number>;
+  questionIndex: number;
+  concept: number;
+  answerDigest: Array<number>;
+  proof: Array<Array<number>>;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Prove one selected question against the question bank
+ *
+ * Accounts:
+ * 0. `[signer]` question_authority: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` battle_room: {@link BattleRoom}
+ * 3. `[]` question_bank: {@link QuestionBank}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - question_index: {@link number}
+ * - concept: {@link number}
+ * - answer_digest: {@link number[]}
+ * - proof: {@link number[][]}
+ */
+export const verifyRoomQuestionBuilder = (
+	args: VerifyRoomQuestionArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const questionBankPubkey = pda.deriveQuestionBankSeedPDA(_program.programId)[0];
+
+  return _program
+    .methods
+    .verifyRoomQuestion(
+      args.roomId,
+      args.questionIndex,
+      args.concept,
+      args.answerDigest,
+      args.proof,
+    )
+    .accountsStrict({
+      questionAuthority: args.questionAuthority,
+      config: configPubkey,
+      battleRoom: battleRoomPubkey,
+      questionBank: questionBankPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Prove one selected question against the question bank
+ *
+ * Accounts:
+ * 0. `[signer]` question_authority: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` battle_room: {@link BattleRoom}
+ * 3. `[]` question_bank: {@link QuestionBank}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - question_index: {@link number}
+ * - concept: {@link number}
+ * - answer_digest: {@link number[]}
+ * - proof: {@link number[][]}
+ */
+export const verifyRoomQuestion = (
+	args: VerifyRoomQuestionArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    verifyRoomQuestionBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Prove one selected question against the question bank
+ *
+ * Accounts:
+ * 0. `[signer]` question_authority: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` battle_room: {@link BattleRoom}
+ * 3. `[]` question_bank: {@link QuestionBank}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - question_index: {@link number}
+ * - concept: {@link number}
+ * - answer_digest: {@link number[]}
+ * - proof: {@link number[][]}
+ */
+export const verifyRoomQuestionSendAndConfirm = async (
+  args: Omit<VerifyRoomQuestionArgs, "questionAuthority"> & {
+    signers: {
+      questionAuthority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return verifyRoomQuestionBuilder({
+      ...args,
+      questionAuthority: args.signers.questionAuthority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.questionAuthority])
+    .rpc();
+}
+
+export type JoinBattleRoomArgs = {
+  playerB: web3.PublicKey;
+  warriorB: web3.PublicKey;
+  warriorNftAccount?: web3.PublicKey;
+  matchQueue?: web3.PublicKey;
+  wagerEscrow?: web3.PublicKey;
+  wagerVault?: web3.PublicKey;
+  playerTokenAccount?: web3.PublicKey;
+  tokenProgram?: web3.PublicKey;
+  roomId: Array<number>;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Join an open battle room
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player_b: {@link PublicKey}
+ * 1. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` match_queue: {@link MatchQueue} (optional)
+ * 4. `[writable]` battle_room: {@link BattleRoom}
+ * 5. `[]` config: {@link Config}
+ * 6. `[writable]` wager_escrow: {@link PublicKey} (optional)
+ * 7. `[writable]` wager_vault: {@link PublicKey} (optional)
+ * 8. `[writable]` player_token_account: {@link PublicKey} (optional)
+ * 9. `[]` token_program: {@link PublicKey} (optional)
+ * 10. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const joinBattleRoomBuilder = (
+	args: JoinBattleRoomArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .joinBattleRoom(
+      args.roomId,
+    )
+    .accountsStrict({
+      playerB: args.playerB,
+      warriorB: args.warriorB,
+      warriorNftAccount: args.warriorNftAccount ?? null,
+      matchQueue: args.matchQueue ?? null,
+      battleRoom: battleRoomPubkey,
+      config: configPubkey,
+      wagerEscrow: args.wagerEscrow ?? null,
+      wagerVault: args.wagerVault ?? null,
+      playerTokenAccount: args.playerTokenAccount ?? null,
+      tokenProgram: args.tokenProgram ?? null,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Join an open battle room
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player_b: {@link PublicKey}
+ * 1. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` match_queue: {@link MatchQueue} (optional)
+ * 4. `[writable]` battle_room: {@link BattleRoom}
+ * 5. `[]` config: {@link Config}
+ * 6. `[writable]` wager_escrow: {@link PublicKey} (optional)
+ * 7. `[writable]` wager_vault: {@link PublicKey} (optional)
+ * 8. `[writable]` player_token_account: {@link PublicKey} (optional)
+ * 9. `[]` token_program: {@link PublicKey} (optional)
+ * 10. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const joinBattleRoom = (
+	args: JoinBattleRoomArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    joinBattleRoomBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Join an open battle room
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player_b: {@link PublicKey}
+ * 1. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` match_queue: {@link MatchQueue} (optional)
+ * 4. `[writable]` battle_room: {@link BattleRoom}
+ * 5. `[]` config: {@link Config}
+ * 6. `[writable]` wager_escrow: {@link PublicKey} (optional)
+ * 7. `[writable]` wager_vault: {@link PublicKey} (optional)
+ * 8. `[writable]` player_token_account: {@link PublicKey} (optional)
+ * 9. `[]` token_program: {@link PublicKey} (optional)
+ * 10. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const joinBattleRoomSendAndConfirm = async (
+  args: Omit<JoinBattleRoomArgs, "playerB"> & {
+    signers: {
+      playerB: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return joinBattleRoomBuilder({
+      ...args,
+      playerB: args.signers.playerB.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.playerB])
+    .rpc();
+}
+
+export type InitializeMatchQueueArgs = {
+  admin: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Create the matchmaking queue
+ *
+ * Accounts:
+ * 0. `[writable, signer]` admin: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` match_queue: {@link MatchQueue}
+ * 3. `[]` system_program: {@link PublicKey}
+ */
+export const initializeMatchQueueBuilder = (
+	args: InitializeMatchQueueArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const matchQueuePubkey = pda.deriveMatchQueueSeedPDA(_program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .initializeMatchQueue()
+    .accountsStrict({
+      admin: args.admin,
+      config: configPubkey,
+      matchQueue: matchQueuePubkey,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Create the matchmaking queue
+ *
+ * Accounts:
+ * 0. `[writable, signer]` admin: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` match_queue: {@link MatchQueue}
+ * 3. `[]` system_program: {@link PublicKey}
+ */
+export const initializeMatchQueue = (
+	args: InitializeMatchQueueArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    initializeMatchQueueBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Create the matchmaking queue
+ *
+ * Accounts:
+ * 0. `[writable, signer]` admin: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` match_queue: {@link MatchQueue}
+ * 3. `[]` system_program: {@link PublicKey}
+ */
+export const initializeMatchQueueSendAndConfirm = async (
+  args: Omit<InitializeMatchQueueArgs, "admin"> & {
+    signers: {
+      admin: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return initializeMatchQueueBuilder({
+      ...args,
+      admin: args.signers.admin.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.admin])
+    .rpc();
+}
+
+export type EnqueueWarriorArgs = {
+  player: web3.PublicKey;
+  warrior: web3.PublicKey;
+  warriorNftAccount?: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Queue a warrior for matchmaking
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[]` config: {@link Config}
+ * 4. `[writable]` match_queue: {@link MatchQueue}
+ */
+export const enqueueWarriorBuilder = (
+	args: EnqueueWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const matchQueuePubkey = pda.deriveMatchQueueSeedPDA(_program.programId)[0];
+
+  return _program
+    .methods
+    .enqueueWarrior()
+    .accountsStrict({
+      player: args.player,
+      warrior: args.warrior,
+      warriorNftAccount: args.warriorNftAccount ?? null,
+      config: configPubkey,
+      matchQueue: matchQueuePubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Queue a warrior for matchmaking
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[]` config: {@link Config}
+ * 4. `[writable]` match_queue: {@link MatchQueue}
+ */
+export const enqueueWarrior = (
+	args: EnqueueWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    enqueueWarriorBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Queue a warrior for matchmaking
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[]` config: {@link Config}
+ * 4. `[writable]` match_queue: {@link MatchQueue}
+ */
+export const enqueueWarriorSendAndConfirm = async (
+  args: Omit<EnqueueWarriorArgs, "player"> & {
+    signers: {
+      player: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return enqueueWarriorBuilder({
+      ...args,
+      player: args.signers.player.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.player])
+    .rpc();
+}
+
+export type DequeueWarriorArgs = {
+  player: web3.PublicKey;
+  warrior: web3.PublicKey;
+  warriorNftAccount?: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Take a warrior out of the matchmaking queue
+ *
+ * Accounts:
+ * 0. `[signer]` player: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` match_queue: {@link MatchQueue}
+ */
+export const dequeueWarriorBuilder = (
+	args: DequeueWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const matchQueuePubkey = pda.deriveMatchQueueSeedPDA(_program.programId)[0];
+
+  return _program
+    .methods
+    .dequeueWarrior()
+    .accountsStrict({
+      player: args.player,
+      warrior: args.warrior,
+      warriorNftAccount: args.warriorNftAccount ?? null,
+      matchQueue: matchQueuePubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Take a warrior out of the matchmaking queue
+ *
+ * Accounts:
+ * 0. `[signer]` player: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` match_queue: {@link MatchQueue}
+ */
+export const dequeueWarrior = (
+	args: DequeueWarriorArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    dequeueWarriorBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Take a warrior out of the matchmaking queue
+ *
+ * Accounts:
+ * 0. `[signer]` player: {@link PublicKey}
+ * 1. `[writable]` warrior: {@link UndeadWarrior}
+ * 2. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 3. `[writable]` match_queue: {@link MatchQueue}
+ */
+export const dequeueWarriorSendAndConfirm = async (
+  args: Omit<DequeueWarriorArgs, "player"> & {
+    signers: {
+      player: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return dequeueWarriorBuilder({
+      ...args,
+      player: args.signers.player.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.player])
+    .rpc();
+}
+
+export type MatchPlayersArgs = {
+  cranker: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorANftAccount?: web3.PublicKey;
+  warriorB: web3.PublicKey;
+  warriorBNftAccount?: web3.PublicKey;
+  roomId: Array<number>;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Crank, pair two queued warriors into a new room
+ *
+ * Accounts:
+ * 0. `[writable, signer]` cranker: {@link PublicKey}
+ * 1. `[writable]` match_queue: {@link MatchQueue}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[]` warrior_a_nft_account: {@link PublicKey} (optional)
+ * 4. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 5. `[]` warrior_b_nft_account: {@link PublicKey} (optional)
+ * 6. `[]` config: {@link Config}
+ * 7. `[writable]` battle_room: {@link BattleRoom}
+ * 8. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const matchPlayersBuilder = (
+	args: MatchPlayersArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const matchQueuePubkey = pda.deriveMatchQueueSeedPDA(_program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
+
+  return _program
+    .methods
+    .matchPlayers(
+      args.roomId,
+    )
+    .accountsStrict({
+      cranker: args.cranker,
+      matchQueue: matchQueuePubkey,
+      warriorA: args.warriorA,
+      warriorANftAccount: args.warriorANftAccount ?? null,
+      warriorB: args.warriorB,
+      warriorBNftAccount: args.warriorBNftAccount ?? null,
+      config: configPubkey,
+      battleRoom: battleRoomPubkey,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Crank, pair two queued warriors into a new room
+ *
+ * Accounts:
+ * 0. `[writable, signer]` cranker: {@link PublicKey}
+ * 1. `[writable]` match_queue: {@link MatchQueue}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[]` warrior_a_nft_account: {@link PublicKey} (optional)
+ * 4. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 5. `[]` warrior_b_nft_account: {@link PublicKey} (optional)
+ * 6. `[]` config: {@link Config}
+ * 7. `[writable]` battle_room: {@link BattleRoom}
+ * 8. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const matchPlayers = (
+	args: MatchPlayersArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    matchPlayersBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Crank, pair two queued warriors into a new room
+ *
+ * Accounts:
+ * 0. `[writable, signer]` cranker: {@link PublicKey}
+ * 1. `[writable]` match_queue: {@link MatchQueue}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[]` warrior_a_nft_account: {@link PublicKey} (optional)
+ * 4. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 5. `[]` warrior_b_nft_account: {@link PublicKey} (optional)
+ * 6. `[]` config: {@link Config}
+ * 7. `[writable]` battle_room: {@link BattleRoom}
+ * 8. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const matchPlayersSendAndConfirm = async (
+  args: Omit<MatchPlayersArgs, "cranker"> & {
+    signers: {
+      cranker: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return matchPlayersBuilder({
+      ...args,
+      cranker: args.signers.cranker.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.cranker])
+    .rpc();
+}
+
+export type SignalReadyArgs = {
+  player: web3.PublicKey;
+  warrior: web3.PublicKey;
+  warriorNftAccount?: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorB: web3.PublicKey;
+  roomId: Array<number>;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Mark a player ready to battle
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior: {@link UndeadWarrior}
+ * 3. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 4. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 5. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 6. `[]` config: {@link Config}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const signalReadyBuilder = (
+	args: SignalReadyArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+
+  return _program
+    .methods
+    .signalReady(
+      args.roomId,
+    )
+    .accountsStrict({
+      player: args.player,
+      battleRoom: battleRoomPubkey,
+      warrior: args.warrior,
+      warriorNftAccount: args.warriorNftAccount ?? null,
+      warriorA: args.warriorA,
+      warriorB: args.warriorB,
+      config: configPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Mark a player ready to battle
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior: {@link UndeadWarrior}
+ * 3. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 4. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 5. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 6. `[]` config: {@link Config}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const signalReady = (
+	args: SignalReadyArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    signalReadyBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Mark a player ready to battle
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior: {@link UndeadWarrior}
+ * 3. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 4. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 5. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 6. `[]` config: {@link Config}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const signalReadySendAndConfirm = async (
+  args: Omit<SignalReadyArgs, "player"> & {
+    signers: {
+      player: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return signalReadyBuilder({
+      ...args,
+      player: args.signers.player.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.player])
+    .rpc();
+}
 
-let _program: Program<RustUndead>;
+export type DelegateBattleArgs = {
+  signer: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorB: web3.PublicKey;
+  roomId: Array<number>;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Delegate the room and both warriors to the ephemeral rollup
+ *
+ * Accounts:
+ * 0. `[writable, signer]` signer: {@link PublicKey}
+ * 1. `[writable]` buffer_battle_room: {@link PublicKey}
+ * 2. `[writable]` delegation_record_battle_room: {@link PublicKey}
+ * 3. `[writable]` delegation_metadata_battle_room: {@link PublicKey}
+ * 4. `[writable]` battle_room: {@link BattleRoom}
+ * 5. `[writable]` buffer_warrior_a: {@link PublicKey}
+ * 6. `[writable]` delegation_record_warrior_a: {@link PublicKey}
+ * 7. `[writable]` delegation_metadata_warrior_a: {@link PublicKey}
+ * 8. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 9. `[writable]` buffer_warrior_b: {@link PublicKey}
+ * 10. `[writable]` delegation_record_warrior_b: {@link PublicKey}
+ * 11. `[writable]` delegation_metadata_warrior_b: {@link PublicKey}
+ * 12. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 13. `[]` config: {@link Config}
+ * 14. `[]` owner_program: {@link PublicKey}
+ * 15. `[]` delegation_program: {@link PublicKey}
+ * 16. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const delegateBattleBuilder = (
+	args: DelegateBattleArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const bufferWarriorAPubkey = pda.deriveDelegateBufferSeedPDA({ account: args.warriorA }, _program.programId)[0];
+  const delegationRecordWarriorAPubkey = pda.deriveDelegationRecordSeedPDA({ account: args.warriorA })[0];
+  const delegationMetadataWarriorAPubkey = pda.deriveDelegationMetadataSeedPDA({ account: args.warriorA })[0];
+  const bufferWarriorBPubkey = pda.deriveDelegateBufferSeedPDA({ account: args.warriorB }, _program.programId)[0];
+  const delegationRecordWarriorBPubkey = pda.deriveDelegationRecordSeedPDA({ account: args.warriorB })[0];
+  const delegationMetadataWarriorBPubkey = pda.deriveDelegationMetadataSeedPDA({ account: args.warriorB })[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const ownerProgramPubkey = _program.programId;
+  const delegationProgramPubkey = pda.DELEGATION_PROGRAM_ID;
+  const systemProgramPubkey = web3.SystemProgram.programId;
+  const bufferBattleRoomPubkey = pda.deriveDelegateBufferSeedPDA({ account: battleRoomPubkey }, _program.programId)[0];
+  const delegationRecordBattleRoomPubkey = pda.deriveDelegationRecordSeedPDA({ account: battleRoomPubkey })[0];
+  const delegationMetadataBattleRoomPubkey = pda.deriveDelegationMetadataSeedPDA({ account: battleRoomPubkey })[0];
+
+  return _program
+    .methods
+    .delegateBattle(
+      args.roomId,
+    )
+    .accountsStrict({
+      signer: args.signer,
+      bufferBattleRoom: bufferBattleRoomPubkey,
+      delegationRecordBattleRoom: delegationRecordBattleRoomPubkey,
+      delegationMetadataBattleRoom: delegationMetadataBattleRoomPubkey,
+      battleRoom: battleRoomPubkey,
+      bufferWarriorA: bufferWarriorAPubkey,
+      delegationRecordWarriorA: delegationRecordWarriorAPubkey,
+      delegationMetadataWarriorA: delegationMetadataWarriorAPubkey,
+      warriorA: args.warriorA,
+      bufferWarriorB: bufferWarriorBPubkey,
+      delegationRecordWarriorB: delegationRecordWarriorBPubkey,
+      delegationMetadataWarriorB: delegationMetadataWarriorBPubkey,
+      warriorB: args.warriorB,
+      config: configPubkey,
+      ownerProgram: ownerProgramPubkey,
+      delegationProgram: delegationProgramPubkey,
+      systemProgram: systemProgramPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Delegate the room and both warriors to the ephemeral rollup
+ *
+ * Accounts:
+ * 0. `[writable, signer]` signer: {@link PublicKey}
+ * 1. `[writable]` buffer_battle_room: {@link PublicKey}
+ * 2. `[writable]` delegation_record_battle_room: {@link PublicKey}
+ * 3. `[writable]` delegation_metadata_battle_room: {@link PublicKey}
+ * 4. `[writable]` battle_room: {@link BattleRoom}
+ * 5. `[writable]` buffer_warrior_a: {@link PublicKey}
+ * 6. `[writable]` delegation_record_warrior_a: {@link PublicKey}
+ * 7. `[writable]` delegation_metadata_warrior_a: {@link PublicKey}
+ * 8. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 9. `[writable]` buffer_warrior_b: {@link PublicKey}
+ * 10. `[writable]` delegation_record_warrior_b: {@link PublicKey}
+ * 11. `[writable]` delegation_metadata_warrior_b: {@link PublicKey}
+ * 12. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 13. `[]` config: {@link Config}
+ * 14. `[]` owner_program: {@link PublicKey}
+ * 15. `[]` delegation_program: {@link PublicKey}
+ * 16. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const delegateBattle = (
+	args: DelegateBattleArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    delegateBattleBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Delegate the room and both warriors to the ephemeral rollup
+ *
+ * Accounts:
+ * 0. `[writable, signer]` signer: {@link PublicKey}
+ * 1. `[writable]` buffer_battle_room: {@link PublicKey}
+ * 2. `[writable]` delegation_record_battle_room: {@link PublicKey}
+ * 3. `[writable]` delegation_metadata_battle_room: {@link PublicKey}
+ * 4. `[writable]` battle_room: {@link BattleRoom}
+ * 5. `[writable]` buffer_warrior_a: {@link PublicKey}
+ * 6. `[writable]` delegation_record_warrior_a: {@link PublicKey}
+ * 7. `[writable]` delegation_metadata_warrior_a: {@link PublicKey}
+ * 8. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 9. `[writable]` buffer_warrior_b: {@link PublicKey}
+ * 10. `[writable]` delegation_record_warrior_b: {@link PublicKey}
+ * 11. `[writable]` delegation_metadata_warrior_b: {@link PublicKey}
+ * 12. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 13. `[]` config: {@link Config}
+ * 14. `[]` owner_program: {@link PublicKey}
+ * 15. `[]` delegation_program: {@link PublicKey}
+ * 16. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const delegateBattleSendAndConfirm = async (
+  args: Omit<DelegateBattleArgs, "signer"> & {
+    signers: {
+      signer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return delegateBattleBuilder({
+      ...args,
+      signer: args.signers.signer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.signer])
+    .rpc();
+}
+
+export type StartBattleArgs = {
+  authority: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorB: web3.PublicKey;
+  roomId: Array<number>;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Start the battle on the ephemeral rollup
+ *
+ * Accounts:
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` config: {@link Config}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const startBattleBuilder = (
+	args: StartBattleArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const magicProgramPubkey = MAGIC_PROGRAM_ID;
+  const magicContextPubkey = MAGIC_CONTEXT_ID;
+  const eventAuthorityPubkey = pda.deriveEventAuthoritySeedPDA(_program.programId)[0];
+  const programPubkey = _program.programId;
+
+  return _program
+    .methods
+    .startBattle(
+      args.roomId,
+    )
+    .accountsStrict({
+      authority: args.authority,
+      battleRoom: battleRoomPubkey,
+      warriorA: args.warriorA,
+      warriorB: args.warriorB,
+      config: configPubkey,
+      magicProgram: magicProgramPubkey,
+      magicContext: magicContextPubkey,
+      eventAuthority: eventAuthorityPubkey,
+      program: programPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Start the battle on the ephemeral rollup
+ *
+ * Accounts:
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` config: {@link Config}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const startBattle = (
+	args: StartBattleArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    startBattleBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Start the battle on the ephemeral rollup
+ *
+ * Accounts:
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` config: {@link Config}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const startBattleSendAndConfirm = async (
+  args: Omit<StartBattleArgs, "authority"> & {
+    signers: {
+      authority: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return startBattleBuilder({
+      ...args,
+      authority: args.signers.authority.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.authority])
+    .rpc();
+}
+
+export type CancelBattleArgs = {
+  player: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorB?: web3.PublicKey;
+  roomId: Array<number>;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Cancel a room before the battle starts
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior} (optional)
+ * 4. `[]` config: {@link Config}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const cancelBattleBuilder = (
+	args: CancelBattleArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+
+  return _program
+    .methods
+    .cancelBattle(
+      args.roomId,
+    )
+    .accountsStrict({
+      player: args.player,
+      battleRoom: battleRoomPubkey,
+      warriorA: args.warriorA,
+      warriorB: args.warriorB ?? null,
+      config: configPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Cancel a room before the battle starts
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior} (optional)
+ * 4. `[]` config: {@link Config}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const cancelBattle = (
+	args: CancelBattleArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    cancelBattleBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Cancel a room before the battle starts
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior} (optional)
+ * 4. `[]` config: {@link Config}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ */
+export const cancelBattleSendAndConfirm = async (
+  args: Omit<CancelBattleArgs, "player"> & {
+    signers: {
+      player: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return cancelBattleBuilder({
+      ...args,
+      player: args.signers.player.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.player])
+    .rpc();
+}
+
+export type AnswerQuestionArgs = {
+  player: web3.PublicKey;
+  attackerWarrior: web3.PublicKey;
+  defenderWarrior: web3.PublicKey;
+  warriorNftAccount?: web3.PublicKey;
+  roomId: Array<number>;
+  sealedAnswer: Array<number>;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Seal an answer to the current question
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[]` attacker_warrior: {@link UndeadWarrior}
+ * 3. `[]` defender_warrior: {@link UndeadWarrior}
+ * 4. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 5. `[]` event_authority: {@link PublicKey}
+ * 6. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - sealed_answer: {@link number[]}
+ */
+export const answerQuestionBuilder = (
+	args: AnswerQuestionArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const eventAuthorityPubkey = pda.deriveEventAuthoritySeedPDA(_program.programId)[0];
+  const programPubkey = _program.programId;
+
+  return _program
+    .methods
+    .answerQuestion(
+      args.roomId,
+      args.sealedAnswer,
+    )
+    .accountsStrict({
+      player: args.player,
+      battleRoom: battleRoomPubkey,
+      attackerWarrior: args.attackerWarrior,
+      defenderWarrior: args.defenderWarrior,
+      warriorNftAccount: args.warriorNftAccount ?? null,
+      eventAuthority: eventAuthorityPubkey,
+      program: programPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Seal an answer to the current question
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[]` attacker_warrior: {@link UndeadWarrior}
+ * 3. `[]` defender_warrior: {@link UndeadWarrior}
+ * 4. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 5. `[]` event_authority: {@link PublicKey}
+ * 6. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - sealed_answer: {@link number[]}
+ */
+export const answerQuestion = (
+	args: AnswerQuestionArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    answerQuestionBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Seal an answer to the current question
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[]` attacker_warrior: {@link UndeadWarrior}
+ * 3. `[]` defender_warrior: {@link UndeadWarrior}
+ * 4. `[]` warrior_nft_account: {@link PublicKey} (optional)
+ * 5. `[]` event_authority: {@link PublicKey}
+ * 6. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - sealed_answer: {@link number[]}
+ */
+export const answerQuestionSendAndConfirm = async (
+  args: Omit<AnswerQuestionArgs, "player"> & {
+    signers: {
+      player: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return answerQuestionBuilder({
+      ...args,
+      player: args.signers.player.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.player])
+    .rpc();
+}
+
+export type RevealPlayerAnswerArgs = {
+  player: web3.PublicKey;
+  roomId: Array<number>;
+  answer: number;
+  salt: Array<number>;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Open the sealed answer once both players have sealed theirs
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - answer: {@link number}
+ * - salt: {@link number[]}
+ */
+export const revealPlayerAnswerBuilder = (
+	args: RevealPlayerAnswerArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<RustUndead, never> => {
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+
+  return _program
+    .methods
+    .revealPlayerAnswer(
+      args.roomId,
+      args.answer,
+      args.salt,
+    )
+    .accountsStrict({
+      player: args.player,
+      battleRoom: battleRoomPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
 
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Open the sealed answer once both players have sealed theirs
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - answer: {@link number}
+ * - salt: {@link number[]}
+ */
+export const revealPlayerAnswer = (
+	args: RevealPlayerAnswerArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    revealPlayerAnswerBuilder(args, remainingAccounts).instruction();
 
-export const initializeClient = (
-    programId: web3.PublicKey,
-    anchorProvider = AnchorProvider.env(),
-) => {
-    _program = new Program<RustUndead>(
-        idl as never,
-        programId,
-        anchorProvider,
-    );
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Open the sealed answer once both players have sealed theirs
+ *
+ * Accounts:
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ *
+ * Data:
+ * - room_id: {@link number[]}
+ * - answer: {@link number}
+ * - salt: {@link number[]}
+ */
+export const revealPlayerAnswerSendAndConfirm = async (
+  args: Omit<RevealPlayerAnswerArgs, "player"> & {
+    signers: {
+      player: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-};
+  return revealPlayerAnswerBuilder({
+      ...args,
+      player: args.signers.player.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.player])
+    .rpc();
+}
 
-export type InitializeGameArgs = {
-  feePayer: web3.PublicKey;
-  authority: web3.PublicKey;
-  systemProgram: web3.PublicKey;
-  vrfOracle: web3.PublicKey;
-  erBridgeAuthority: web3.PublicKey;
+export type RevealAnswerArgs = {
+  questionAuthority: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorB: web3.PublicKey;
+  roomId: Array<number>;
+  questionIndex: number;
+  answer: number;
+  salt: Array<number>;
+  answerSalt: Array<number>;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Initialize the global game state
+ * Question authority reveals the committed answer once both players answered
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` authority: {@link PublicKey} The game authority
- * 2. `[writable]` game_state: {@link GameState} The global game state account
- * 3. `[]` system_program: {@link PublicKey} The system program
+ * 0. `[writable, signer]` question_authority: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` battle_room: {@link BattleRoom}
+ * 3. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 4. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
  *
  * Data:
- * - vrf_oracle: {@link PublicKey} Magic Block VRF oracle pubkey
- * - er_bridge_authority: {@link PublicKey} Ephemeral rollup bridge authority
+ * - room_id: {@link number[]}
+ * - question_index: {@link number}
+ * - answer: {@link number}
+ * - salt: {@link number[]}
+ * - answer_salt: {@link number[]}
  */
-export const initializeGameBuilder = (
-	args: InitializeGameArgs,
+export const revealAnswerBuilder = (
+	args: RevealAnswerArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<RustUndead, never> => {
-  const [gameStatePubkey] = pda.deriveGameStateSeedPDA(_program.programId);
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const magicProgramPubkey = MAGIC_PROGRAM_ID;
+  const magicContextPubkey = MAGIC_CONTEXT_ID;
+  const eventAuthorityPubkey = pda.deriveEventAuthoritySeedPDA(_program.programId)[0];
+  const programPubkey = _program.programId;
 
   return _program
     .methods
-    .initializeGame(
-      args.vrfOracle,
-      args.erBridgeAuthority,
+    .revealAnswer(
+      args.roomId,
+      args.questionIndex,
+      args.answer,
+      args.salt,
+      args.answerSalt,
     )
     .accountsStrict({
-      feePayer: args.feePayer,
-      authority: args.authority,
-      gameState: gameStatePubkey,
-      systemProgram: args.systemProgram,
+      questionAuthority: args.questionAuthority,
+      config: configPubkey,
+      battleRoom: battleRoomPubkey,
+      warriorA: args.warriorA,
+      warriorB: args.warriorB,
+      magicProgram: magicProgramPubkey,
+      magicContext: magicContextPubkey,
+      eventAuthority: eventAuthorityPubkey,
+      program: programPubkey,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Initialize the global game state
+ * Question authority reveals the committed answer once both players answered
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` authority: {@link PublicKey} The game authority
- * 2. `[writable]` game_state: {@link GameState} The global game state account
- * 3. `[]` system_program: {@link PublicKey} The system program
+ * 0. `[writable, signer]` question_authority: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` battle_room: {@link BattleRoom}
+ * 3. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 4. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
  *
  * Data:
- * - vrf_oracle: {@link PublicKey} Magic Block VRF oracle pubkey
- * - er_bridge_authority: {@link PublicKey} Ephemeral rollup bridge authority
+ * - room_id: {@link number[]}
+ * - question_index: {@link number}
+ * - answer: {@link number}
+ * - salt: {@link number[]}
+ * - answer_salt: {@link number[]}
  */
-export const initializeGame = (
-	args: InitializeGameArgs,
+export const revealAnswer = (
+	args: RevealAnswerArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): Promise<web3.TransactionInstruction> =>
-    initializeGameBuilder(args, remainingAccounts).instruction();
+    revealAnswerBuilder(args, remainingAccounts).instruction();
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Initialize the global game state
+ * Question authority reveals the committed answer once both players answered
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` authority: {@link PublicKey} The game authority
- * 2. `[writable]` game_state: {@link GameState} The global game state account
- * 3. `[]` system_program: {@link PublicKey} The system program
+ * 0. `[writable, signer]` question_authority: {@link PublicKey}
+ * 1. `[]` config: {@link Config}
+ * 2. `[writable]` battle_room: {@link BattleRoom}
+ * 3. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 4. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
  *
  * Data:
- * - vrf_oracle: {@link PublicKey} Magic Block VRF oracle pubkey
- * - er_bridge_authority: {@link PublicKey} Ephemeral rollup bridge authority
+ * - room_id: {@link number[]}
+ * - question_index: {@link number}
+ * - answer: {@link number}
+ * - salt: {@link number[]}
+ * - answer_salt: {@link number[]}
  */
-export const initializeGameSendAndConfirm = async (
-  args: Omit<InitializeGameArgs, "feePayer" | "authority"> & {
+export const revealAnswerSendAndConfirm = async (
+  args: Omit<RevealAnswerArgs, "questionAuthority"> & {
     signers: {
-      feePayer: web3.Signer,
-      authority: web3.Signer,
+      questionAuthority: web3.Signer,
     },
   },
   remainingAccounts: Array<web3.AccountMeta> = [],
@@ -117,116 +3502,111 @@ export const initializeGameSendAndConfirm = async (
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-  return initializeGameBuilder({
+  return revealAnswerBuilder({
       ...args,
-      feePayer: args.signers.feePayer.publicKey,
-      authority: args.signers.authority.publicKey,
+      questionAuthority: args.signers.questionAuthority.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.authority])
+    .signers([args.signers.questionAuthority])
     .rpc();
 }
 
-export type CreateWarriorArgs = {
-  feePayer: web3.PublicKey;
-  owner: web3.PublicKey;
-  vrfClient: web3.PublicKey;
-  vrfProgram: web3.PublicKey;
-  systemProgram: web3.PublicKey;
-  name: string;
-  dna: bigint;
+export type ClaimTimeoutArgs = {
+  caller: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorB: web3.PublicKey;
+  roomId: Array<number>;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Create a new warrior with DNA and request VRF for stats
+ * End a battle whose current question is past its deadline
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` owner: {@link PublicKey} The owner of the warrior
- * 2. `[writable]` warrior: {@link Warrior} The warrior account
- * 3. `[writable]` game_state: {@link GameState} The global game state account
- * 4. `[writable]` vrf_client: {@link PublicKey} The Magic Block VRF client account
- * 5. `[]` vrf_program: {@link PublicKey} The Magic Block VRF program
- * 6. `[]` system_program: {@link PublicKey} The system program
+ * 0. `[writable, signer]` caller: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` magic_program: {@link PublicKey}
+ * 5. `[writable]` magic_context: {@link PublicKey}
+ * 6. `[]` event_authority: {@link PublicKey}
+ * 7. `[]` program: {@link PublicKey}
  *
  * Data:
- * - name: {@link string} The name of the warrior
- * - dna: {@link BigInt} DNA for visual generation
+ * - room_id: {@link number[]}
  */
-export const createWarriorBuilder = (
-	args: CreateWarriorArgs,
+export const claimTimeoutBuilder = (
+	args: ClaimTimeoutArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<RustUndead, never> => {
-    const [warriorPubkey] = pda.deriveWarriorSeedPDA({
-        owner: args.owner,
-        name: args.name,
-    }, _program.programId);
-  const [gameStatePubkey] = pda.deriveGameStateSeedPDA(_program.programId);
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const magicProgramPubkey = MAGIC_PROGRAM_ID;
+  const magicContextPubkey = MAGIC_CONTEXT_ID;
+  const eventAuthorityPubkey = pda.deriveEventAuthoritySeedPDA(_program.programId)[0];
+  const programPubkey = _program.programId;
 
   return _program
     .methods
-    .createWarrior(
-      args.name,
-      new BN(args.dna.toString()),
+    .claimTimeout(
+      args.roomId,
     )
     .accountsStrict({
-      feePayer: args.feePayer,
-      owner: args.owner,
-      warrior: warriorPubkey,
-      gameState: gameStatePubkey,
-      vrfClient: args.vrfClient,
-      vrfProgram: args.vrfProgram,
-      systemProgram: args.systemProgram,
+      caller: args.caller,
+      battleRoom: battleRoomPubkey,
+      warriorA: args.warriorA,
+      warriorB: args.warriorB,
+      magicProgram: magicProgramPubkey,
+      magicContext: magicContextPubkey,
+      eventAuthority: eventAuthorityPubkey,
+      program: programPubkey,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Create a new warrior with DNA and request VRF for stats
+ * End a battle whose current question is past its deadline
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` owner: {@link PublicKey} The owner of the warrior
- * 2. `[writable]` warrior: {@link Warrior} The warrior account
- * 3. `[writable]` game_state: {@link GameState} The global game state account
- * 4. `[writable]` vrf_client: {@link PublicKey} The Magic Block VRF client account
- * 5. `[]` vrf_program: {@link PublicKey} The Magic Block VRF program
- * 6. `[]` system_program: {@link PublicKey} The system program
+ * 0. `[writable, signer]` caller: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` magic_program: {@link PublicKey}
+ * 5. `[writable]` magic_context: {@link PublicKey}
+ * 6. `[]` event_authority: {@link PublicKey}
+ * 7. `[]` program: {@link PublicKey}
  *
  * Data:
- * - name: {@link string} The name of the warrior
- * - dna: {@link BigInt} DNA for visual generation
+ * - room_id: {@link number[]}
  */
-export const createWarrior = (
-	args: CreateWarriorArgs,
+export const claimTimeout = (
+	args: ClaimTimeoutArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): Promise<web3.TransactionInstruction> =>
-    createWarriorBuilder(args, remainingAccounts).instruction();
+    claimTimeoutBuilder(args, remainingAccounts).instruction();
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Create a new warrior with DNA and request VRF for stats
+ * End a battle whose current question is past its deadline
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` owner: {@link PublicKey} The owner of the warrior
- * 2. `[writable]` warrior: {@link Warrior} The warrior account
- * 3. `[writable]` game_state: {@link GameState} The global game state account
- * 4. `[writable]` vrf_client: {@link PublicKey} The Magic Block VRF client account
- * 5. `[]` vrf_program: {@link PublicKey} The Magic Block VRF program
- * 6. `[]` system_program: {@link PublicKey} The system program
+ * 0. `[writable, signer]` caller: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` magic_program: {@link PublicKey}
+ * 5. `[writable]` magic_context: {@link PublicKey}
+ * 6. `[]` event_authority: {@link PublicKey}
+ * 7. `[]` program: {@link PublicKey}
  *
  * Data:
- * - name: {@link string} The name of the warrior
- * - dna: {@link BigInt} DNA for visual generation
+ * - room_id: {@link number[]}
  */
-export const createWarriorSendAndConfirm = async (
-  args: Omit<CreateWarriorArgs, "feePayer" | "owner"> & {
+export const claimTimeoutSendAndConfirm = async (
+  args: Omit<ClaimTimeoutArgs, "caller"> & {
     signers: {
-      feePayer: web3.Signer,
-      owner: web3.Signer,
+      caller: web3.Signer,
     },
   },
   remainingAccounts: Array<web3.AccountMeta> = [],
@@ -234,88 +3614,116 @@ export const createWarriorSendAndConfirm = async (
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-  return createWarriorBuilder({
+  return claimTimeoutBuilder({
       ...args,
-      feePayer: args.signers.feePayer.publicKey,
-      owner: args.signers.owner.publicKey,
+      caller: args.signers.caller.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.owner])
+    .signers([args.signers.caller])
     .rpc();
 }
 
-export type FinalizeWarriorStatsArgs = {
-  feePayer: web3.PublicKey;
-  warrior: web3.PublicKey;
-  owner: web3.PublicKey;
-  vrfClient: web3.PublicKey;
-  vrfProgram: web3.PublicKey;
+export type SettleBattleRoomArgs = {
+  authority: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorB: web3.PublicKey;
+  roomId: Array<number>;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Finalize warrior creation with VRF randomness result
+ * Apply battle results on the ephemeral rollup
  *
  * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} Auto-generated, default fee payer
- * 1. `[writable]` warrior: {@link Warrior} The warrior account
- * 2. `[]` owner: {@link PublicKey} The warrior owner (for verification)
- * 3. `[]` vrf_client: {@link PublicKey} The Magic Block VRF client account
- * 4. `[]` vrf_program: {@link PublicKey} The Magic Block VRF program
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` config: {@link Config}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const finalizeWarriorStatsBuilder = (
-	args: FinalizeWarriorStatsArgs,
+export const settleBattleRoomBuilder = (
+	args: SettleBattleRoomArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<RustUndead, never> => {
-
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const magicProgramPubkey = MAGIC_PROGRAM_ID;
+  const magicContextPubkey = MAGIC_CONTEXT_ID;
+  const eventAuthorityPubkey = pda.deriveEventAuthoritySeedPDA(_program.programId)[0];
+  const programPubkey = _program.programId;
 
   return _program
     .methods
-    .finalizeWarriorStats(
-
+    .settleBattleRoom(
+      args.roomId,
     )
     .accountsStrict({
-      feePayer: args.feePayer,
-      warrior: args.warrior,
-      owner: args.owner,
-      vrfClient: args.vrfClient,
-      vrfProgram: args.vrfProgram,
+      authority: args.authority,
+      battleRoom: battleRoomPubkey,
+      warriorA: args.warriorA,
+      warriorB: args.warriorB,
+      config: configPubkey,
+      magicProgram: magicProgramPubkey,
+      magicContext: magicContextPubkey,
+      eventAuthority: eventAuthorityPubkey,
+      program: programPubkey,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Finalize warrior creation with VRF randomness result
+ * Apply battle results on the ephemeral rollup
  *
  * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} Auto-generated, default fee payer
- * 1. `[writable]` warrior: {@link Warrior} The warrior account
- * 2. `[]` owner: {@link PublicKey} The warrior owner (for verification)
- * 3. `[]` vrf_client: {@link PublicKey} The Magic Block VRF client account
- * 4. `[]` vrf_program: {@link PublicKey} The Magic Block VRF program
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` config: {@link Config}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const finalizeWarriorStats = (
-	args: FinalizeWarriorStatsArgs,
+export const settleBattleRoom = (
+	args: SettleBattleRoomArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): Promise<web3.TransactionInstruction> =>
-    finalizeWarriorStatsBuilder(args, remainingAccounts).instruction();
+    settleBattleRoomBuilder(args, remainingAccounts).instruction();
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Finalize warrior creation with VRF randomness result
+ * Apply battle results on the ephemeral rollup
  *
  * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} Auto-generated, default fee payer
- * 1. `[writable]` warrior: {@link Warrior} The warrior account
- * 2. `[]` owner: {@link PublicKey} The warrior owner (for verification)
- * 3. `[]` vrf_client: {@link PublicKey} The Magic Block VRF client account
- * 4. `[]` vrf_program: {@link PublicKey} The Magic Block VRF program
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` config: {@link Config}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const finalizeWarriorStatsSendAndConfirm = async (
-  args: Omit<FinalizeWarriorStatsArgs, "feePayer"> & {
+export const settleBattleRoomSendAndConfirm = async (
+  args: Omit<SettleBattleRoomArgs, "authority"> & {
     signers: {
-      feePayer: web3.Signer,
+      authority: web3.Signer,
     },
   },
   remainingAccounts: Array<web3.AccountMeta> = [],
@@ -323,104 +3731,101 @@ export const finalizeWarriorStatsSendAndConfirm = async (
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-  return finalizeWarriorStatsBuilder({
+  return settleBattleRoomBuilder({
       ...args,
-      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer])
+    .signers([args.signers.authority])
     .rpc();
 }
 
-export type CreateBattleRoomArgs = {
-  feePayer: web3.PublicKey;
-  creator: web3.PublicKey;
-  warrior: web3.PublicKey;
-  systemProgram: web3.PublicKey;
-  roomId: string;
+export type UndelegateBattleRoomArgs = {
+  authority: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorB: web3.PublicKey;
+  roomId: Array<number>;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Create a new battle room
+ * Commit and undelegate the room and both warriors
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` creator: {@link PublicKey} The creator of the battle room
- * 2. `[writable]` warrior: {@link Warrior} The creator's warrior
- * 3. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 4. `[writable]` game_state: {@link GameState} The global game state account
- * 5. `[]` system_program: {@link PublicKey} The system program
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` magic_program: {@link PublicKey}
+ * 5. `[writable]` magic_context: {@link PublicKey}
  *
  * Data:
- * - room_id: {@link string} Unique identifier for the battle room
+ * - room_id: {@link number[]}
  */
-export const createBattleRoomBuilder = (
-	args: CreateBattleRoomArgs,
+export const undelegateBattleRoomBuilder = (
+	args: UndelegateBattleRoomArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<RustUndead, never> => {
-    const [battleRoomPubkey] = pda.deriveBattleRoomSeedPDA({
-        roomId: args.roomId,
-    }, _program.programId);
-  const [gameStatePubkey] = pda.deriveGameStateSeedPDA(_program.programId);
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const magicProgramPubkey = MAGIC_PROGRAM_ID;
+  const magicContextPubkey = MAGIC_CONTEXT_ID;
 
   return _program
     .methods
-    .createBattleRoom(
+    .undelegateBattleRoom(
       args.roomId,
     )
     .accountsStrict({
-      feePayer: args.feePayer,
-      creator: args.creator,
-      warrior: args.warrior,
+      authority: args.authority,
       battleRoom: battleRoomPubkey,
-      gameState: gameStatePubkey,
-      systemProgram: args.systemProgram,
+      warriorA: args.warriorA,
+      warriorB: args.warriorB,
+      magicProgram: magicProgramPubkey,
+      magicContext: magicContextPubkey,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Create a new battle room
+ * Commit and undelegate the room and both warriors
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` creator: {@link PublicKey} The creator of the battle room
- * 2. `[writable]` warrior: {@link Warrior} The creator's warrior
- * 3. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 4. `[writable]` game_state: {@link GameState} The global game state account
- * 5. `[]` system_program: {@link PublicKey} The system program
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` magic_program: {@link PublicKey}
+ * 5. `[writable]` magic_context: {@link PublicKey}
  *
  * Data:
- * - room_id: {@link string} Unique identifier for the battle room
+ * - room_id: {@link number[]}
  */
-export const createBattleRoom = (
-	args: CreateBattleRoomArgs,
+export const undelegateBattleRoom = (
+	args: UndelegateBattleRoomArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): Promise<web3.TransactionInstruction> =>
-    createBattleRoomBuilder(args, remainingAccounts).instruction();
+    undelegateBattleRoomBuilder(args, remainingAccounts).instruction();
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Create a new battle room
+ * Commit and undelegate the room and both warriors
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` creator: {@link PublicKey} The creator of the battle room
- * 2. `[writable]` warrior: {@link Warrior} The creator's warrior
- * 3. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 4. `[writable]` game_state: {@link GameState} The global game state account
- * 5. `[]` system_program: {@link PublicKey} The system program
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` magic_program: {@link PublicKey}
+ * 5. `[writable]` magic_context: {@link PublicKey}
  *
  * Data:
- * - room_id: {@link string} Unique identifier for the battle room
+ * - room_id: {@link number[]}
  */
-export const createBattleRoomSendAndConfirm = async (
-  args: Omit<CreateBattleRoomArgs, "feePayer" | "creator"> & {
+export const undelegateBattleRoomSendAndConfirm = async (
+  args: Omit<UndelegateBattleRoomArgs, "authority"> & {
     signers: {
-      feePayer: web3.Signer,
-      creator: web3.Signer,
+      authority: web3.Signer,
     },
   },
   remainingAccounts: Array<web3.AccountMeta> = [],
@@ -428,84 +3833,116 @@ export const createBattleRoomSendAndConfirm = async (
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-  return createBattleRoomBuilder({
+  return undelegateBattleRoomBuilder({
       ...args,
-      feePayer: args.signers.feePayer.publicKey,
-      creator: args.signers.creator.publicKey,
+      authority: args.signers.authority.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.creator])
+    .signers([args.signers.authority])
     .rpc();
 }
 
-export type JoinBattleRoomArgs = {
-  feePayer: web3.PublicKey;
-  joiner: web3.PublicKey;
-  warrior: web3.PublicKey;
-  battleRoom: web3.PublicKey;
+export type ForceUndelegateArgs = {
+  caller: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorB: web3.PublicKey;
+  roomId: Array<number>;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Join an existing battle room
+ * Crank for delegated rooms left idle past the timeout
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` joiner: {@link PublicKey} The player joining the battle
- * 2. `[writable]` warrior: {@link Warrior} The joiner's warrior
- * 3. `[writable]` battle_room: {@link BattleRoom} The battle room account
+ * 0. `[writable, signer]` caller: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` config: {@link Config}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const joinBattleRoomBuilder = (
-	args: JoinBattleRoomArgs,
+export const forceUndelegateBuilder = (
+	args: ForceUndelegateArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<RustUndead, never> => {
-
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const magicProgramPubkey = MAGIC_PROGRAM_ID;
+  const magicContextPubkey = MAGIC_CONTEXT_ID;
+  const eventAuthorityPubkey = pda.deriveEventAuthoritySeedPDA(_program.programId)[0];
+  const programPubkey = _program.programId;
 
   return _program
     .methods
-    .joinBattleRoom(
-
+    .forceUndelegate(
+      args.roomId,
     )
     .accountsStrict({
-      feePayer: args.feePayer,
-      joiner: args.joiner,
-      warrior: args.warrior,
-      battleRoom: args.battleRoom,
+      caller: args.caller,
+      battleRoom: battleRoomPubkey,
+      warriorA: args.warriorA,
+      warriorB: args.warriorB,
+      config: configPubkey,
+      magicProgram: magicProgramPubkey,
+      magicContext: magicContextPubkey,
+      eventAuthority: eventAuthorityPubkey,
+      program: programPubkey,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Join an existing battle room
+ * Crank for delegated rooms left idle past the timeout
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` joiner: {@link PublicKey} The player joining the battle
- * 2. `[writable]` warrior: {@link Warrior} The joiner's warrior
- * 3. `[writable]` battle_room: {@link BattleRoom} The battle room account
+ * 0. `[writable, signer]` caller: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` config: {@link Config}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const joinBattleRoom = (
-	args: JoinBattleRoomArgs,
+export const forceUndelegate = (
+	args: ForceUndelegateArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): Promise<web3.TransactionInstruction> =>
-    joinBattleRoomBuilder(args, remainingAccounts).instruction();
+    forceUndelegateBuilder(args, remainingAccounts).instruction();
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Join an existing battle room
+ * Crank for delegated rooms left idle past the timeout
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` joiner: {@link PublicKey} The player joining the battle
- * 2. `[writable]` warrior: {@link Warrior} The joiner's warrior
- * 3. `[writable]` battle_room: {@link BattleRoom} The battle room account
+ * 0. `[writable, signer]` caller: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[]` config: {@link Config}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const joinBattleRoomSendAndConfirm = async (
-  args: Omit<JoinBattleRoomArgs, "feePayer" | "joiner"> & {
+export const forceUndelegateSendAndConfirm = async (
+  args: Omit<ForceUndelegateArgs, "caller"> & {
     signers: {
-      feePayer: web3.Signer,
-      joiner: web3.Signer,
+      caller: web3.Signer,
     },
   },
   remainingAccounts: Array<web3.AccountMeta> = [],
@@ -513,89 +3950,96 @@ export const joinBattleRoomSendAndConfirm = async (
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-  return joinBattleRoomBuilder({
+  return forceUndelegateBuilder({
       ...args,
-      feePayer: args.signers.feePayer.publicKey,
-      joiner: args.signers.joiner.publicKey,
+      caller: args.signers.caller.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.joiner])
+    .signers([args.signers.caller])
     .rpc();
 }
 
-export type SelectBattleConceptsArgs = {
-  feePayer: web3.PublicKey;
-  authority: web3.PublicKey;
-  battleRoom: web3.PublicKey;
-  vrfClient: web3.PublicKey;
-  vrfProgram: web3.PublicKey;
+export type CancelEmptyBattleRoomArgs = {
+  player: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorB?: web3.PublicKey;
+  roomId: Array<number>;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Use VRF to select 5 concepts for the battle
+ * Cancel a battle room no one joined
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` authority: {@link PublicKey} Either player can trigger concept selection
- * 2. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 3. `[writable]` vrf_client: {@link PublicKey} The Magic Block VRF client account
- * 4. `[]` vrf_program: {@link PublicKey} The Magic Block VRF program
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior} (optional)
+ * 4. `[]` config: {@link Config}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const selectBattleConceptsBuilder = (
-	args: SelectBattleConceptsArgs,
+export const cancelEmptyBattleRoomBuilder = (
+	args: CancelEmptyBattleRoomArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<RustUndead, never> => {
-
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
 
   return _program
     .methods
-    .selectBattleConcepts(
-
+    .cancelEmptyBattleRoom(
+      args.roomId,
     )
     .accountsStrict({
-      feePayer: args.feePayer,
-      authority: args.authority,
-      battleRoom: args.battleRoom,
-      vrfClient: args.vrfClient,
-      vrfProgram: args.vrfProgram,
+      player: args.player,
+      battleRoom: battleRoomPubkey,
+      warriorA: args.warriorA,
+      warriorB: args.warriorB ?? null,
+      config: configPubkey,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Use VRF to select 5 concepts for the battle
+ * Cancel a battle room no one joined
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` authority: {@link PublicKey} Either player can trigger concept selection
- * 2. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 3. `[writable]` vrf_client: {@link PublicKey} The Magic Block VRF client account
- * 4. `[]` vrf_program: {@link PublicKey} The Magic Block VRF program
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior} (optional)
+ * 4. `[]` config: {@link Config}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const selectBattleConcepts = (
-	args: SelectBattleConceptsArgs,
+export const cancelEmptyBattleRoom = (
+	args: CancelEmptyBattleRoomArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): Promise<web3.TransactionInstruction> =>
-    selectBattleConceptsBuilder(args, remainingAccounts).instruction();
+    cancelEmptyBattleRoomBuilder(args, remainingAccounts).instruction();
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Use VRF to select 5 concepts for the battle
+ * Cancel a battle room no one joined
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` authority: {@link PublicKey} Either player can trigger concept selection
- * 2. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 3. `[writable]` vrf_client: {@link PublicKey} The Magic Block VRF client account
- * 4. `[]` vrf_program: {@link PublicKey} The Magic Block VRF program
+ * 0. `[writable, signer]` player: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior} (optional)
+ * 4. `[]` config: {@link Config}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const selectBattleConceptsSendAndConfirm = async (
-  args: Omit<SelectBattleConceptsArgs, "feePayer" | "authority"> & {
+export const cancelEmptyBattleRoomSendAndConfirm = async (
+  args: Omit<CancelEmptyBattleRoomArgs, "player"> & {
     signers: {
-      feePayer: web3.Signer,
-      authority: web3.Signer,
+      player: web3.Signer,
     },
   },
   remainingAccounts: Array<web3.AccountMeta> = [],
@@ -603,83 +4047,141 @@ export const selectBattleConceptsSendAndConfirm = async (
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-  return selectBattleConceptsBuilder({
+  return cancelEmptyBattleRoomBuilder({
       ...args,
-      feePayer: args.signers.feePayer.publicKey,
-      authority: args.signers.authority.publicKey,
+      player: args.signers.player.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.authority])
+    .signers([args.signers.player])
     .rpc();
 }
 
-export type FinalizeConceptSelectionArgs = {
-  feePayer: web3.PublicKey;
-  battleRoom: web3.PublicKey;
-  vrfClient: web3.PublicKey;
-  vrfProgram: web3.PublicKey;
+export type UpdateFinalStateArgs = {
+  authority: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorB: web3.PublicKey;
+  profileA: web3.PublicKey;
+  profileB: web3.PublicKey;
+  achievementsA: web3.PublicKey;
+  achievementsB: web3.PublicKey;
+  seasonLeaderboard: web3.PublicKey;
+  roomId: Array<number>;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Finalize concept selection with VRF result
+ * Record the final battle state on the base layer
  *
  * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} Auto-generated, default fee payer
- * 1. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 2. `[]` vrf_client: {@link PublicKey} The Magic Block VRF client account
- * 3. `[]` vrf_program: {@link PublicKey} The Magic Block VRF program
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[writable]` profile_a: {@link UserProfile}
+ * 5. `[writable]` profile_b: {@link UserProfile}
+ * 6. `[writable]` achievements_a: {@link UserAchievements}
+ * 7. `[writable]` achievements_b: {@link UserAchievements}
+ * 8. `[writable]` config: {@link Config}
+ * 9. `[writable]` leaderboard: {@link Leaderboard}
+ * 10. `[writable]` rating_leaderboard: {@link Leaderboard}
+ * 11. `[writable]` season_leaderboard: {@link Leaderboard}
+ * 12. `[]` event_authority: {@link PublicKey}
+ * 13. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const finalizeConceptSelectionBuilder = (
-	args: FinalizeConceptSelectionArgs,
+export const updateFinalStateBuilder = (
+	args: UpdateFinalStateArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<RustUndead, never> => {
-
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const leaderboardPubkey = pda.deriveLeaderboardSeedPDA(_program.programId)[0];
+  const ratingLeaderboardPubkey = pda.deriveRatingLeaderboardSeedPDA(_program.programId)[0];
+  const eventAuthorityPubkey = pda.deriveEventAuthoritySeedPDA(_program.programId)[0];
+  const programPubkey = _program.programId;
 
   return _program
     .methods
-    .finalizeConceptSelection(
-
+    .updateFinalState(
+      args.roomId,
     )
     .accountsStrict({
-      feePayer: args.feePayer,
-      battleRoom: args.battleRoom,
-      vrfClient: args.vrfClient,
-      vrfProgram: args.vrfProgram,
+      authority: args.authority,
+      battleRoom: battleRoomPubkey,
+      warriorA: args.warriorA,
+      warriorB: args.warriorB,
+      profileA: args.profileA,
+      profileB: args.profileB,
+      achievementsA: args.achievementsA,
+      achievementsB: args.achievementsB,
+      config: configPubkey,
+      leaderboard: leaderboardPubkey,
+      ratingLeaderboard: ratingLeaderboardPubkey,
+      seasonLeaderboard: args.seasonLeaderboard,
+      eventAuthority: eventAuthorityPubkey,
+      program: programPubkey,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Finalize concept selection with VRF result
+ * Record the final battle state on the base layer
  *
  * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} Auto-generated, default fee payer
- * 1. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 2. `[]` vrf_client: {@link PublicKey} The Magic Block VRF client account
- * 3. `[]` vrf_program: {@link PublicKey} The Magic Block VRF program
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[writable]` profile_a: {@link UserProfile}
+ * 5. `[writable]` profile_b: {@link UserProfile}
+ * 6. `[writable]` achievements_a: {@link UserAchievements}
+ * 7. `[writable]` achievements_b: {@link UserAchievements}
+ * 8. `[writable]` config: {@link Config}
+ * 9. `[writable]` leaderboard: {@link Leaderboard}
+ * 10. `[writable]` rating_leaderboard: {@link Leaderboard}
+ * 11. `[writable]` season_leaderboard: {@link Leaderboard}
+ * 12. `[]` event_authority: {@link PublicKey}
+ * 13. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const finalizeConceptSelection = (
-	args: FinalizeConceptSelectionArgs,
+export const updateFinalState = (
+	args: UpdateFinalStateArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): Promise<web3.TransactionInstruction> =>
-    finalizeConceptSelectionBuilder(args, remainingAccounts).instruction();
+    updateFinalStateBuilder(args, remainingAccounts).instruction();
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Finalize concept selection with VRF result
+ * Record the final battle state on the base layer
  *
  * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} Auto-generated, default fee payer
- * 1. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 2. `[]` vrf_client: {@link PublicKey} The Magic Block VRF client account
- * 3. `[]` vrf_program: {@link PublicKey} The Magic Block VRF program
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[writable]` profile_a: {@link UserProfile}
+ * 5. `[writable]` profile_b: {@link UserProfile}
+ * 6. `[writable]` achievements_a: {@link UserAchievements}
+ * 7. `[writable]` achievements_b: {@link UserAchievements}
+ * 8. `[writable]` config: {@link Config}
+ * 9. `[writable]` leaderboard: {@link Leaderboard}
+ * 10. `[writable]` rating_leaderboard: {@link Leaderboard}
+ * 11. `[writable]` season_leaderboard: {@link Leaderboard}
+ * 12. `[]` event_authority: {@link PublicKey}
+ * 13. `[]` program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const finalizeConceptSelectionSendAndConfirm = async (
-  args: Omit<FinalizeConceptSelectionArgs, "feePayer"> & {
+export const updateFinalStateSendAndConfirm = async (
+  args: Omit<UpdateFinalStateArgs, "authority"> & {
     signers: {
-      feePayer: web3.Signer,
+      authority: web3.Signer,
     },
   },
   remainingAccounts: Array<web3.AccountMeta> = [],
@@ -687,78 +4189,131 @@ export const finalizeConceptSelectionSendAndConfirm = async (
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-  return finalizeConceptSelectionBuilder({
+  return updateFinalStateBuilder({
       ...args,
-      feePayer: args.signers.feePayer.publicKey,
+      authority: args.signers.authority.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer])
+    .signers([args.signers.authority])
     .rpc();
 }
 
-export type MarkReadyForBattleArgs = {
-  feePayer: web3.PublicKey;
-  player: web3.PublicKey;
-  battleRoom: web3.PublicKey;
+export type SettleWagerArgs = {
+  authority: web3.PublicKey;
+  winner: web3.PublicKey;
+  creator: web3.PublicKey;
+  treasury: web3.PublicKey;
+  wagerVault?: web3.PublicKey;
+  winnerTokenAccount?: web3.PublicKey;
+  treasuryTokenAccount?: web3.PublicKey;
+  tokenProgram?: web3.PublicKey;
+  roomId: Array<number>;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Mark a player as ready for battle after studying concepts
+ * Pay a wagered pot to the winner, minus the protocol fee
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` player: {@link PublicKey} The player marking themselves as ready
- * 2. `[writable]` battle_room: {@link BattleRoom} The battle room account
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` winner: {@link PublicKey}
+ * 3. `[writable]` creator: {@link PublicKey}
+ * 4. `[]` config: {@link Config}
+ * 5. `[writable]` treasury: {@link PublicKey}
+ * 6. `[writable]` wager_escrow: {@link PublicKey}
+ * 7. `[writable]` wager_vault: {@link PublicKey} (optional)
+ * 8. `[writable]` winner_token_account: {@link PublicKey} (optional)
+ * 9. `[writable]` treasury_token_account: {@link PublicKey} (optional)
+ * 10. `[]` token_program: {@link PublicKey} (optional)
+ * 11. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const markReadyForBattleBuilder = (
-	args: MarkReadyForBattleArgs,
+export const settleWagerBuilder = (
+	args: SettleWagerArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<RustUndead, never> => {
-
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const wagerEscrowPubkey = pda.deriveWagerEscrowSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
 
   return _program
     .methods
-    .markReadyForBattle(
-
+    .settleWager(
+      args.roomId,
     )
     .accountsStrict({
-      feePayer: args.feePayer,
-      player: args.player,
-      battleRoom: args.battleRoom,
+      authority: args.authority,
+      battleRoom: battleRoomPubkey,
+      winner: args.winner,
+      creator: args.creator,
+      config: configPubkey,
+      treasury: args.treasury,
+      wagerEscrow: wagerEscrowPubkey,
+      wagerVault: args.wagerVault ?? null,
+      winnerTokenAccount: args.winnerTokenAccount ?? null,
+      treasuryTokenAccount: args.treasuryTokenAccount ?? null,
+      tokenProgram: args.tokenProgram ?? null,
+      systemProgram: systemProgramPubkey,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Mark a player as ready for battle after studying concepts
+ * Pay a wagered pot to the winner, minus the protocol fee
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` player: {@link PublicKey} The player marking themselves as ready
- * 2. `[writable]` battle_room: {@link BattleRoom} The battle room account
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` winner: {@link PublicKey}
+ * 3. `[writable]` creator: {@link PublicKey}
+ * 4. `[]` config: {@link Config}
+ * 5. `[writable]` treasury: {@link PublicKey}
+ * 6. `[writable]` wager_escrow: {@link PublicKey}
+ * 7. `[writable]` wager_vault: {@link PublicKey} (optional)
+ * 8. `[writable]` winner_token_account: {@link PublicKey} (optional)
+ * 9. `[writable]` treasury_token_account: {@link PublicKey} (optional)
+ * 10. `[]` token_program: {@link PublicKey} (optional)
+ * 11. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const markReadyForBattle = (
-	args: MarkReadyForBattleArgs,
+export const settleWager = (
+	args: SettleWagerArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): Promise<web3.TransactionInstruction> =>
-    markReadyForBattleBuilder(args, remainingAccounts).instruction();
+    settleWagerBuilder(args, remainingAccounts).instruction();
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Mark a player as ready for battle after studying concepts
+ * Pay a wagered pot to the winner, minus the protocol fee
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` player: {@link PublicKey} The player marking themselves as ready
- * 2. `[writable]` battle_room: {@link BattleRoom} The battle room account
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` winner: {@link PublicKey}
+ * 3. `[writable]` creator: {@link PublicKey}
+ * 4. `[]` config: {@link Config}
+ * 5. `[writable]` treasury: {@link PublicKey}
+ * 6. `[writable]` wager_escrow: {@link PublicKey}
+ * 7. `[writable]` wager_vault: {@link PublicKey} (optional)
+ * 8. `[writable]` winner_token_account: {@link PublicKey} (optional)
+ * 9. `[writable]` treasury_token_account: {@link PublicKey} (optional)
+ * 10. `[]` token_program: {@link PublicKey} (optional)
+ * 11. `[]` system_program: {@link PublicKey}
+ *
+ * Data:
+ * - room_id: {@link number[]}
  */
-export const markReadyForBattleSendAndConfirm = async (
-  args: Omit<MarkReadyForBattleArgs, "feePayer" | "player"> & {
+export const settleWagerSendAndConfirm = async (
+  args: Omit<SettleWagerArgs, "authority"> & {
     signers: {
-      feePayer: web3.Signer,
-      player: web3.Signer,
+      authority: web3.Signer,
     },
   },
   remainingAccounts: Array<web3.AccountMeta> = [],
@@ -766,98 +4321,120 @@ export const markReadyForBattleSendAndConfirm = async (
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-  return markReadyForBattleBuilder({
+  return settleWagerBuilder({
       ...args,
-      feePayer: args.signers.feePayer.publicKey,
-      player: args.signers.player.publicKey,
+      authority: args.signers.authority.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.player])
+    .signers([args.signers.authority])
     .rpc();
 }
 
-export type DelegateToEphemeralRollupArgs = {
-  feePayer: web3.PublicKey;
+export type RefundWagerArgs = {
   authority: web3.PublicKey;
-  battleRoom: web3.PublicKey;
-  warriorA: web3.PublicKey;
-  warriorB: web3.PublicKey;
-  erSessionId: bigint;
+  playerA: web3.PublicKey;
+  playerB?: web3.PublicKey;
+  wagerVault?: web3.PublicKey;
+  playerATokenAccount?: web3.PublicKey;
+  playerBTokenAccount?: web3.PublicKey;
+  tokenProgram?: web3.PublicKey;
+  roomId: Array<number>;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Delegate warriors to ephemeral rollup for real-time battle
+ * Return stakes from a no-contest room
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` authority: {@link PublicKey} Either player can trigger delegation
- * 2. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 3. `[writable]` warrior_a: {@link Warrior} First warrior in the battle
- * 4. `[writable]` warrior_b: {@link Warrior} Second warrior in the battle
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` player_a: {@link PublicKey}
+ * 3. `[writable]` player_b: {@link PublicKey} (optional)
+ * 4. `[writable]` wager_escrow: {@link PublicKey}
+ * 5. `[writable]` wager_vault: {@link PublicKey} (optional)
+ * 6. `[writable]` player_a_token_account: {@link PublicKey} (optional)
+ * 7. `[writable]` player_b_token_account: {@link PublicKey} (optional)
+ * 8. `[]` token_program: {@link PublicKey} (optional)
+ * 9. `[]` system_program: {@link PublicKey}
  *
  * Data:
- * - er_session_id: {@link BigInt} Ephemeral rollup session identifier
+ * - room_id: {@link number[]}
  */
-export const delegateToEphemeralRollupBuilder = (
-	args: DelegateToEphemeralRollupArgs,
+export const refundWagerBuilder = (
+	args: RefundWagerArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<RustUndead, never> => {
-
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const wagerEscrowPubkey = pda.deriveWagerEscrowSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const systemProgramPubkey = web3.SystemProgram.programId;
 
   return _program
     .methods
-    .delegateToEphemeralRollup(
-      new BN(args.erSessionId.toString()),
+    .refundWager(
+      args.roomId,
     )
     .accountsStrict({
-      feePayer: args.feePayer,
       authority: args.authority,
-      battleRoom: args.battleRoom,
-      warriorA: args.warriorA,
-      warriorB: args.warriorB,
+      battleRoom: battleRoomPubkey,
+      playerA: args.playerA,
+      playerB: args.playerB ?? null,
+      wagerEscrow: wagerEscrowPubkey,
+      wagerVault: args.wagerVault ?? null,
+      playerATokenAccount: args.playerATokenAccount ?? null,
+      playerBTokenAccount: args.playerBTokenAccount ?? null,
+      tokenProgram: args.tokenProgram ?? null,
+      systemProgram: systemProgramPubkey,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Delegate warriors to ephemeral rollup for real-time battle
+ * Return stakes from a no-contest room
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` authority: {@link PublicKey} Either player can trigger delegation
- * 2. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 3. `[writable]` warrior_a: {@link Warrior} First warrior in the battle
- * 4. `[writable]` warrior_b: {@link Warrior} Second warrior in the battle
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` player_a: {@link PublicKey}
+ * 3. `[writable]` player_b: {@link PublicKey} (optional)
+ * 4. `[writable]` wager_escrow: {@link PublicKey}
+ * 5. `[writable]` wager_vault: {@link PublicKey} (optional)
+ * 6. `[writable]` player_a_token_account: {@link PublicKey} (optional)
+ * 7. `[writable]` player_b_token_account: {@link PublicKey} (optional)
+ * 8. `[]` token_program: {@link PublicKey} (optional)
+ * 9. `[]` system_program: {@link PublicKey}
  *
  * Data:
- * - er_session_id: {@link BigInt} Ephemeral rollup session identifier
+ * - room_id: {@link number[]}
  */
-export const delegateToEphemeralRollup = (
-	args: DelegateToEphemeralRollupArgs,
+export const refundWager = (
+	args: RefundWagerArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): Promise<web3.TransactionInstruction> =>
-    delegateToEphemeralRollupBuilder(args, remainingAccounts).instruction();
+    refundWagerBuilder(args, remainingAccounts).instruction();
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Delegate warriors to ephemeral rollup for real-time battle
+ * Return stakes from a no-contest room
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` authority: {@link PublicKey} Either player can trigger delegation
- * 2. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 3. `[writable]` warrior_a: {@link Warrior} First warrior in the battle
- * 4. `[writable]` warrior_b: {@link Warrior} Second warrior in the battle
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` player_a: {@link PublicKey}
+ * 3. `[writable]` player_b: {@link PublicKey} (optional)
+ * 4. `[writable]` wager_escrow: {@link PublicKey}
+ * 5. `[writable]` wager_vault: {@link PublicKey} (optional)
+ * 6. `[writable]` player_a_token_account: {@link PublicKey} (optional)
+ * 7. `[writable]` player_b_token_account: {@link PublicKey} (optional)
+ * 8. `[]` token_program: {@link PublicKey} (optional)
+ * 9. `[]` system_program: {@link PublicKey}
  *
  * Data:
- * - er_session_id: {@link BigInt} Ephemeral rollup session identifier
+ * - room_id: {@link number[]}
  */
-export const delegateToEphemeralRollupSendAndConfirm = async (
-  args: Omit<DelegateToEphemeralRollupArgs, "feePayer" | "authority"> & {
+export const refundWagerSendAndConfirm = async (
+  args: Omit<RefundWagerArgs, "authority"> & {
     signers: {
-      feePayer: web3.Signer,
       authority: web3.Signer,
     },
   },
@@ -866,128 +4443,96 @@ export const delegateToEphemeralRollupSendAndConfirm = async (
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-  return delegateToEphemeralRollupBuilder({
+  return refundWagerBuilder({
       ...args,
-      feePayer: args.signers.feePayer.publicKey,
       authority: args.signers.authority.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.authority])
+    .signers([args.signers.authority])
     .rpc();
 }
 
-export type SettleBattleResultsArgs = {
-  feePayer: web3.PublicKey;
-  settlementAuthority: web3.PublicKey;
-  battleRoom: web3.PublicKey;
-  warriorA: web3.PublicKey;
-  warriorB: web3.PublicKey;
-  winner: web3.PublicKey;
-  totalQuestions: number;
-  playerACorrect: number;
-  playerBCorrect: number;
-  battleDuration: number;
-  criticalHits: number;
+export type CloseBattleRoomArgs = {
+  authority: web3.PublicKey;
+  rentPayer: web3.PublicKey;
+  historyA?: web3.PublicKey;
+  historyB?: web3.PublicKey;
+  roomId: Array<number>;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Settle battle results from ephemeral rollup back to mainnet
+ * Close a finished room, rent goes back to whoever paid it
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` settlement_authority: {@link PublicKey} Authorized settlement bridge or admin
- * 2. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 3. `[writable]` warrior_a: {@link Warrior} First warrior in the battle
- * 4. `[writable]` warrior_b: {@link Warrior} Second warrior in the battle
- * 5. `[writable]` game_state: {@link GameState} The global game state account
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` rent_payer: {@link PublicKey}
+ * 2. `[writable]` battle_room: {@link BattleRoom}
+ * 3. `[writable]` history_a: {@link WarriorHistory} (optional)
+ * 4. `[writable]` history_b: {@link WarriorHistory} (optional)
  *
  * Data:
- * - winner: {@link PublicKey} The winner of the battle
- * - total_questions: {@link number} Total questions in the battle
- * - player_a_correct: {@link number} Correct answers by player A
- * - player_b_correct: {@link number} Correct answers by player B
- * - battle_duration: {@link number} Battle duration in seconds
- * - critical_hits: {@link number} Total critical hits
+ * - room_id: {@link number[]}
  */
-export const settleBattleResultsBuilder = (
-	args: SettleBattleResultsArgs,
+export const closeBattleRoomBuilder = (
+	args: CloseBattleRoomArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<RustUndead, never> => {
-  const [gameStatePubkey] = pda.deriveGameStateSeedPDA(_program.programId);
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
 
   return _program
     .methods
-    .settleBattleResults(
-      args.winner,
-      args.totalQuestions,
-      args.playerACorrect,
-      args.playerBCorrect,
-      args.battleDuration,
-      args.criticalHits,
+    .closeBattleRoom(
+      args.roomId,
     )
     .accountsStrict({
-      feePayer: args.feePayer,
-      settlementAuthority: args.settlementAuthority,
-      battleRoom: args.battleRoom,
-      warriorA: args.warriorA,
-      warriorB: args.warriorB,
-      gameState: gameStatePubkey,
+      authority: args.authority,
+      rentPayer: args.rentPayer,
+      battleRoom: battleRoomPubkey,
+      historyA: args.historyA ?? null,
+      historyB: args.historyB ?? null,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Settle battle results from ephemeral rollup back to mainnet
+ * Close a finished room, rent goes back to whoever paid it
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` settlement_authority: {@link PublicKey} Authorized settlement bridge or admin
- * 2. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 3. `[writable]` warrior_a: {@link Warrior} First warrior in the battle
- * 4. `[writable]` warrior_b: {@link Warrior} Second warrior in the battle
- * 5. `[writable]` game_state: {@link GameState} The global game state account
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` rent_payer: {@link PublicKey}
+ * 2. `[writable]` battle_room: {@link BattleRoom}
+ * 3. `[writable]` history_a: {@link WarriorHistory} (optional)
+ * 4. `[writable]` history_b: {@link WarriorHistory} (optional)
  *
  * Data:
- * - winner: {@link PublicKey} The winner of the battle
- * - total_questions: {@link number} Total questions in the battle
- * - player_a_correct: {@link number} Correct answers by player A
- * - player_b_correct: {@link number} Correct answers by player B
- * - battle_duration: {@link number} Battle duration in seconds
- * - critical_hits: {@link number} Total critical hits
+ * - room_id: {@link number[]}
  */
-export const settleBattleResults = (
-	args: SettleBattleResultsArgs,
+export const closeBattleRoom = (
+	args: CloseBattleRoomArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): Promise<web3.TransactionInstruction> =>
-    settleBattleResultsBuilder(args, remainingAccounts).instruction();
+    closeBattleRoomBuilder(args, remainingAccounts).instruction();
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Settle battle results from ephemeral rollup back to mainnet
+ * Close a finished room, rent goes back to whoever paid it
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` settlement_authority: {@link PublicKey} Authorized settlement bridge or admin
- * 2. `[writable]` battle_room: {@link BattleRoom} The battle room account
- * 3. `[writable]` warrior_a: {@link Warrior} First warrior in the battle
- * 4. `[writable]` warrior_b: {@link Warrior} Second warrior in the battle
- * 5. `[writable]` game_state: {@link GameState} The global game state account
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` rent_payer: {@link PublicKey}
+ * 2. `[writable]` battle_room: {@link BattleRoom}
+ * 3. `[writable]` history_a: {@link WarriorHistory} (optional)
+ * 4. `[writable]` history_b: {@link WarriorHistory} (optional)
  *
  * Data:
- * - winner: {@link PublicKey} The winner of the battle
- * - total_questions: {@link number} Total questions in the battle
- * - player_a_correct: {@link number} Correct answers by player A
- * - player_b_correct: {@link number} Correct answers by player B
- * - battle_duration: {@link number} Battle duration in seconds
- * - critical_hits: {@link number} Total critical hits
+ * - room_id: {@link number[]}
  */
-export const settleBattleResultsSendAndConfirm = async (
-  args: Omit<SettleBattleResultsArgs, "feePayer" | "settlementAuthority"> & {
+export const closeBattleRoomSendAndConfirm = async (
+  args: Omit<CloseBattleRoomArgs, "authority"> & {
     signers: {
-      feePayer: web3.Signer,
-      settlementAuthority: web3.Signer,
+      authority: web3.Signer,
     },
   },
   remainingAccounts: Array<web3.AccountMeta> = [],
@@ -995,97 +4540,115 @@ export const settleBattleResultsSendAndConfirm = async (
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-  return settleBattleResultsBuilder({
+  return closeBattleRoomBuilder({
       ...args,
-      feePayer: args.signers.feePayer.publicKey,
-      settlementAuthority: args.signers.settlementAuthority.publicKey,
+      authority: args.signers.authority.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.settlementAuthority])
+    .signers([args.signers.authority])
     .rpc();
 }
 
-export type UpdateGameConfigArgs = {
-  feePayer: web3.PublicKey;
+export type EmergencyCancelBattleArgs = {
   authority: web3.PublicKey;
-  newWarriorCreationFee: bigint;
-  newBattleEntryFee: bigint;
-  newVrfOracle: web3.PublicKey;
+  warriorA: web3.PublicKey;
+  warriorB: web3.PublicKey;
+  roomId: Array<number>;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Update game configuration (admin only)
+ * Emergency cancel a battle room
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` authority: {@link PublicKey} The game authority
- * 2. `[writable]` game_state: {@link GameState} The global game state account
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[writable]` config: {@link Config}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
  *
  * Data:
- * - new_warrior_creation_fee: {@link BigInt} New warrior creation fee (optional)
- * - new_battle_entry_fee: {@link BigInt} New battle entry fee (optional)
- * - new_vrf_oracle: {@link PublicKey} New VRF oracle (optional)
+ * - room_id: {@link number[]}
  */
-export const updateGameConfigBuilder = (
-	args: UpdateGameConfigArgs,
+export const emergencyCancelBattleBuilder = (
+	args: EmergencyCancelBattleArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<RustUndead, never> => {
-  const [gameStatePubkey] = pda.deriveGameStateSeedPDA(_program.programId);
+  const battleRoomPubkey = pda.deriveBattleRoomSeedPDA({ roomId: args.roomId }, _program.programId)[0];
+  const configPubkey = pda.deriveConfigSeedPDA(_program.programId)[0];
+  const magicProgramPubkey = MAGIC_PROGRAM_ID;
+  const magicContextPubkey = MAGIC_CONTEXT_ID;
+  const eventAuthorityPubkey = pda.deriveEventAuthoritySeedPDA(_program.programId)[0];
+  const programPubkey = _program.programId;
 
   return _program
     .methods
-    .updateGameConfig(
-      new BN(args.newWarriorCreationFee.toString()),
-      new BN(args.newBattleEntryFee.toString()),
-      args.newVrfOracle,
+    .emergencyCancelBattle(
+      args.roomId,
     )
     .accountsStrict({
-      feePayer: args.feePayer,
       authority: args.authority,
-      gameState: gameStatePubkey,
+      battleRoom: battleRoomPubkey,
+      warriorA: args.warriorA,
+      warriorB: args.warriorB,
+      config: configPubkey,
+      magicProgram: magicProgramPubkey,
+      magicContext: magicContextPubkey,
+      eventAuthority: eventAuthorityPubkey,
+      program: programPubkey,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Update game configuration (admin only)
+ * Emergency cancel a battle room
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` authority: {@link PublicKey} The game authority
- * 2. `[writable]` game_state: {@link GameState} The global game state account
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[writable]` config: {@link Config}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
  *
  * Data:
- * - new_warrior_creation_fee: {@link BigInt} New warrior creation fee (optional)
- * - new_battle_entry_fee: {@link BigInt} New battle entry fee (optional)
- * - new_vrf_oracle: {@link PublicKey} New VRF oracle (optional)
+ * - room_id: {@link number[]}
  */
-export const updateGameConfig = (
-	args: UpdateGameConfigArgs,
+export const emergencyCancelBattle = (
+	args: EmergencyCancelBattleArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): Promise<web3.TransactionInstruction> =>
-    updateGameConfigBuilder(args, remainingAccounts).instruction();
+    emergencyCancelBattleBuilder(args, remainingAccounts).instruction();
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Update game configuration (admin only)
+ * Emergency cancel a battle room
  *
  * Accounts:
- * 0. `[signer]` fee_payer: {@link PublicKey} 
- * 1. `[signer]` authority: {@link PublicKey} The game authority
- * 2. `[writable]` game_state: {@link GameState} The global game state account
+ * 0. `[writable, signer]` authority: {@link PublicKey}
+ * 1. `[writable]` battle_room: {@link BattleRoom}
+ * 2. `[writable]` warrior_a: {@link UndeadWarrior}
+ * 3. `[writable]` warrior_b: {@link UndeadWarrior}
+ * 4. `[writable]` config: {@link Config}
+ * 5. `[]` magic_program: {@link PublicKey}
+ * 6. `[writable]` magic_context: {@link PublicKey}
+ * 7. `[]` event_authority: {@link PublicKey}
+ * 8. `[]` program: {@link PublicKey}
  *
  * Data:
- * - new_warrior_creation_fee: {@link BigInt} New warrior creation fee (optional)
- * - new_battle_entry_fee: {@link BigInt} New battle entry fee (optional)
- * - new_vrf_oracle: {@link PublicKey} New VRF oracle (optional)
+ * - room_id: {@link number[]}
  */
-export const updateGameConfigSendAndConfirm = async (
-  args: Omit<UpdateGameConfigArgs, "feePayer" | "authority"> & {
+export const emergencyCancelBattleSendAndConfirm = async (
+  args: Omit<EmergencyCancelBattleArgs, "authority"> & {
     signers: {
-      feePayer: web3.Signer,
       authority: web3.Signer,
     },
   },
@@ -1094,29 +4657,73 @@ export const updateGameConfigSendAndConfirm = async (
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-  return updateGameConfigBuilder({
+  return emergencyCancelBattleBuilder({
       ...args,
-      feePayer: args.signers.feePayer.publicKey,
       authority: args.signers.authority.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.authority])
+    .signers([args.signers.authority])
     .rpc();
 }
 
 // Getters
 
-export const getGameState = (
+export const getBattleRoom = (
     publicKey: web3.PublicKey,
     commitment?: web3.Commitment
-): Promise<IdlAccounts<RustUndead>["gameState"]> => _program.account.gameState.fetch(publicKey, commitment);
+): Promise<IdlAccounts<RustUndead>["battleRoom"]> => _program.account.battleRoom.fetch(publicKey, commitment);
 
-export const getWarrior = (
+export const getWarriorHistory = (
     publicKey: web3.PublicKey,
     commitment?: web3.Commitment
-): Promise<IdlAccounts<RustUndead>["warrior"]> => _program.account.warrior.fetch(publicKey, commitment);
+): Promise<IdlAccounts<RustUndead>["warriorHistory"]> => _program.account.warriorHistory.fetch(publicKey, commitment);
 
-export const getBattleRoom = (
+export const getConfig = (
     publicKey: web3.PublicKey,
     commitment?: web3.Commitment
-): Promise<IdlAccounts<RustUndead>["battleRoom"]> => _program.account.battleRoom.fetch(publicKey, commitment);
+): Promise<IdlAccounts<RustUndead>["config"]> => _program.account.config.fetch(publicKey, commitment);
+
+export const getUserAchievements = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<RustUndead>["userAchievements"]> => _program.account.userAchievements.fetch(publicKey, commitment);
+
+export const getUserProfile = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<RustUndead>["userProfile"]> => _program.account.userProfile.fetch(publicKey, commitment);
+
+export const getGraveyard = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<RustUndead>["graveyard"]> => _program.account.graveyard.fetch(publicKey, commitment);
+
+export const getLeaderboard = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<RustUndead>["leaderboard"]> => _program.account.leaderboard.fetch(publicKey, commitment);
+
+export const getMatchQueue = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<RustUndead>["matchQueue"]> => _program.account.matchQueue.fetch(publicKey, commitment);
+
+export const getQuestionBank = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<RustUndead>["questionBank"]> => _program.account.questionBank.fetch(publicKey, commitment);
+
+export const getNameRegistry = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<RustUndead>["nameRegistry"]> => _program.account.nameRegistry.fetch(publicKey, commitment);
+
+export const getUndeadWarrior = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<RustUndead>["undeadWarrior"]> => _program.account.undeadWarrior.fetch(publicKey, commitment);
+
+export const getWarriorLookup = (
+    publicKey: web3.PublicKey,
+    commitment?: web3.Commitment
+): Promise<IdlAccounts<RustUndead>["warriorLookup"]> => _program.account.warriorLookup.fetch(publicKey, commitment);
@@ -1,3 +1,5 @@
+use anchor_lang::prelude::*;
+
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
 pub const CONFIG: &[u8] = b"config";
 pub const BATTLE: &[u8] = b"battleroom";
@@ -6,6 +8,87 @@ pub const USER_ACHIEVEMENT: &[u8] =
 b"user_achievements";
 pub const UNDEAD_WARRIOR: &[u8] = b"undead_warrior";
 pub const LEADERBOARD: &[u8] = b"leaderboard";
+pub const RATING_LEADERBOARD: &[u8] = b"rating_leaderboard";
+pub const SEASON_LEADERBOARD: &[u8] = b"season_leaderboard"; // + season number (u32 LE)
+pub const QUESTION_BANK: &[u8] = b"question_bank";
+pub const BATTLE_HISTORY: &[u8] = b"battle_history";
+pub const WAGER_ESCROW: &[u8] = b"wager_escrow";
+pub const WAGER_VAULT: &[u8] = b"wager_vault";
+pub const MATCH_QUEUE: &[u8] = b"match_queue";
+pub const WARRIOR_NFT_MINT: &[u8] = b"warrior_nft_mint";
+pub const NFT_AUTHORITY: &[u8] = b"nft_authority";
+pub const NAME_REGISTRY: &[u8] = b"name_registry";
+pub const WARRIOR_LOOKUP: &[u8] = b"warrior_lookup";
+pub const GRAVEYARD: &[u8] = b"graveyard";
+pub const METADATA: &[u8] = b"metadata"; // token metadata program's own seed
+
+// post-battle cooldown defaults
+pub const DEFAULT_WINNER_COOLDOWN_PCT: u8 = 50;
+pub const DEFAULT_LOSER_COOLDOWN_PCT: u8 = 100;
+pub const DEFAULT_HP_COOLDOWN_PENALTY_PCT: u8 = 100;
+pub const MAX_COOLDOWN_TIME: u64 = 7 * 24 * 3600;
+pub const MAX_COOLDOWN_PCT: u8 = 100; // a role never serves more than the base cooldown
+pub const MAX_HP_COOLDOWN_PENALTY_PCT: u8 = 200;
+
+// healing defaults
+pub const DEFAULT_HP_REGEN_PER_HOUR: u16 = 20;
+pub const DEFAULT_HEAL_COST_LAMPORTS: u64 = 10_000_000; // 0.01 SOL
+pub const MAX_HEAL_COST_LAMPORTS: u64 = 1_000_000_000; // 1 SOL
+pub const SECONDS_PER_HOUR: i64 = 3600;
+
+// renaming defaults
+pub const DEFAULT_RENAME_FEE_LAMPORTS: u64 = 50_000_000; // 0.05 SOL
+pub const MAX_RENAME_FEE_LAMPORTS: u64 = 1_000_000_000; // 1 SOL
+pub const DEFAULT_RENAME_COOLDOWN: i64 = 7 * 24 * 3600;
+
+// seconds per question before an absent player can be timed out
+pub const DEFAULT_ANSWER_TIMEOUT: i64 = 120;
+// seconds a delegated room can sit idle before anyone can force undelegate it
+pub const DEFAULT_IDLE_TIMEOUT: i64 = 1800;
+
+// wagers
+pub const DEFAULT_WAGER_FEE_BPS: u16 = 250; // 2.5% of the pot
+pub const MAX_WAGER_FEE_BPS: u16 = 1_000;
+pub const MIN_SOL_WAGER_LAMPORTS: u64 = 1_000_000; // keeps the SOL escrow above rent exemption
+
+// leaderboards
+pub const LEADERBOARD_CAPACITY: usize = 4096;
+pub const LEADERBOARD_INDEX_SIZE: usize = 8192; // power of two, kept at 2x capacity
+pub const LEADERBOARD_GROWTH_STEP: usize = 10_240; // max realloc per instruction
+
+// skill rating (Elo)
+pub const DEFAULT_RATING: u32 = 1200;
+pub const MIN_RATING: u32 = 100;
+pub const RATING_PROVISIONAL_BATTLES: u32 = 10; // faster movement while provisional
+pub const RATING_K_PROVISIONAL: i64 = 64;
+pub const RATING_K: i64 = 32;
+
+// matchmaking
+pub const MATCH_QUEUE_CAPACITY: usize = 32;
+pub const MATCH_QUEUE_TTL: i64 = 600; // queued entries expire after 10 minutes
+pub const MATCH_LEVEL_RANGE: u16 = 5; // max level gap between paired warriors
+pub const MATCH_RATING_RANGE: u32 = 300; // max rating gap once both warriors are established
+
+// battle records kept per warrior
+pub const BATTLE_HISTORY_LEN: usize = 10;
+// memorial entries kept per player for retired warriors
+pub const GRAVEYARD_LEN: usize = 10;
+
+// Warrior NFTs, minted as SPL tokens with token metadata
+pub const WARRIOR_NFT_SYMBOL: &str = "UNDEAD";
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+// Questions per battle, chosen when the room's questions are set
+pub const MAX_QUESTIONS: usize = 15;
+pub const ALLOWED_QUESTION_COUNTS: [u8; 3] = [5, 10, 15];
+pub const MIN_ANSWER_OPTIONS: u8 = 2; // true/false
+pub const MAX_ANSWER_OPTIONS: u8 = 6;
+
+// level progression
+pub const DEFAULT_MAX_LEVEL: u16 = 50;
+pub const MAX_LEVEL_CAP: u16 = 100;
+pub const LEVEL_XP_STEP: u64 = 50; // XP to reach level L is LEVEL_XP_STEP * L * (L - 1)
 
 // images suite 
 // IPFS folder hashes
@@ -0,0 +1,11 @@
+pub mod pause;
+pub use pause::*;
+
+pub mod transfer_admin;
+pub use transfer_admin::*;
+
+pub mod update_config;
+pub use update_config::*;
+
+pub mod start_season;
+pub use start_season::*;
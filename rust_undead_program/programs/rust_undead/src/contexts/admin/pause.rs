@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
+
+// admin switch for new gameplay, battles already in progress can still finish and settle
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ RustUndeadError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> SetPause<'info> {
+    pub fn set_paused(&mut self, paused: bool) -> Result<()> {
+        self.config.is_paused = paused;
+
+        if paused {
+            msg!("⏸️ Game paused by admin {}", self.admin.key());
+        } else {
+            msg!("▶️ Game unpaused by admin {}", self.admin.key());
+        }
+
+        Ok(())
+    }
+}
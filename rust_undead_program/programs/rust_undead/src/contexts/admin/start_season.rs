@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
+
+// the next season's board is created and grown ahead of time, so battles
+// never settle against a board that is still being allocated
+#[derive(Accounts)]
+pub struct CreateSeasonLeaderboard<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ RustUndeadError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = LEADERBOARD_GROWTH_STEP,
+        seeds = [SEASON_LEADERBOARD, (config.current_season + 1).to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub next_leaderboard: AccountLoader<'info, Leaderboard>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateSeasonLeaderboard<'info> {
+    pub fn create_season_leaderboard(&mut self) -> Result<()> {
+        msg!(
+            "🏆 Season {} leaderboard created, grow it to {} bytes before starting the season",
+            self.config.current_season + 1,
+            Leaderboard::SPACE
+        );
+
+        Ok(())
+    }
+}
+
+// close the running season, its board is frozen as the final standings and
+// the next board starts collecting points
+#[derive(Accounts)]
+pub struct StartNewSeason<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ RustUndeadError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_LEADERBOARD, config.current_season.to_le_bytes().as_ref()],
+        bump,
+        constraint = Leaderboard::is_allocated(previous_leaderboard.as_ref()) @ RustUndeadError::LeaderboardNotAllocated,
+    )]
+    pub previous_leaderboard: AccountLoader<'info, Leaderboard>,
+
+    #[account(
+        seeds = [SEASON_LEADERBOARD, (config.current_season + 1).to_le_bytes().as_ref()],
+        bump,
+        constraint = Leaderboard::is_allocated(next_leaderboard.as_ref()) @ RustUndeadError::LeaderboardNotAllocated,
+    )]
+    pub next_leaderboard: AccountLoader<'info, Leaderboard>,
+}
+
+impl<'info> StartNewSeason<'info> {
+    pub fn start_new_season(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let config = &mut self.config;
+        let ended_season = config.current_season;
+
+        // Freeze the final standings
+        let mut previous = self.previous_leaderboard.load_mut()?;
+        require!(previous.archived == 0, RustUndeadError::SeasonArchived);
+        previous.archived = 1;
+        previous.last_updated = now;
+
+        config.current_season = ended_season + 1;
+        config.season_started_at = now;
+
+        msg!("🏁 Season {} ended, standings archived ({} players)", ended_season, previous.len);
+        for (rank, (player, score)) in previous.get_top(3).iter().enumerate() {
+            msg!("   #{} {} - {} points", rank + 1, player, score);
+        }
+        msg!("🌱 Season {} started", config.current_season);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
+
+// two-step handover so a typo'd key can't lock the admin out
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ RustUndeadError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> ProposeAdmin<'info> {
+    pub fn propose_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        self.config.pending_admin = Some(new_admin);
+
+        msg!("👑 Admin handover proposed: {} → {}", self.admin.key(), new_admin);
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = config.pending_admin == Some(new_admin.key()) @ RustUndeadError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn accept_admin(&mut self) -> Result<()> {
+        let previous_admin = self.config.admin;
+        self.config.admin = self.new_admin.key();
+        self.config.pending_admin = None;
+
+        msg!("👑 Admin handover complete: {} → {}", previous_admin, self.config.admin);
+
+        Ok(())
+    }
+}
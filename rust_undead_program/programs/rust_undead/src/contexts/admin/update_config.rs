@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
+
+// only the fields that are set get changed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ConfigUpdate {
+    pub cooldown_time: Option<u64>,
+    pub winner_cooldown_pct: Option<u8>,
+    pub loser_cooldown_pct: Option<u8>,
+    pub hp_cooldown_penalty_pct: Option<u8>,
+    pub hp_regen_per_hour: Option<u16>,
+    pub heal_cost_lamports: Option<u64>,
+    pub rename_fee_lamports: Option<u64>,
+    pub rename_cooldown: Option<i64>,
+    pub treasury: Option<Pubkey>,
+    pub wager_fee_bps: Option<u16>,
+    pub max_level: Option<u16>,
+    pub answer_timeout: Option<i64>,
+    pub idle_timeout: Option<i64>,
+    pub question_authority: Option<Pubkey>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = config.admin == admin.key() @ RustUndeadError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> UpdateConfig<'info> {
+    pub fn update_config(&mut self, update: ConfigUpdate) -> Result<()> {
+        let config = &mut self.config;
+
+        if let Some(cooldown_time) = update.cooldown_time {
+            require!(cooldown_time <= MAX_COOLDOWN_TIME, RustUndeadError::InvalidConfigValue);
+            config.cooldown_time = cooldown_time;
+            msg!("⚙️ Cooldown time set to {} seconds", cooldown_time);
+        }
+
+        if let Some(winner_cooldown_pct) = update.winner_cooldown_pct {
+            require!(winner_cooldown_pct <= MAX_COOLDOWN_PCT, RustUndeadError::InvalidConfigValue);
+            config.winner_cooldown_pct = winner_cooldown_pct;
+            msg!("⚙️ Winner cooldown set to {}% of base", winner_cooldown_pct);
+        }
+
+        if let Some(loser_cooldown_pct) = update.loser_cooldown_pct {
+            require!(loser_cooldown_pct <= MAX_COOLDOWN_PCT, RustUndeadError::InvalidConfigValue);
+            config.loser_cooldown_pct = loser_cooldown_pct;
+            msg!("⚙️ Loser cooldown set to {}% of base", loser_cooldown_pct);
+        }
+
+        if let Some(hp_cooldown_penalty_pct) = update.hp_cooldown_penalty_pct {
+            require!(hp_cooldown_penalty_pct <= MAX_HP_COOLDOWN_PENALTY_PCT, RustUndeadError::InvalidConfigValue);
+            config.hp_cooldown_penalty_pct = hp_cooldown_penalty_pct;
+            msg!("⚙️ HP cooldown penalty set to {}%", hp_cooldown_penalty_pct);
+        }
+
+        if let Some(hp_regen_per_hour) = update.hp_regen_per_hour {
+            config.hp_regen_per_hour = hp_regen_per_hour;
+            msg!("⚙️ Passive regen set to {} HP/hour", hp_regen_per_hour);
+        }
+
+        if let Some(heal_cost_lamports) = update.heal_cost_lamports {
+            require!(heal_cost_lamports <= MAX_HEAL_COST_LAMPORTS, RustUndeadError::InvalidConfigValue);
+            config.heal_cost_lamports = heal_cost_lamports;
+            msg!("⚙️ Heal cost set to {} lamports", heal_cost_lamports);
+        }
+
+        if let Some(rename_fee_lamports) = update.rename_fee_lamports {
+            require!(rename_fee_lamports <= MAX_RENAME_FEE_LAMPORTS, RustUndeadError::InvalidConfigValue);
+            config.rename_fee_lamports = rename_fee_lamports;
+            msg!("⚙️ Rename fee set to {} lamports", rename_fee_lamports);
+        }
+
+        if let Some(rename_cooldown) = update.rename_cooldown {
+            require!(rename_cooldown >= 0, RustUndeadError::InvalidConfigValue);
+            config.rename_cooldown = rename_cooldown;
+            msg!("⚙️ Rename cooldown set to {} seconds", rename_cooldown);
+        }
+
+        if let Some(treasury) = update.treasury {
+            config.treasury = treasury;
+            msg!("⚙️ Treasury set to {}", treasury);
+        }
+
+        if let Some(wager_fee_bps) = update.wager_fee_bps {
+            require!(wager_fee_bps <= MAX_WAGER_FEE_BPS, RustUndeadError::InvalidConfigValue);
+            config.wager_fee_bps = wager_fee_bps;
+            msg!("⚙️ Wager fee set to {} bps", wager_fee_bps);
+        }
+
+        if let Some(max_level) = update.max_level {
+            require!((1..=MAX_LEVEL_CAP).contains(&max_level), RustUndeadError::InvalidConfigValue);
+            config.max_level = max_level;
+            msg!("⚙️ Level cap set to {}", max_level);
+        }
+
+        if let Some(answer_timeout) = update.answer_timeout {
+            require!(answer_timeout > 0, RustUndeadError::InvalidConfigValue);
+            config.answer_timeout = answer_timeout;
+            msg!("⚙️ Answer timeout set to {} seconds", answer_timeout);
+        }
+
+        if let Some(idle_timeout) = update.idle_timeout {
+            require!(idle_timeout > 0, RustUndeadError::InvalidConfigValue);
+            config.idle_timeout = idle_timeout;
+            msg!("⚙️ Idle timeout set to {} seconds", idle_timeout);
+        }
+
+        // force_undelegate must never beat a question that is still open
+        require!(
+            config.idle_timeout > config.answer_timeout,
+            RustUndeadError::IdleTimeoutTooShort
+        );
+
+        if let Some(question_authority) = update.question_authority {
+            config.question_authority = question_authority;
+            msg!("⚙️ Question authority set to {}", question_authority);
+        }
+
+        Ok(())
+    }
+}
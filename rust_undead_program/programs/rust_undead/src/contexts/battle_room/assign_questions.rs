@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::helpers::*;
+use crate::error::RustUndeadError;
+
+// content for rooms opened by the matchmaking crank, picked by the question
+// authority instead of a creator. Questions are then verified as usual
+#[derive(Accounts)]
+#[instruction(room_id: [u8; 32])]
+pub struct AssignRoomQuestions<'info> {
+    pub question_authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = config.question_authority == question_authority.key() @ RustUndeadError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [QUESTION_BANK],
+        bump = question_bank.bump,
+    )]
+    pub question_bank: Account<'info, QuestionBank>,
+
+    #[account(
+        mut,
+        seeds = [BATTLE, room_id.as_ref()],
+        bump,
+        constraint = battle_room.room_id == room_id @ RustUndeadError::InvalidRoomId,
+        constraint = battle_room.state == BattleState::Created @ RustUndeadError::InvalidBattleState,
+        constraint = !battle_room.has_answer_key() @ RustUndeadError::QuestionsAlreadyAssigned,
+    )]
+    pub battle_room: Account<'info, BattleRoom>,
+}
+
+impl<'info> AssignRoomQuestions<'info> {
+    pub fn assign_room_questions(
+        &mut self,
+        room_id: [u8; 32],
+        selected_concepts: [u8; 5],
+        selected_topics: Vec<u8>,
+        selected_questions: Vec<u16>,
+        answer_options: Vec<u8>,
+        answer_commitments: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        require!(self.battle_room.room_id == room_id, RustUndeadError::InvalidRoomId);
+        validate_selected_concepts(&selected_concepts, self.question_bank.concept_count)?;
+
+        let battle_room = &mut self.battle_room;
+        battle_room.selected_concepts = selected_concepts;
+        battle_room.set_questions(&selected_topics, &selected_questions, &answer_options, &answer_commitments)?;
+
+        msg!("📚 Questions assigned to matched room {:?}", room_id);
+        msg!("   Concepts: {:?}", selected_concepts);
+        msg!("   Questions ({} selected): {:?}", selected_questions.len(), selected_questions);
+        msg!("🔍 Verify each question against the question bank to open the room");
+
+        Ok(())
+    }
+}
@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::events::*;
 use crate::constants::*;
 use crate::error::RustUndeadError;
 
+// the creator can cancel until the room is delegated. The opponent can too
+// once the room has sat idle past Config.idle_timeout, so a creator who never
+// readies up can't hold their warrior and stake
 #[derive(Accounts)]
 #[instruction(room_id: [u8; 32])]
 pub struct CancelBattleRoom<'info> {
@@ -13,7 +17,8 @@ pub struct CancelBattleRoom<'info> {
         seeds = [BATTLE, room_id.as_ref()],
         bump,
         constraint = battle_room.room_id == room_id @ RustUndeadError::InvalidRoomId,
-        constraint = battle_room.player_a == player.key() @ RustUndeadError::OnlyCreatorCanCancel,
+        constraint = battle_room.player_a == player.key()
+            || battle_room.player_b == Some(player.key()) @ RustUndeadError::OnlyCreatorCanCancel,
         constraint = battle_room.state != BattleState::InProgress @ RustUndeadError::BattleAlreadyStarted,
         constraint = battle_room.state != BattleState::Completed @ RustUndeadError::BattleAlreadyCompleted,
         constraint = battle_room.state != BattleState::Cancelled @ RustUndeadError::BattleAlreadyCancelled,
@@ -23,15 +28,21 @@ pub struct CancelBattleRoom<'info> {
     #[account(
         mut,
         constraint = warrior_a.key() == battle_room.warrior_a @ RustUndeadError::InvalidWarrior,
-        constraint = warrior_a.owner == player.key() @ RustUndeadError::NotWarriorOwner,
     )]
     pub warrior_a: Account<'info, UndeadWarrior>,
 
+    // required once an opponent has joined
     #[account(
         mut,
-        constraint = warrior_b.key() == battle_room.warrior_b.unwrap_or_default() @ RustUndeadError::InvalidWarrior,
+        constraint = battle_room.warrior_b == Some(warrior_b.key()) @ RustUndeadError::InvalidWarrior,
     )]
     pub warrior_b: Option<Account<'info, UndeadWarrior>>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
 }
 
 impl<'info> CancelBattleRoom<'info> {
@@ -39,11 +50,28 @@ impl<'info> CancelBattleRoom<'info> {
         &mut self,
         room_id: [u8; 32],
     ) -> Result<()> {
+        let player = self.player.key();
         let battle_room = &mut self.battle_room;
         let warrior_a = &mut self.warrior_a;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(
+            self.warrior_b.is_some() == battle_room.player_b.is_some(),
+            RustUndeadError::InvalidWarrior
+        );
+
+        // The opponent may only walk away from a room the creator left idle
+        let reason = if player == battle_room.player_a {
+            CancelReason::Creator
+        } else {
+            let idle_for = current_time.saturating_sub(battle_room.last_activity_at);
+            require!(idle_for > self.config.idle_timeout, RustUndeadError::RoomNotIdle);
+            CancelReason::OpponentLeft
+        };
         
         // Determine cancellation stage
         let cancellation_stage = match battle_room.state {
+            BattleState::Created => "Room Created - Questions not verified",
             BattleState::QuestionsSelected => {
                 if battle_room.player_b.is_none() {
                     "Room Created - No opponent yet"
@@ -58,23 +86,25 @@ impl<'info> CancelBattleRoom<'info> {
         msg!("🚫 Cancelling battle room at stage: {}", cancellation_stage);
         msg!("   Room ID: {:?}", room_id);
         msg!("   Creator: {}", battle_room.player_a);
+        if reason == CancelReason::OpponentLeft {
+            msg!("   Opponent {} left the idle room", player);
+        }
 
         // ✅ Release Player A's warrior from battle
         warrior_a.last_battle_at = 0;
         warrior_a.cooldown_expires_at = 0;
+        warrior_a.leave_room(&room_id);
         msg!("✅ Released {} from battle", warrior_a.name);
 
         // ✅ Release Player B's warrior if they joined
         if let Some(warrior_b) = &mut self.warrior_b {
-            if battle_room.player_b.is_some() {
-                warrior_b.last_battle_at = 0;
-                warrior_b.cooldown_expires_at = 0;
-                msg!("✅ Released {} from battle", warrior_b.name);
-            }
+            warrior_b.last_battle_at = 0;
+            warrior_b.cooldown_expires_at = 0;
+            warrior_b.leave_room(&room_id);
+            msg!("✅ Released {} from battle", warrior_b.name);
         }
 
         // ✅ Calculate timing and provide feedback
-        let current_time = Clock::get()?.unix_timestamp;
         let room_age = current_time - battle_room.created_at;
         
         if room_age < 300 { // 5 minutes
@@ -92,6 +122,7 @@ impl<'info> CancelBattleRoom<'info> {
         // ✅ Log final cancellation summary
         msg!("🚫 Battle room cancelled successfully!");
         msg!("   Status: {}", match cancellation_stage {
+            "Room Created - Questions not verified" => "Clean cancellation",
             "Room Created - No opponent yet" => "Clean cancellation",
             "Opponent joined but not ready" => "Pre-ready cancellation", 
             "Both players ready - Pre-delegation" => "Pre-battle cancellation",
@@ -102,6 +133,15 @@ impl<'info> CancelBattleRoom<'info> {
         msg!("   Affected players: {}", affected_players);
         msg!("   Warriors released from battle commitment");
         msg!("   Room marked as cancelled and cleaned up");
+        if battle_room.wager_status == WagerStatus::Escrowed {
+            msg!("💰 Stakes are refundable with refund_wager");
+        }
+
+        emit!(RoomCancelled {
+            room_id,
+            cancelled_by: player,
+            reason,
+        });
 
         Ok(())
     }
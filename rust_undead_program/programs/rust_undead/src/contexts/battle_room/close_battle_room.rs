@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
+
+// reclaim the rent of a finished room for whoever paid it. Anyone can close it,
+// passing the warriors' history accounts first copies a summary of the match
+#[derive(Accounts)]
+#[instruction(room_id: [u8; 32])]
+pub struct CloseBattleRoom<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        address = battle_room.rent_payer @ RustUndeadError::NotAuthorized,
+    )]
+    pub rent_payer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [BATTLE, room_id.as_ref()],
+        bump,
+        constraint = battle_room.room_id == room_id @ RustUndeadError::InvalidRoomId,
+    )]
+    pub battle_room: Account<'info, BattleRoom>,
+
+    #[account(
+        mut,
+        seeds = [BATTLE_HISTORY, battle_room.warrior_a.as_ref()],
+        bump = history_a.bump,
+    )]
+    pub history_a: Option<Account<'info, WarriorHistory>>,
+
+    #[account(
+        mut,
+        seeds = [BATTLE_HISTORY, battle_room.warrior_b.unwrap_or_default().as_ref()],
+        bump = history_b.bump,
+    )]
+    pub history_b: Option<Account<'info, WarriorHistory>>,
+}
+
+impl<'info> CloseBattleRoom<'info> {
+    pub fn close_battle_room(
+        &mut self,
+        room_id: [u8; 32],
+    ) -> Result<()> {
+        let battle_room = &self.battle_room;
+
+        let closable = battle_room.is_no_contest()
+            || battle_room.settlement == SettlementStatus::Settled;
+        require!(closable, RustUndeadError::RoomNotClosable);
+        require!(
+            battle_room.wager_status != WagerStatus::Escrowed,
+            RustUndeadError::WagerStillEscrowed
+        );
+
+        msg!("🧹 Closing battle room");
+        msg!("   Room ID: {:?}", room_id);
+
+        let started_at = if battle_room.battle_start_time > 0 {
+            battle_room.battle_start_time
+        } else {
+            battle_room.created_at
+        };
+
+        let outcome_for = |player: Pubkey| match battle_room.winner {
+            Some(winner) if winner == player => BattleOutcome::Won,
+            Some(_) => BattleOutcome::Lost,
+            None => BattleOutcome::Cancelled,
+        };
+
+        if let Some(history_a) = &mut self.history_a {
+            history_a.push_record(BattleRecord {
+                room_id,
+                opponent_warrior: battle_room.warrior_b,
+                outcome: outcome_for(battle_room.player_a),
+                correct: battle_room.player_a_correct,
+                opponent_correct: battle_room.player_b_correct,
+                started_at,
+                duration: battle_room.battle_duration,
+            });
+            msg!("📜 Recorded battle for warrior A ({} total)", history_a.total_recorded);
+        }
+
+        if let (Some(history_b), Some(player_b)) = (&mut self.history_b, battle_room.player_b) {
+            history_b.push_record(BattleRecord {
+                room_id,
+                opponent_warrior: Some(battle_room.warrior_a),
+                outcome: outcome_for(player_b),
+                correct: battle_room.player_b_correct,
+                opponent_correct: battle_room.player_a_correct,
+                started_at,
+                duration: battle_room.battle_duration,
+            });
+            msg!("📜 Recorded battle for warrior B ({} total)", history_b.total_recorded);
+        }
+
+        msg!("💰 Rent returned to {}", self.rent_payer.key());
+
+        Ok(())
+    }
+}
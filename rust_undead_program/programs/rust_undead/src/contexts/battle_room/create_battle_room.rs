@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
 use crate::state::*;
+use crate::events::*;
 use crate::constants::*;
-use crate::helpers::is_warrior_ready;
+use crate::helpers::{
+    deposit_wager, leave_match_queue, open_escrow_vault, prepare_warrior_for_battle,
+    validate_selected_concepts,
+};
 use crate::error::RustUndeadError;
 
 #[derive(Accounts)]
-#[instruction(room_id: [u8; 32], warrior_name: String, selected_concepts: [u8; 5], selected_topics: [u8; 10], 
-selected_questions: [u16; 10], correct_answers: [bool; 10]
+#[instruction(room_id: [u8; 32], selected_concepts: [u8; 5], selected_topics: Vec<u8>, 
+selected_questions: Vec<u16>, answer_options: Vec<u8>, answer_commitments: Vec<[u8; 32]>, wager_amount: u64
 )]
 pub struct CreateBattleRoom<'info> {
     #[account(mut)]
@@ -14,13 +19,40 @@ pub struct CreateBattleRoom<'info> {
 
     #[account(
         mut,
-        seeds = [UNDEAD_WARRIOR, player_a.key().as_ref(), warrior_name.as_bytes()],
-        constraint = warrior_a.owner == player_a.key() @ RustUndeadError::NotWarriorOwner,
-        constraint = is_warrior_ready(&warrior_a) @ RustUndeadError::WarriorOnCooldown,
-        bump
+        seeds = [UNDEAD_WARRIOR, warrior_a.creator.as_ref(), warrior_a.warrior_index.to_le_bytes().as_ref()],
+        bump = warrior_a.bump,
+        constraint = warrior_a.current_room.is_none() @ RustUndeadError::WarriorInRoom,
     )]
     pub warrior_a: Account<'info, UndeadWarrior>,
 
+    // NFT token account for minted warriors, see prepare_warrior_for_battle
+    pub warrior_nft_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // needed while the warrior is queued, see leave_match_queue
+    #[account(
+        mut,
+        seeds = [MATCH_QUEUE],
+        bump = match_queue.bump,
+    )]
+    pub match_queue: Option<Box<Account<'info, MatchQueue>>>,
+
+    // co-signs the room so the creator never sees or picks the answer key
+    pub question_authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = config.question_authority == question_authority.key() @ RustUndeadError::NotAuthorized,
+        constraint = !config.is_paused @ RustUndeadError::GamePaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [QUESTION_BANK],
+        bump = question_bank.bump,
+    )]
+    pub question_bank: Account<'info, QuestionBank>,
+
     #[account(
         init,
         payer = player_a,
@@ -30,59 +62,146 @@ pub struct CreateBattleRoom<'info> {
     )]
     pub battle_room: Account<'info, BattleRoom>,
 
+    // stake accounts, only needed for wagered rooms. SOL stakes use the escrow
+    // alone, SPL stakes also pass the mint and a vault is opened for the room
+    /// CHECK: system owned PDA holding SOL stakes and signing for the token vault
+    #[account(
+        mut,
+        seeds = [WAGER_ESCROW, room_id.as_ref()],
+        bump,
+    )]
+    pub wager_escrow: Option<UncheckedAccount<'info>>,
+
+    pub wager_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// CHECK: token vault PDA, created here for SPL stakes
+    #[account(
+        mut,
+        seeds = [WAGER_VAULT, room_id.as_ref()],
+        bump,
+    )]
+    pub wager_vault: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        constraint = player_token_account.owner == player_a.key() @ RustUndeadError::InvalidWagerAccounts,
+    )]
+    pub player_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
     pub system_program: Program<'info, System>,
 }
 
 /// Create battle room with pre-selected educational content
-
 impl<'info> CreateBattleRoom<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn create_battle_room(
         &mut self,
         room_id: [u8; 32],
-        _warrior_name: String,
         selected_concepts: [u8; 5], 
-        selected_topics: [u8; 10], 
-        selected_questions: [u16; 10], 
-        correct_answers: [bool; 10],
+        selected_topics: Vec<u8>, 
+        selected_questions: Vec<u16>, 
+        answer_options: Vec<u8>,
+        answer_commitments: Vec<[u8; 32]>,
+        wager_amount: u64,
         bumps: &CreateBattleRoomBumps
     ) -> Result<()> {
-        for &concept in &selected_concepts {
-            require!(concept >= 1 && concept <= 10, RustUndeadError::InvalidConceptSelection);
-        }
-        
-        // Ensure concepts are unique
-        let mut unique_concepts = selected_concepts;
-        unique_concepts.sort();
-        for i in 1..unique_concepts.len() {
-            require!(unique_concepts[i] != unique_concepts[i-1], RustUndeadError::InvalidConceptSelection);
-        }
+        leave_match_queue(&mut self.warrior_a, &mut self.match_queue)?;
+        prepare_warrior_for_battle(
+            &mut self.warrior_a,
+            self.player_a.key(),
+            &self.warrior_nft_account,
+            &self.config,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        validate_selected_concepts(&selected_concepts, self.question_bank.concept_count)?;
+
+        // Lock the creator's stake, the opponent has to match it to join
+        let wager_mint = self.wager_mint.as_ref().map(|mint| mint.key());
+        let wager_status = if wager_amount > 0 {
+            let vault = match &self.wager_mint {
+                Some(mint) => {
+                    let (Some(escrow), Some(vault), Some(token_program)) =
+                        (&self.wager_escrow, &self.wager_vault, &self.token_program)
+                    else {
+                        return Err(RustUndeadError::InvalidWagerAccounts.into());
+                    };
+                    open_escrow_vault(
+                        &self.player_a,
+                        &vault.to_account_info(),
+                        &mint.to_account_info(),
+                        &escrow.to_account_info(),
+                        token_program,
+                        &self.system_program,
+                        &[WAGER_VAULT, room_id.as_ref(), &[bumps.wager_vault.unwrap()]],
+                    )?;
+                    Some(vault.to_account_info())
+                },
+                None => {
+                    require!(wager_amount >= MIN_SOL_WAGER_LAMPORTS, RustUndeadError::InvalidWagerAmount);
+                    None
+                },
+            };
+            deposit_wager(
+                wager_amount,
+                &self.player_a,
+                &self.wager_escrow,
+                vault,
+                &self.player_token_account,
+                &self.token_program,
+                &self.system_program,
+            )?;
+            WagerStatus::Escrowed
+        } else {
+            WagerStatus::None
+        };
 
         self.battle_room.set_inner(
             BattleRoom { 
                 room_id, 
                 created_at: Clock::get()?.unix_timestamp, 
+                rent_payer: self.player_a.key(),
                 player_a: self.player_a.key(), 
                 player_b: None, 
                 warrior_a: self.warrior_a.key(), 
                 warrior_b: None, 
                 selected_concepts, 
-                selected_topics,  
-                selected_questions,  
-                correct_answers, 
-                state: BattleState::QuestionsSelected, 
+                question_count: 0,
+                selected_topics: [0; MAX_QUESTIONS],  
+                selected_questions: [0; MAX_QUESTIONS],  
+                answer_options: [0; MAX_QUESTIONS],
+                questions_verified: 0,
+                answer_digests: [[0u8; 32]; MAX_QUESTIONS],
+                answer_commitments: [[0u8; 32]; MAX_QUESTIONS], 
+                revealed_answers: [None; MAX_QUESTIONS],
+                state: BattleState::Created, 
+                settlement: SettlementStatus::Unsettled,
                 player_a_ready: false, 
                 player_b_ready: false, 
                 current_question: 0, 
-                player_a_answers: [None; 10], 
-                player_b_answers: [None; 10], 
+                player_a_answers: [None; MAX_QUESTIONS], 
+                player_b_answers: [None; MAX_QUESTIONS], 
+                player_a_sealed_answer: None,
+                player_b_sealed_answer: None,
+                damage_entropy: [0u8; 32],
                 player_a_correct: 0, 
                 player_b_correct: 0, 
                 winner: None, 
                 battle_duration: 0, 
                 bump: bumps.battle_room,
                 battle_start_time: 0,
+                answer_timeout: 0,
+                question_deadline: 0,
+                last_activity_at: 0,
+                wager_amount,
+                wager_mint: if wager_amount > 0 { wager_mint } else { None },
+                wager_status,
             }
         );
+        self.battle_room.set_questions(&selected_topics, &selected_questions, &answer_options, &answer_commitments)?;
+        self.warrior_a.current_room = Some(room_id);
 
         msg!("🎮 Battle room created by Player A: {}", self.player_a.key());
         msg!("⚔️ Warrior selected: {} ({})", self.warrior_a.name, self.warrior_a.key());
@@ -90,9 +209,27 @@ impl<'info> CreateBattleRoom<'info> {
         msg!("   Concepts ({} selected): {:?}", selected_concepts.len(), selected_concepts);
         msg!("   Topics ({} selected): {:?}", selected_topics.len(), selected_topics);
         msg!("   Questions ({} selected): {:?}", selected_questions.len(), selected_questions);
-        msg!("   Correct answers: {:?}", correct_answers);
+        msg!("   Answer options: {:?}", answer_options);
+        msg!("   Answer key: committed by {}", self.question_authority.key());
+        if wager_status == WagerStatus::Escrowed {
+            match wager_mint {
+                Some(mint) => msg!("💰 Wager: {} tokens of {} per player", wager_amount, mint),
+                None => msg!("💰 Wager: {} lamports per player", wager_amount),
+            }
+        }
         msg!("✅ Room ID: {:?}", room_id);
-        msg!("✅ Room ready for Player B to join!");
+        msg!("🔍 Verify each question against the question bank to open the room");
+
+        emit!(RoomCreated {
+            room_id,
+            player_a: self.player_a.key(),
+            warrior_a: self.warrior_a.key(),
+            player_b: None,
+            warrior_b: None,
+            question_count: self.battle_room.question_count,
+            wager_amount: self.battle_room.wager_amount,
+            wager_mint: self.battle_room.wager_mint,
+        });
         
         Ok(())
     }
@@ -1,12 +1,15 @@
 use anchor_lang::prelude::*;
 use crate::constants::*;
+use crate::state::{BattleRoom, Config, UndeadWarrior};
+use crate::events::BattleDelegated;
+use crate::error::RustUndeadError;
 //MB
 use ephemeral_rollups_sdk::anchor::delegate;
 use ephemeral_rollups_sdk::cpi::DelegateConfig;
 
 #[delegate]
 #[derive(Accounts)]
-#[instruction(room_id: [u8; 32], player_a: Pubkey, warrior_a_name: String, player_b: Pubkey, warrior_b_name: String)]
+#[instruction(room_id: [u8; 32])]
 pub struct DelegateBattle<'info> {
     #[account(mut)]
     pub signer: Signer<'info>, //we need to be users doing this call
@@ -20,34 +23,40 @@ pub struct DelegateBattle<'info> {
     )]
     pub battle_room: AccountInfo<'info>,
 
+    // warriors are checked against the room and their seeds read from their
+    // own data, so the client only passes the room id
     /// CHECK: Warrior A account we are delegating
-    #[account(
-        mut,
-        del,
-        seeds = [UNDEAD_WARRIOR, player_a.as_ref(), warrior_a_name.as_bytes()],
-        bump,
-    )]
+    #[account(mut, del)]
     pub warrior_a: AccountInfo<'info>,
 
     /// CHECK: Warrior B account we are delegating
+    #[account(mut, del)]
+    pub warrior_b: AccountInfo<'info>,
+
     #[account(
-        mut,
-        del,
-        seeds = [UNDEAD_WARRIOR, player_b.as_ref(), warrior_b_name.as_bytes()],
-        bump,
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = !config.is_paused @ RustUndeadError::GamePaused,
     )]
-    pub warrior_b: AccountInfo<'info>,
+    pub config: Account<'info, Config>,
 }
 
 impl<'info> DelegateBattle<'info> {
     pub fn delegate_to_rollup(
         &mut self,
         room_id: [u8; 32],
-        player_a: Pubkey,
-        warrior_a_name: String,
-        player_b: Pubkey,
-        warrior_b_name: String,
     ) -> Result<()> {
+        let (room_warrior_a, room_warrior_b) = {
+            let data = self.battle_room.try_borrow_data()?;
+            let battle_room = BattleRoom::try_deserialize(&mut &data[..])?;
+            (battle_room.warrior_a, battle_room.warrior_b)
+        };
+        require!(self.warrior_a.key() == room_warrior_a, RustUndeadError::InvalidWarrior);
+        require!(Some(self.warrior_b.key()) == room_warrior_b, RustUndeadError::InvalidWarrior);
+
+        let (creator_a, index_a, name_a) = warrior_seeds(&self.warrior_a)?;
+        let (creator_b, index_b, name_b) = warrior_seeds(&self.warrior_b)?;
+
         msg!("Delegating battle room and warriors to ephemeral rollup...");
         // Delegate battle room
         
@@ -60,14 +69,14 @@ impl<'info> DelegateBattle<'info> {
         // Delegate warrior A
         self.delegate_warrior_a(
             &self.signer,
-            &[UNDEAD_WARRIOR, player_a.as_ref(), warrior_a_name.as_bytes()],
+            &[UNDEAD_WARRIOR, creator_a.as_ref(), index_a.as_ref()],
             DelegateConfig::default(),
         )?;
         
         // Delegate warrior B
         self.delegate_warrior_b(
             &self.signer,
-            &[UNDEAD_WARRIOR, player_b.as_ref(), warrior_b_name.as_bytes()],
+            &[UNDEAD_WARRIOR, creator_b.as_ref(), index_b.as_ref()],
             DelegateConfig::default(),
         )?;
         
@@ -77,10 +86,31 @@ impl<'info> DelegateBattle<'info> {
         
         msg!(
             "Delegated warriors: {} (Player A) vs {} (Player B)",
-            warrior_a_name,
-            warrior_b_name
+            name_a,
+            name_b
         );
+
+        emit!(BattleDelegated {
+            room_id,
+            warrior_a: self.warrior_a.key(),
+            warrior_b: self.warrior_b.key(),
+        });
         
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+// Creator and index seeds of a warrior, checked against its address
+fn warrior_seeds(warrior: &AccountInfo) -> Result<(Pubkey, [u8; 4], String)> {
+    let data = warrior.try_borrow_data()?;
+    let warrior_data = UndeadWarrior::try_deserialize(&mut &data[..])?;
+    let index = warrior_data.warrior_index.to_le_bytes();
+    let expected = Pubkey::create_program_address(
+        &[UNDEAD_WARRIOR, warrior_data.creator.as_ref(), index.as_ref(), &[warrior_data.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| RustUndeadError::InvalidWarrior)?;
+    require!(warrior.key() == expected, RustUndeadError::InvalidWarrior);
+
+    Ok((warrior_data.creator, index, warrior_data.name))
+}
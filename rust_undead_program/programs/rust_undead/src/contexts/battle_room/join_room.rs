@@ -1,24 +1,36 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
 use crate::state::*;
+use crate::events::*;
 use crate::constants::*;
 use crate::helpers::*;
 use crate::error::RustUndeadError;
 
 #[derive(Accounts)]
-#[instruction(room_id: [u8; 32], warrior_name: String)]
+#[instruction(room_id: [u8; 32])]
 pub struct JoinBattleRoom<'info> {
     #[account(mut)]
     pub player_b: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [UNDEAD_WARRIOR, player_b.key().as_ref(), warrior_name.as_bytes()],
-        constraint = warrior_b.owner == player_b.key() @ RustUndeadError::NotWarriorOwner,
-        constraint = is_warrior_ready(&warrior_b) @ RustUndeadError::WarriorOnCooldown,
-        bump
+        seeds = [UNDEAD_WARRIOR, warrior_b.creator.as_ref(), warrior_b.warrior_index.to_le_bytes().as_ref()],
+        bump = warrior_b.bump,
+        constraint = warrior_b.current_room.is_none() @ RustUndeadError::WarriorInRoom,
     )]
     pub warrior_b: Account<'info, UndeadWarrior>,
 
+    // NFT token account for minted warriors, see prepare_warrior_for_battle
+    pub warrior_nft_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // needed while the warrior is queued, see leave_match_queue
+    #[account(
+        mut,
+        seeds = [MATCH_QUEUE],
+        bump = match_queue.bump,
+    )]
+    pub match_queue: Option<Box<Account<'info, MatchQueue>>>,
+
     #[account(
         mut,
         seeds = [BATTLE, room_id.as_ref()],
@@ -29,6 +41,39 @@ pub struct JoinBattleRoom<'info> {
         constraint = battle_room.player_a != player_b.key() @ RustUndeadError::NotAuthorized,
     )]
     pub battle_room: Account<'info, BattleRoom>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = !config.is_paused @ RustUndeadError::GamePaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    // stake accounts, only needed when the room is wagered
+    /// CHECK: system owned PDA holding SOL stakes and signing for the token vault
+    #[account(
+        mut,
+        seeds = [WAGER_ESCROW, room_id.as_ref()],
+        bump,
+    )]
+    pub wager_escrow: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [WAGER_VAULT, room_id.as_ref()],
+        bump,
+    )]
+    pub wager_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        constraint = player_token_account.owner == player_b.key() @ RustUndeadError::InvalidWagerAccounts,
+    )]
+    pub player_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 /// Join an existing battle room
@@ -41,14 +86,38 @@ pub struct JoinBattleRoom<'info> {
 /// 
 /// Data:
 /// - room_id: [u8; 32] Room to join
-/// - warrior_name: String Warrior to use in battle
-
 impl<'info> JoinBattleRoom<'info> {
     pub fn join_battle_room(
         &mut self,
         room_id: [u8; 32],
-        _warrior_name: String,
     ) -> Result<()> {
+        leave_match_queue(&mut self.warrior_b, &mut self.match_queue)?;
+        prepare_warrior_for_battle(
+            &mut self.warrior_b,
+            self.player_b.key(),
+            &self.warrior_nft_account,
+            &self.config,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        // Match the creator's stake
+        if self.battle_room.wager_status == WagerStatus::Escrowed {
+            let vault_mint = self.wager_vault.as_ref().map(|vault| vault.mint);
+            require!(vault_mint == self.battle_room.wager_mint, RustUndeadError::InvalidWagerAccounts);
+            deposit_wager(
+                self.battle_room.wager_amount,
+                &self.player_b,
+                &self.wager_escrow,
+                self.wager_vault.as_ref().map(|vault| vault.to_account_info()),
+                &self.player_token_account,
+                &self.token_program,
+                &self.system_program,
+            )?;
+            msg!("💰 Matched wager of {}", self.battle_room.wager_amount);
+        }
+
+        self.warrior_b.current_room = Some(room_id);
+
         let battle_room = &mut self.battle_room;
         let warrior_b = &self.warrior_b;
         let player_b = &self.player_b;
@@ -68,6 +137,7 @@ impl<'info> JoinBattleRoom<'info> {
         battle_room.player_b = Some(player_b.key());
         battle_room.warrior_b = Some(warrior_b.key());
         battle_room.player_b_ready = false; // Initialize as not ready
+        battle_room.last_activity_at = Clock::get()?.unix_timestamp;
         
         // ✅ Log successful join
         msg!("🎮 Player B joined the battle room!");
@@ -77,8 +147,14 @@ impl<'info> JoinBattleRoom<'info> {
         msg!("   Player B: {} with {}", player_b.key(), warrior_b.key());
         msg!("📚 Educational content ready:");
         msg!("   Concepts: {:?}", battle_room.selected_concepts);
-        msg!("   Questions: 10 total prepared");
+        msg!("   Questions: {} total prepared", battle_room.question_count);
         msg!("✅ Room complete! Players can now study content and signal ready for battle.");
+
+        emit!(RoomJoined {
+            room_id,
+            player_b: player_b.key(),
+            warrior_b: warrior_b.key(),
+        });
         
         Ok(())
     }
@@ -15,4 +15,19 @@ pub mod cancel_battle;
 pub use cancel_battle::*;
 
 pub mod update_state;
-pub use update_state::*;
\ No newline at end of file
+pub use update_state::*;
+
+pub mod verify_questions;
+pub use verify_questions::*;
+
+pub mod close_battle_room;
+pub use close_battle_room::*;
+
+pub mod settle_wager;
+pub use settle_wager::*;
+
+pub mod refund_wager;
+pub use refund_wager::*;
+
+pub mod assign_questions;
+pub use assign_questions::*;
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use crate::state::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::helpers::*;
+use crate::error::RustUndeadError;
+
+// return both stakes of a cancelled or no-contest room. Anyone can crank it
+// once the room is back on the base layer
+#[derive(Accounts)]
+#[instruction(room_id: [u8; 32])]
+pub struct RefundWager<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BATTLE, room_id.as_ref()],
+        bump,
+        constraint = battle_room.room_id == room_id @ RustUndeadError::InvalidRoomId,
+        constraint = battle_room.is_no_contest() @ RustUndeadError::InvalidBattleState,
+        constraint = battle_room.wager_status == WagerStatus::Escrowed @ RustUndeadError::WagerNotEscrowed,
+    )]
+    pub battle_room: Account<'info, BattleRoom>,
+
+    #[account(
+        mut,
+        address = battle_room.player_a @ RustUndeadError::InvalidWagerAccounts,
+    )]
+    pub player_a: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        address = battle_room.player_b.unwrap_or_default() @ RustUndeadError::InvalidWagerAccounts,
+    )]
+    pub player_b: Option<SystemAccount<'info>>,
+
+    /// CHECK: system owned PDA holding SOL stakes and signing for the token vault
+    #[account(
+        mut,
+        seeds = [WAGER_ESCROW, room_id.as_ref()],
+        bump,
+    )]
+    pub wager_escrow: UncheckedAccount<'info>,
+
+    // SPL stakes only
+    #[account(
+        mut,
+        seeds = [WAGER_VAULT, room_id.as_ref()],
+        bump,
+    )]
+    pub wager_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        constraint = player_a_token_account.owner == player_a.key() @ RustUndeadError::InvalidWagerAccounts,
+    )]
+    pub player_a_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        constraint = Some(player_b_token_account.owner) == battle_room.player_b @ RustUndeadError::InvalidWagerAccounts,
+    )]
+    pub player_b_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RefundWager<'info> {
+    pub fn refund_wager(
+        &mut self,
+        room_id: [u8; 32],
+        bumps: &RefundWagerBumps,
+    ) -> Result<()> {
+        let amount = self.battle_room.wager_amount;
+        // player B only staked if they joined
+        let refund_b = self.battle_room.player_b.is_some();
+
+        msg!("↩️ Refunding wager for room {:?}", room_id);
+
+        match self.battle_room.wager_mint {
+            Some(mint) => {
+                let (Some(vault), Some(player_a_tokens), Some(token_program)) = (
+                    &self.wager_vault,
+                    &self.player_a_token_account,
+                    &self.token_program,
+                ) else {
+                    return Err(RustUndeadError::InvalidWagerAccounts.into());
+                };
+                require!(vault.mint == mint, RustUndeadError::InvalidWagerAccounts);
+
+                release_escrow_tokens(&self.wager_escrow, vault, player_a_tokens, token_program, &room_id, bumps.wager_escrow, amount)?;
+                if refund_b {
+                    let player_b_tokens = self.player_b_token_account.as_ref().ok_or(RustUndeadError::InvalidWagerAccounts)?;
+                    release_escrow_tokens(&self.wager_escrow, vault, player_b_tokens, token_program, &room_id, bumps.wager_escrow, amount)?;
+                }
+                close_escrow_vault(
+                    &self.wager_escrow,
+                    vault,
+                    &self.player_a.to_account_info(),
+                    token_program,
+                    &room_id,
+                    bumps.wager_escrow,
+                )?;
+            },
+            None => {
+                release_escrow_sol(&self.wager_escrow, &self.player_a.to_account_info(), &self.system_program, &room_id, bumps.wager_escrow, amount)?;
+                if refund_b {
+                    let player_b = self.player_b.as_ref().ok_or(RustUndeadError::InvalidWagerAccounts)?;
+                    release_escrow_sol(&self.wager_escrow, &player_b.to_account_info(), &self.system_program, &room_id, bumps.wager_escrow, amount)?;
+                }
+            },
+        }
+
+        self.battle_room.wager_status = WagerStatus::Refunded;
+        let players_refunded = if refund_b { 2 } else { 1 };
+        msg!("✅ Stakes returned to {} player(s)", players_refunded);
+
+        emit!(WagerRefunded {
+            room_id,
+            mint: self.battle_room.wager_mint,
+            amount,
+            players_refunded,
+        });
+
+        Ok(())
+    }
+}
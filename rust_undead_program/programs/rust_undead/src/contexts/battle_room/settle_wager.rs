@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use crate::state::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::helpers::*;
+use crate::error::RustUndeadError;
+
+// pay a wagered pot to the winner once the room is back on the base layer,
+// the protocol fee goes to the treasury. Anyone can crank it
+#[derive(Accounts)]
+#[instruction(room_id: [u8; 32])]
+pub struct SettleWager<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BATTLE, room_id.as_ref()],
+        bump,
+        constraint = battle_room.room_id == room_id @ RustUndeadError::InvalidRoomId,
+        constraint = battle_room.state == BattleState::Completed @ RustUndeadError::InvalidBattleState,
+        constraint = battle_room.winner.is_some() @ RustUndeadError::InvalidBattleState,
+        constraint = battle_room.wager_status == WagerStatus::Escrowed @ RustUndeadError::WagerNotEscrowed,
+    )]
+    pub battle_room: Account<'info, BattleRoom>,
+
+    #[account(
+        mut,
+        address = battle_room.winner.unwrap() @ RustUndeadError::InvalidWagerAccounts,
+    )]
+    pub winner: SystemAccount<'info>,
+
+    // receives the vault rent back, they paid for it
+    #[account(
+        mut,
+        address = battle_room.player_a @ RustUndeadError::InvalidWagerAccounts,
+    )]
+    pub creator: SystemAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = config.treasury @ RustUndeadError::NotAuthorized,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// CHECK: system owned PDA holding SOL stakes and signing for the token vault
+    #[account(
+        mut,
+        seeds = [WAGER_ESCROW, room_id.as_ref()],
+        bump,
+    )]
+    pub wager_escrow: UncheckedAccount<'info>,
+
+    // SPL stakes only
+    #[account(
+        mut,
+        seeds = [WAGER_VAULT, room_id.as_ref()],
+        bump,
+    )]
+    pub wager_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        constraint = winner_token_account.owner == winner.key() @ RustUndeadError::InvalidWagerAccounts,
+    )]
+    pub winner_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == config.treasury @ RustUndeadError::InvalidWagerAccounts,
+    )]
+    pub treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SettleWager<'info> {
+    pub fn settle_wager(
+        &mut self,
+        room_id: [u8; 32],
+        bumps: &SettleWagerBumps,
+    ) -> Result<()> {
+        let pot = self.battle_room.wager_amount.saturating_mul(2);
+        let fee = (pot as u128 * self.config.wager_fee_bps as u128 / 10_000) as u64;
+        let payout = pot - fee;
+
+        msg!("💰 Settling wager for room {:?}", room_id);
+        msg!("   Pot: {}, Fee: {} ({} bps), Winner gets: {}", pot, fee, self.config.wager_fee_bps, payout);
+
+        match self.battle_room.wager_mint {
+            Some(mint) => {
+                let (Some(vault), Some(winner_tokens), Some(treasury_tokens), Some(token_program)) = (
+                    &self.wager_vault,
+                    &self.winner_token_account,
+                    &self.treasury_token_account,
+                    &self.token_program,
+                ) else {
+                    return Err(RustUndeadError::InvalidWagerAccounts.into());
+                };
+                require!(vault.mint == mint, RustUndeadError::InvalidWagerAccounts);
+
+                release_escrow_tokens(&self.wager_escrow, vault, treasury_tokens, token_program, &room_id, bumps.wager_escrow, fee)?;
+                release_escrow_tokens(&self.wager_escrow, vault, winner_tokens, token_program, &room_id, bumps.wager_escrow, payout)?;
+                close_escrow_vault(
+                    &self.wager_escrow,
+                    vault,
+                    &self.creator.to_account_info(),
+                    token_program,
+                    &room_id,
+                    bumps.wager_escrow,
+                )?;
+            },
+            None => {
+                // fee first so the escrow stays rent exempt until the final payout empties it
+                release_escrow_sol(&self.wager_escrow, &self.treasury.to_account_info(), &self.system_program, &room_id, bumps.wager_escrow, fee)?;
+                release_escrow_sol(&self.wager_escrow, &self.winner.to_account_info(), &self.system_program, &room_id, bumps.wager_escrow, payout)?;
+            },
+        }
+
+        self.battle_room.wager_status = WagerStatus::Paid;
+        msg!("🏆 Wager paid to {}", self.winner.key());
+
+        emit!(WagerSettled {
+            room_id,
+            winner: self.winner.key(),
+            mint: self.battle_room.wager_mint,
+            payout,
+            fee,
+        });
+
+        Ok(())
+    }
+}
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::events::*;
 use crate::constants::*;
+use crate::helpers::is_warrior_holder;
 use crate::error::RustUndeadError;
 
 #[derive(Accounts)]
-#[instruction(room_id: [u8; 32], warrior_name: String)]
+#[instruction(room_id: [u8; 32])]
 pub struct SignalReady<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
@@ -20,9 +22,9 @@ pub struct SignalReady<'info> {
 
     #[account(
         mut,
-        seeds = [UNDEAD_WARRIOR, player.key().as_ref(), warrior_name.as_bytes()],
-        bump,
-        constraint = warrior.owner == player.key() @ RustUndeadError::NotWarriorOwner,
+        seeds = [UNDEAD_WARRIOR, warrior.creator.as_ref(), warrior.warrior_index.to_le_bytes().as_ref()],
+        bump = warrior.bump,
+        constraint = is_warrior_holder(&warrior, &player.key(), &warrior_nft_account) @ RustUndeadError::NotWarriorOwner,
         constraint = 
             (player.key() == battle_room.player_a && warrior.key() == battle_room.warrior_a) ||
             (Some(player.key()) == battle_room.player_b && Some(warrior.key()) == battle_room.warrior_b)
@@ -30,6 +32,9 @@ pub struct SignalReady<'info> {
     )]
     pub warrior: Account<'info, UndeadWarrior>,
 
+    // the warrior's NFT token account, required once the warrior is minted
+    pub warrior_nft_account: Option<Box<Account<'info, TokenAccount>>>,
+
     #[account(
         mut,
         constraint = warrior_a.key() == battle_room.warrior_a @ RustUndeadError::InvalidWarrior,
@@ -41,13 +46,19 @@ pub struct SignalReady<'info> {
         constraint = warrior_b.key() == battle_room.warrior_b.unwrap() @ RustUndeadError::InvalidWarrior,
     )]
     pub warrior_b: Account<'info, UndeadWarrior>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = !config.is_paused @ RustUndeadError::GamePaused,
+    )]
+    pub config: Account<'info, Config>,
 }
 
 impl<'info> SignalReady<'info> {
     pub fn signal_ready(
         &mut self,
         room_id: [u8; 32],
-        warrior_name: String,
     ) -> Result<()> {
 // check if playerA is ready and let last player perform the rollup delegate. if player a calls, check if b is, if false, just set it as player A, if player b is calling, if a is ready call 
         let battle_room = &mut self.battle_room;
@@ -62,11 +73,11 @@ impl<'info> SignalReady<'info> {
         if player == battle_room.player_a {
             require!(!battle_room.player_a_ready, RustUndeadError::AlreadyReady);
             battle_room.player_a_ready = true;
-            msg!("Player A is ready for battle with warrior: {}", warrior_name);
+            msg!("Player A is ready for battle with warrior: {}", self.warrior.name);
         } else if Some(player) == battle_room.player_b {
             require!(!battle_room.player_b_ready, RustUndeadError::AlreadyReady);
             battle_room.player_b_ready = true;
-            msg!("Player B is ready for battle with warrior: {}", warrior_name);
+            msg!("Player B is ready for battle with warrior: {}", self.warrior.name);
         } else {
             return Err(RustUndeadError::PlayerNotInRoom.into());
         }
@@ -85,8 +96,12 @@ impl<'info> SignalReady<'info> {
             
             // Reset/initialize battle state
             battle_room.current_question = 0;
-            battle_room.player_a_answers = [None; 10];
-            battle_room.player_b_answers = [None; 10];
+            battle_room.player_a_answers = [None; MAX_QUESTIONS];
+            battle_room.player_b_answers = [None; MAX_QUESTIONS];
+            battle_room.player_a_sealed_answer = None;
+            battle_room.player_b_sealed_answer = None;
+            battle_room.damage_entropy = [0u8; 32];
+            battle_room.revealed_answers = [None; MAX_QUESTIONS];
             battle_room.player_a_correct = 0;
             battle_room.player_b_correct = 0;
             battle_room.battle_duration = 0;
@@ -94,6 +109,7 @@ impl<'info> SignalReady<'info> {
         
             // Update state to ready for delegation
             battle_room.state = BattleState::ReadyForDelegation;
+            battle_room.last_activity_at = Clock::get()?.unix_timestamp;
             
             msg!(
                 "⚔️ Battle prepared! {} ({} HP) vs {} ({} HP)",
@@ -106,6 +122,12 @@ impl<'info> SignalReady<'info> {
             msg!("🎯 Battle room ready for delegation to ephemeral rollup!");
         }
 
+        emit!(PlayerReady {
+            room_id,
+            player,
+            both_ready: battle_room.player_a_ready && battle_room.player_b_ready,
+        });
+
         Ok(())
     }
 }
\ No newline at end of file
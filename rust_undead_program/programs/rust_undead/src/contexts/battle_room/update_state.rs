@@ -1,13 +1,18 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::events::*;
 use crate::constants::*;
 use crate::error::RustUndeadError;
 use crate::helpers::*;
 
 //after base battle is completed, update all state
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(room_id: [u8; 32])]
 pub struct UpdateState<'info> {
+    // anyone can settle once settle_battle_room has applied the rewards on the
+    // rollup. Before that the room is Unsettled and rejected below, after it
+    // the Settled status stops repeats
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -18,6 +23,8 @@ pub struct UpdateState<'info> {
         constraint = battle_room.room_id == room_id @ RustUndeadError::InvalidRoomId,
         constraint = battle_room.state == BattleState::Completed @ RustUndeadError::InvalidBattleState,
         constraint = battle_room.winner.is_some() @ RustUndeadError::InvalidBattleState,
+        constraint = battle_room.settlement != SettlementStatus::Settled @ RustUndeadError::BattleAlreadySettled,
+        constraint = battle_room.settlement == SettlementStatus::RewardsApplied @ RustUndeadError::InvalidBattleState,
     )]
     pub battle_room: Account<'info, BattleRoom>,
 
@@ -63,33 +70,52 @@ pub struct UpdateState<'info> {
 
     #[account(
         mut,
-        seeds = [CONFIG, authority.key().as_ref()],
+        seeds = [CONFIG],
         bump,
     )]
     pub config: Account<'info, Config>,
 
     #[account(
         mut,
-        seeds = [LEADERBOARD, authority.key().as_ref()],
+        seeds = [LEADERBOARD],
         bump,
+        constraint = Leaderboard::is_allocated(leaderboard.as_ref()) @ RustUndeadError::LeaderboardNotAllocated,
     )]
-    pub leaderboard: Account<'info, Leaderboard>,
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+
+    #[account(
+        mut,
+        seeds = [RATING_LEADERBOARD],
+        bump,
+        constraint = Leaderboard::is_allocated(rating_leaderboard.as_ref()) @ RustUndeadError::LeaderboardNotAllocated,
+    )]
+    pub rating_leaderboard: AccountLoader<'info, Leaderboard>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_LEADERBOARD, config.current_season.to_le_bytes().as_ref()],
+        bump,
+        constraint = Leaderboard::is_allocated(season_leaderboard.as_ref()) @ RustUndeadError::LeaderboardNotAllocated,
+    )]
+    pub season_leaderboard: AccountLoader<'info, Leaderboard>,
 }
 
 impl<'info> UpdateState<'info> {
     pub fn update_state(
-        &mut self,
+        ctx: Context<Self>,
         room_id: [u8; 32],
     ) -> Result<()> {
-        let battle_room = &mut self.battle_room;
-        let warrior_a = &mut self.warrior_a;
-        let warrior_b = &mut self.warrior_b;
-        let profile_a = &mut self.profile_a;
-        let profile_b = &mut self.profile_b;
-        let achievements_a = &mut self.achievements_a;
-        let achievements_b = &mut self.achievements_b;
-        let config = &mut self.config;
-        let leaderboard = &mut self.leaderboard;
+        let battle_room = &mut ctx.accounts.battle_room;
+        let warrior_a = &mut ctx.accounts.warrior_a;
+        let warrior_b = &mut ctx.accounts.warrior_b;
+        let profile_a = &mut ctx.accounts.profile_a;
+        let profile_b = &mut ctx.accounts.profile_b;
+        let achievements_a = &mut ctx.accounts.achievements_a;
+        let achievements_b = &mut ctx.accounts.achievements_b;
+        let config = &mut ctx.accounts.config;
+        let mut leaderboard = ctx.accounts.leaderboard.load_mut()?;
+        let mut rating_leaderboard = ctx.accounts.rating_leaderboard.load_mut()?;
+        let mut season_leaderboard = ctx.accounts.season_leaderboard.load_mut()?;
 
         //Check cooldown status for both warriors
         let current_time = Clock::get()?.unix_timestamp;
@@ -137,6 +163,30 @@ impl<'info> UpdateState<'info> {
         let winner_player = battle_room.winner.unwrap();
         let is_player_a_winner = winner_player == battle_room.player_a;
 
+        // ✅ Update skill ratings from the result and the opponent's rating
+        let warrior_a_rating = updated_rating(warrior_a.rating, warrior_b.rating, warrior_a.rated_battles, is_player_a_winner);
+        let warrior_b_rating = updated_rating(warrior_b.rating, warrior_a.rating, warrior_b.rated_battles, !is_player_a_winner);
+        let profile_a_rating = updated_rating(profile_a.rating, profile_b.rating, profile_a.rated_battles, is_player_a_winner);
+        let profile_b_rating = updated_rating(profile_b.rating, profile_a.rating, profile_b.rated_battles, !is_player_a_winner);
+
+        msg!("📊 Rating Updates:");
+        for (warrior, new_rating) in [(&mut *warrior_a, warrior_a_rating), (&mut *warrior_b, warrior_b_rating)] {
+            msg!("   {} - {} -> {}{}",
+                warrior.name, warrior.rating, new_rating,
+                if is_rating_provisional(warrior.rated_battles) { " (provisional)" } else { "" }
+            );
+            warrior.rating = new_rating;
+            warrior.rated_battles = warrior.rated_battles.saturating_add(1);
+        }
+        for (profile, new_rating) in [(&mut *profile_a, profile_a_rating), (&mut *profile_b, profile_b_rating)] {
+            msg!("   Player {} - {} -> {}{}",
+                profile.owner, profile.rating, new_rating,
+                if is_rating_provisional(profile.rated_battles) { " (provisional)" } else { "" }
+            );
+            profile.rating = new_rating;
+            profile.rated_battles = profile.rated_battles.saturating_add(1);
+        }
+
         // Create references instead of moving the values
         let (winner_profile, loser_profile) = if is_player_a_winner {
             (&mut *profile_a, &mut *profile_b)
@@ -185,6 +235,12 @@ impl<'info> UpdateState<'info> {
         loser_profile.total_battles_fought = loser_profile.total_battles_fought.saturating_add(1);
         loser_profile.total_points = loser_profile.total_points.saturating_add(loser_xp);
 
+        // Season points count toward the current season's board only
+        winner_profile.sync_season(config.current_season);
+        loser_profile.sync_season(config.current_season);
+        winner_profile.season_points = winner_profile.season_points.saturating_add(winner_xp);
+        loser_profile.season_points = loser_profile.season_points.saturating_add(loser_xp);
+
         msg!("👤 Profile Updates:");
         msg!("   {} - Battles: {}, Wins: {}, Points: {}", 
             winner_warrior.name, winner_profile.total_battles_fought, 
@@ -194,14 +250,44 @@ impl<'info> UpdateState<'info> {
             loser_profile.total_battles_lost, loser_profile.total_points);
 
         // ✅ Update Achievements
-        winner_achievements.winner_achievement = calculate_winner_achievement(winner_profile.total_battles_won);
-        winner_achievements.battle_achievement = calculate_battle_achievement(winner_profile.total_battles_fought);
-        winner_achievements.overall_achievements = calculate_overall_achievement(winner_profile.total_points);
-
-        loser_achievements.battle_achievement = calculate_battle_achievement(loser_profile.total_battles_fought);
-        loser_achievements.overall_achievements = calculate_overall_achievement(loser_profile.total_points);
-
-      
+        let winner_owner = winner_warrior.owner;
+        let loser_owner = loser_warrior.owner;
+        let mut unlocked = Vec::new();
+        unlocked.extend(update_achievement(
+            winner_owner,
+            AchievementCategory::Winner,
+            &mut winner_achievements.winner_achievement,
+            calculate_winner_achievement(winner_profile.total_battles_won),
+        ));
+        unlocked.extend(update_achievement(
+            winner_owner,
+            AchievementCategory::Battle,
+            &mut winner_achievements.battle_achievement,
+            calculate_battle_achievement(winner_profile.total_battles_fought),
+        ));
+        unlocked.extend(update_achievement(
+            winner_owner,
+            AchievementCategory::Overall,
+            &mut winner_achievements.overall_achievements,
+            calculate_overall_achievement(winner_profile.total_points),
+        ));
+
+        unlocked.extend(update_achievement(
+            loser_owner,
+            AchievementCategory::Battle,
+            &mut loser_achievements.battle_achievement,
+            calculate_battle_achievement(loser_profile.total_battles_fought),
+        ));
+        unlocked.extend(update_achievement(
+            loser_owner,
+            AchievementCategory::Overall,
+            &mut loser_achievements.overall_achievements,
+            calculate_overall_achievement(loser_profile.total_points),
+        ));
+
+        for event in unlocked {
+            emit_cpi!(event);
+        }
 
         msg!("🏅 Achievement Updates:");
         msg!("   {} - Winner: {:?}, Battle: {:?}, Overall: {:?}", 
@@ -219,6 +305,12 @@ impl<'info> UpdateState<'info> {
         leaderboard.update_player_score(winner_warrior.owner, winner_profile.total_points)?;
         leaderboard.update_player_score(loser_warrior.owner, loser_profile.total_points)?;
 
+        season_leaderboard.update_player_score(winner_warrior.owner, winner_profile.season_points)?;
+        season_leaderboard.update_player_score(loser_warrior.owner, loser_profile.season_points)?;
+
+        rating_leaderboard.update_player_score(warrior_a.owner, profile_a.rating)?;
+        rating_leaderboard.update_player_score(warrior_b.owner, profile_b.rating)?;
+
         // Check for leaderboard achievements
         if leaderboard.is_top_10(winner_warrior.owner) {
             msg!("🌟 {} reached top 10 leaderboard!", winner_warrior.name);
@@ -251,6 +343,27 @@ impl<'info> UpdateState<'info> {
             }
         }
 
+        // ✅ Mark room settled so profiles and leaderboard are only credited once
+        battle_room.settlement = SettlementStatus::Settled;
+        warrior_a.leave_room(&room_id);
+        warrior_b.leave_room(&room_id);
+
+        let (winner_rating, loser_rating) = if is_player_a_winner {
+            (profile_a.rating, profile_b.rating)
+        } else {
+            (profile_b.rating, profile_a.rating)
+        };
+        emit_cpi!(BattleSettled {
+            room_id,
+            winner: winner_owner,
+            loser: loser_owner,
+            winner_points: winner_xp,
+            loser_points: loser_xp,
+            winner_rating,
+            loser_rating,
+            season: config.current_season,
+        });
+
         // ✅ Final State Summary
         msg!("✅ Complete State Update Summary:");
         msg!("   📊 Profiles: Updated battle records and points");
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::helpers::*;
+use crate::error::RustUndeadError;
+
+// proofs for all questions don't fit in one transaction, so each selected
+// question is proven on its own and the room opens once every one checks out.
+// The proven answer digest is kept so the revealed answer can be checked
+// against the bank, and the leaf binds the question to its own concept
+#[derive(Accounts)]
+#[instruction(room_id: [u8; 32])]
+pub struct VerifyRoomQuestion<'info> {
+    pub question_authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = config.question_authority == question_authority.key() @ RustUndeadError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [BATTLE, room_id.as_ref()],
+        bump,
+        constraint = battle_room.room_id == room_id @ RustUndeadError::InvalidRoomId,
+        constraint = battle_room.state == BattleState::Created @ RustUndeadError::InvalidBattleState,
+    )]
+    pub battle_room: Account<'info, BattleRoom>,
+
+    #[account(
+        seeds = [QUESTION_BANK],
+        bump = question_bank.bump,
+    )]
+    pub question_bank: Account<'info, QuestionBank>,
+}
+
+impl<'info> VerifyRoomQuestion<'info> {
+    pub fn verify_room_question(
+        &mut self,
+        room_id: [u8; 32],
+        question_index: u8,
+        concept: u8,
+        answer_digest: [u8; 32],
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let battle_room = &mut self.battle_room;
+        let idx = question_index as usize;
+
+        require!(battle_room.room_id == room_id, RustUndeadError::InvalidRoomId);
+        require!(question_index < battle_room.question_count, RustUndeadError::InvalidQuestionIndex);
+        require!(
+            !battle_room.is_question_verified(question_index),
+            RustUndeadError::QuestionAlreadyVerified
+        );
+
+        // question must belong to one of the concepts the room declared
+        require!(
+            battle_room.selected_concepts.contains(&concept),
+            RustUndeadError::InvalidConceptSelection
+        );
+
+        let question_id = battle_room.selected_questions[idx];
+        let topic = battle_room.selected_topics[idx];
+        let answer_options = battle_room.answer_options[idx];
+        let leaf = compute_question_leaf(question_id, concept, topic, answer_options, &answer_digest);
+
+        require!(
+            verify_merkle_proof(&proof, &self.question_bank.merkle_root, leaf),
+            RustUndeadError::InvalidQuestionProof
+        );
+
+        battle_room.questions_verified |= 1 << question_index;
+        battle_room.answer_digests[idx] = answer_digest;
+        msg!(
+            "✅ Question {} (ID {}, concept {}, topic {}) verified against bank v{}",
+            idx + 1,
+            question_id,
+            concept,
+            topic,
+            self.question_bank.version
+        );
+
+        if battle_room.all_questions_verified() {
+            battle_room.state = BattleState::QuestionsSelected;
+            if battle_room.player_b.is_some() {
+                msg!("✅ All questions verified! Players can signal ready");
+            } else {
+                msg!("✅ All questions verified! Room ready for Player B to join!");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::{Config,Leaderboard};
-use crate::constants::{ANCHOR_DISCRIMINATOR, CONFIG, LEADERBOARD};
+use crate::constants::*;
+use crate::error::RustUndeadError;
 
 	#[derive(Accounts)]
 	pub struct Initialize<'info> {
@@ -11,7 +12,7 @@ use crate::constants::{ANCHOR_DISCRIMINATOR, CONFIG, LEADERBOARD};
 			init,
 			payer = authority,
 			space = ANCHOR_DISCRIMINATOR + Config::INIT_SPACE,
-			seeds = [CONFIG, authority.key().as_ref()],
+			seeds = [CONFIG],
 			bump,
 		)]
 		pub config: Account<'info, Config>,
@@ -19,11 +20,30 @@ use crate::constants::{ANCHOR_DISCRIMINATOR, CONFIG, LEADERBOARD};
 		#[account(
 			init,
 			payer = authority,
-			space = ANCHOR_DISCRIMINATOR + Leaderboard::INIT_SPACE,
-			seeds = [LEADERBOARD, authority.key().as_ref()],
+			space = LEADERBOARD_GROWTH_STEP,
+			seeds = [LEADERBOARD],
 			bump,
 		)]
-		pub leaderboard: Account<'info, Leaderboard>,
+		pub leaderboard: AccountLoader<'info, Leaderboard>,
+
+		// same board ordered by skill rating instead of points
+		#[account(
+			init,
+			payer = authority,
+			space = LEADERBOARD_GROWTH_STEP,
+			seeds = [RATING_LEADERBOARD],
+			bump,
+		)]
+		pub rating_leaderboard: AccountLoader<'info, Leaderboard>,
+
+		#[account(
+			init,
+			payer = authority,
+			space = LEADERBOARD_GROWTH_STEP,
+			seeds = [SEASON_LEADERBOARD, 1u32.to_le_bytes().as_ref()],
+			bump,
+		)]
+		pub season_leaderboard: AccountLoader<'info, Leaderboard>,
 		pub system_program : Program<'info, System>
 	}
 
@@ -31,29 +51,41 @@ use crate::constants::{ANCHOR_DISCRIMINATOR, CONFIG, LEADERBOARD};
 		pub fn initialize (
 		&mut self,
 		cooldown_time: u64,
+		question_authority: Pubkey,
 		bumps: &InitializeBumps
 		) -> Result<()> {
+		require!(cooldown_time <= MAX_COOLDOWN_TIME, RustUndeadError::InvalidConfigValue);
 		let clock = Clock::get()?;
 
 		self.config.set_inner(
 			Config { 
 				admin: self.authority.key(), 
+				pending_admin: None,
+				question_authority,
 				total_warriors: 0, 
 				cooldown_time, 
+				winner_cooldown_pct: DEFAULT_WINNER_COOLDOWN_PCT,
+				loser_cooldown_pct: DEFAULT_LOSER_COOLDOWN_PCT,
+				hp_cooldown_penalty_pct: DEFAULT_HP_COOLDOWN_PENALTY_PCT,
+				hp_regen_per_hour: DEFAULT_HP_REGEN_PER_HOUR,
+				heal_cost_lamports: DEFAULT_HEAL_COST_LAMPORTS,
+				rename_fee_lamports: DEFAULT_RENAME_FEE_LAMPORTS,
+				rename_cooldown: DEFAULT_RENAME_COOLDOWN,
+				treasury: self.authority.key(),
+				wager_fee_bps: DEFAULT_WAGER_FEE_BPS,
+				max_level: DEFAULT_MAX_LEVEL,
+				answer_timeout: DEFAULT_ANSWER_TIMEOUT,
+				idle_timeout: DEFAULT_IDLE_TIMEOUT,
 				total_battles: 0, 
+				current_season: 1,
+				season_started_at: clock.unix_timestamp,
 				is_paused: false, 
 				created_at: clock.unix_timestamp,
 				bump: bumps.config,
 			 });
 
-			 self.leaderboard.set_inner(
-				Leaderboard{
-					top_players: [Pubkey::default(); 20],
-					top_scores: [0u32; 20], 
-					last_updated: clock.unix_timestamp,
-					bump: bumps.leaderboard,
-				}
-			 );
+			// boards start empty and small, grow_leaderboard brings them to full size
+			msg!("🏆 Leaderboards created, grow each to {} bytes before the first battle", Leaderboard::SPACE);
 			Ok(())
 		}
 	}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::helpers::*;
+use crate::error::RustUndeadError;
+
+#[derive(Accounts)]
+pub struct DequeueWarrior<'info> {
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = is_warrior_holder(&warrior, &player.key(), &warrior_nft_account) @ RustUndeadError::NotWarriorOwner,
+    )]
+    pub warrior: Account<'info, UndeadWarrior>,
+
+    // the warrior's NFT token account, required once the warrior is minted
+    pub warrior_nft_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [MATCH_QUEUE],
+        bump = match_queue.bump,
+    )]
+    pub match_queue: Account<'info, MatchQueue>,
+}
+
+impl<'info> DequeueWarrior<'info> {
+    pub fn dequeue_warrior(&mut self) -> Result<()> {
+        let match_queue = &mut self.match_queue;
+        let warrior = &mut self.warrior;
+
+        // An expired entry is already gone, only the flag is left to clear
+        match match_queue.position(&warrior.key()) {
+            Some(index) => {
+                match_queue.entries.remove(index);
+                msg!("🚪 {} left the match queue", warrior.name);
+            },
+            None => {
+                require!(warrior.queued, RustUndeadError::NotQueued);
+                msg!("🚪 {}'s queue entry had expired", warrior.name);
+            },
+        }
+        warrior.queued = false;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::helpers::*;
+use crate::error::RustUndeadError;
+
+#[derive(Accounts)]
+pub struct EnqueueWarrior<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [UNDEAD_WARRIOR, warrior.creator.as_ref(), warrior.warrior_index.to_le_bytes().as_ref()],
+        bump = warrior.bump,
+        constraint = warrior.current_room.is_none() @ RustUndeadError::WarriorInRoom,
+    )]
+    pub warrior: Account<'info, UndeadWarrior>,
+
+    // NFT token account for minted warriors, see prepare_warrior_for_battle
+    pub warrior_nft_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = !config.is_paused @ RustUndeadError::GamePaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [MATCH_QUEUE],
+        bump = match_queue.bump,
+    )]
+    pub match_queue: Account<'info, MatchQueue>,
+}
+
+impl<'info> EnqueueWarrior<'info> {
+    pub fn enqueue_warrior(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        // Same entry rules as a room
+        prepare_warrior_for_battle(
+            &mut self.warrior,
+            self.player.key(),
+            &self.warrior_nft_account,
+            &self.config,
+            now,
+        )?;
+
+        let match_queue = &mut self.match_queue;
+        let expired = match_queue.purge_expired(now, MATCH_QUEUE_TTL);
+        if expired > 0 {
+            msg!("🧹 Removed {} expired queue entries", expired);
+        }
+
+        let warrior_key = self.warrior.key();
+        require!(!match_queue.is_queued(&warrior_key), RustUndeadError::AlreadyQueued);
+        require!(match_queue.entries.len() < MATCH_QUEUE_CAPACITY, RustUndeadError::MatchQueueFull);
+
+        match_queue.entries.push(QueueEntry {
+            player: self.player.key(),
+            warrior: warrior_key,
+            level: self.warrior.level,
+            rating: self.warrior.rating,
+            provisional: is_rating_provisional(self.warrior.rated_battles),
+            enqueued_at: now,
+        });
+        self.warrior.queued = true;
+
+        msg!("🎯 {} (level {}, rating {}) joined the match queue", self.warrior.name, self.warrior.level, self.warrior.rating);
+        msg!("   Waiting: {}/{}", match_queue.entries.len(), MATCH_QUEUE_CAPACITY);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
+
+#[derive(Accounts)]
+pub struct InitializeMatchQueue<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == config.admin @ RustUndeadError::NotAuthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + MatchQueue::INIT_SPACE,
+        seeds = [MATCH_QUEUE],
+        bump,
+    )]
+    pub match_queue: Account<'info, MatchQueue>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeMatchQueue<'info> {
+    pub fn initialize_match_queue(&mut self, bumps: &InitializeMatchQueueBumps) -> Result<()> {
+        self.match_queue.set_inner(MatchQueue {
+            entries: Vec::new(),
+            total_matched: 0,
+            bump: bumps.match_queue,
+        });
+
+        msg!("🎯 Match queue initialized with {} slots", MATCH_QUEUE_CAPACITY);
+
+        Ok(())
+    }
+}
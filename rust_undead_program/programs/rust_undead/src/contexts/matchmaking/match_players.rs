@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::helpers::*;
+use crate::error::RustUndeadError;
+
+// permissionless crank, pairs two queued warriors and opens a room with both
+// seats filled. The question authority then assigns the room's questions
+#[derive(Accounts)]
+#[instruction(room_id: [u8; 32])]
+pub struct MatchPlayers<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [MATCH_QUEUE],
+        bump = match_queue.bump,
+    )]
+    pub match_queue: Account<'info, MatchQueue>,
+
+    // each warrior must be the one its queue entry was made for
+    #[account(
+        mut,
+        seeds = [UNDEAD_WARRIOR, warrior_a.creator.as_ref(), warrior_a.warrior_index.to_le_bytes().as_ref()],
+        bump = warrior_a.bump,
+        constraint = warrior_a.current_room.is_none() @ RustUndeadError::WarriorInRoom,
+    )]
+    pub warrior_a: Account<'info, UndeadWarrior>,
+
+    // NFT token accounts for minted warriors, see prepare_warrior_for_battle
+    pub warrior_a_nft_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [UNDEAD_WARRIOR, warrior_b.creator.as_ref(), warrior_b.warrior_index.to_le_bytes().as_ref()],
+        bump = warrior_b.bump,
+        constraint = warrior_b.current_room.is_none() @ RustUndeadError::WarriorInRoom,
+        constraint = warrior_b.key() != warrior_a.key() @ RustUndeadError::SameWarriorCannotBattle,
+    )]
+    pub warrior_b: Account<'info, UndeadWarrior>,
+
+    pub warrior_b_nft_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = !config.is_paused @ RustUndeadError::GamePaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = cranker,
+        space = ANCHOR_DISCRIMINATOR + BattleRoom::INIT_SPACE,
+        seeds = [BATTLE, room_id.as_ref()],
+        bump,
+    )]
+    pub battle_room: Account<'info, BattleRoom>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MatchPlayers<'info> {
+    pub fn match_players(
+        &mut self,
+        room_id: [u8; 32],
+        bumps: &MatchPlayersBumps,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let match_queue = &mut self.match_queue;
+
+        let expired = match_queue.purge_expired(now, MATCH_QUEUE_TTL);
+        if expired > 0 {
+            msg!("🧹 Removed {} expired queue entries", expired);
+        }
+
+        let index_a = match_queue.position(&self.warrior_a.key()).ok_or(RustUndeadError::NotQueued)?;
+        let index_b = match_queue.position(&self.warrior_b.key()).ok_or(RustUndeadError::NotQueued)?;
+        let entry_a = match_queue.entries[index_a];
+        let entry_b = match_queue.entries[index_b];
+
+        require!(entry_a.player != entry_b.player, RustUndeadError::IncompatibleMatch);
+        require!(
+            entry_a.level.abs_diff(entry_b.level) <= MATCH_LEVEL_RANGE,
+            RustUndeadError::IncompatibleMatch
+        );
+        if !entry_a.provisional && !entry_b.provisional {
+            require!(
+                entry_a.rating.abs_diff(entry_b.rating) <= MATCH_RATING_RANGE,
+                RustUndeadError::IncompatibleMatch
+            );
+        }
+
+        // Warriors may have battled or changed hands since they queued
+        for (warrior, entry, nft_account) in [
+            (&mut self.warrior_a, &entry_a, &self.warrior_a_nft_account),
+            (&mut self.warrior_b, &entry_b, &self.warrior_b_nft_account),
+        ] {
+            require!(warrior.key() == entry.warrior, RustUndeadError::InvalidWarrior);
+            prepare_warrior_for_battle(warrior, entry.player, nft_account, &self.config, now)?;
+        }
+
+        // Remove the later index first so the earlier one stays valid
+        match_queue.entries.remove(index_a.max(index_b));
+        match_queue.entries.remove(index_a.min(index_b));
+        match_queue.total_matched = match_queue.total_matched.saturating_add(1);
+        self.warrior_a.current_room = Some(room_id);
+        self.warrior_b.current_room = Some(room_id);
+        self.warrior_a.queued = false;
+        self.warrior_b.queued = false;
+
+        self.battle_room.set_inner(
+            BattleRoom {
+                room_id,
+                created_at: now,
+                rent_payer: self.cranker.key(),
+                player_a: entry_a.player,
+                player_b: Some(entry_b.player),
+                warrior_a: entry_a.warrior,
+                warrior_b: Some(entry_b.warrior),
+                selected_concepts: [0; 5],
+                question_count: 0,
+                selected_topics: [0; MAX_QUESTIONS],
+                selected_questions: [0; MAX_QUESTIONS],
+                answer_options: [0; MAX_QUESTIONS],
+                questions_verified: 0,
+                answer_digests: [[0u8; 32]; MAX_QUESTIONS],
+                answer_commitments: [[0u8; 32]; MAX_QUESTIONS],
+                revealed_answers: [None; MAX_QUESTIONS],
+                state: BattleState::Created,
+                settlement: SettlementStatus::Unsettled,
+                player_a_ready: false,
+                player_b_ready: false,
+                current_question: 0,
+                player_a_answers: [None; MAX_QUESTIONS],
+                player_b_answers: [None; MAX_QUESTIONS],
+                player_a_sealed_answer: None,
+                player_b_sealed_answer: None,
+                damage_entropy: [0u8; 32],
+                player_a_correct: 0,
+                player_b_correct: 0,
+                winner: None,
+                battle_duration: 0,
+                bump: bumps.battle_room,
+                battle_start_time: 0,
+                answer_timeout: 0,
+                question_deadline: 0,
+                last_activity_at: now,
+                wager_amount: 0,
+                wager_mint: None,
+                wager_status: WagerStatus::None,
+            }
+        );
+
+        msg!("🤝 Matched {} (level {}, rating {}) vs {} (level {}, rating {})",
+            self.warrior_a.name, entry_a.level, entry_a.rating,
+            self.warrior_b.name, entry_b.level, entry_b.rating
+        );
+        msg!("✅ Room ID: {:?}", room_id);
+        msg!("📚 Waiting for the question authority to assign questions");
+
+        emit!(RoomCreated {
+            room_id,
+            player_a: entry_a.player,
+            warrior_a: entry_a.warrior,
+            player_b: Some(entry_b.player),
+            warrior_b: Some(entry_b.warrior),
+            question_count: 0,
+            wager_amount: 0,
+            wager_mint: None,
+        });
+
+        Ok(())
+    }
+}
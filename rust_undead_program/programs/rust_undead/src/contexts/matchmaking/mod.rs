@@ -0,0 +1,11 @@
+pub mod initialize_queue;
+pub use initialize_queue::*;
+
+pub mod enqueue;
+pub use enqueue::*;
+
+pub mod dequeue;
+pub use dequeue::*;
+
+pub mod match_players;
+pub use match_players::*;
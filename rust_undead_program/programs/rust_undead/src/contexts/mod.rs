@@ -1,10 +1,20 @@
 pub mod battle_room;
 pub mod rollup;
 pub mod initialize;
+pub mod questions;
+pub mod admin;
+pub mod warriors;
+pub mod matchmaking;
+pub mod rankings;
 
 
 
 pub use battle_room::*;
 pub use rollup::*;
 pub use initialize::*;
+pub use questions::*;
+pub use admin::*;
+pub use warriors::*;
+pub use matchmaking::*;
+pub use rankings::*;
 
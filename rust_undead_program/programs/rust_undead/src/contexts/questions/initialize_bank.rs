@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
+
+#[derive(Accounts)]
+pub struct InitializeQuestionBank<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == config.admin @ RustUndeadError::NotAuthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + QuestionBank::INIT_SPACE,
+        seeds = [QUESTION_BANK],
+        bump,
+    )]
+    pub question_bank: Account<'info, QuestionBank>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeQuestionBank<'info> {
+    pub fn initialize_question_bank(
+        &mut self,
+        content_authority: Pubkey,
+        merkle_root: [u8; 32],
+        question_count: u32,
+        concept_count: u8,
+        bumps: &InitializeQuestionBankBumps,
+    ) -> Result<()> {
+        require!(concept_count > 0, RustUndeadError::InvalidConceptSelection);
+
+        self.question_bank.set_inner(QuestionBank {
+            authority: content_authority,
+            merkle_root,
+            question_count,
+            concept_count,
+            version: 1,
+            updated_at: Clock::get()?.unix_timestamp,
+            bump: bumps.question_bank,
+        });
+
+        msg!("📚 Question bank initialized");
+        msg!("   Content authority: {}", content_authority);
+        msg!("   Questions: {}, Concepts: {}", question_count, concept_count);
+
+        Ok(())
+    }
+}
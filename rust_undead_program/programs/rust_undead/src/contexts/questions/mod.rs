@@ -0,0 +1,5 @@
+pub mod initialize_bank;
+pub use initialize_bank::*;
+
+pub mod update_bank;
+pub use update_bank::*;
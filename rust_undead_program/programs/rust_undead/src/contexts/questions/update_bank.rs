@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
+
+#[derive(Accounts)]
+pub struct UpdateQuestionBank<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [QUESTION_BANK],
+        bump = question_bank.bump,
+        constraint = question_bank.authority == authority.key() @ RustUndeadError::NotAuthorized,
+    )]
+    pub question_bank: Account<'info, QuestionBank>,
+}
+
+impl<'info> UpdateQuestionBank<'info> {
+    pub fn update_question_bank(
+        &mut self,
+        merkle_root: [u8; 32],
+        question_count: u32,
+        concept_count: u8,
+    ) -> Result<()> {
+        require!(concept_count > 0, RustUndeadError::InvalidConceptSelection);
+
+        let question_bank = &mut self.question_bank;
+        question_bank.merkle_root = merkle_root;
+        question_bank.question_count = question_count;
+        question_bank.concept_count = concept_count;
+        question_bank.version = question_bank.version.saturating_add(1);
+        question_bank.updated_at = Clock::get()?.unix_timestamp;
+
+        msg!("📚 Question bank updated to version {}", question_bank.version);
+        msg!("   Questions: {}, Concepts: {}", question_count, concept_count);
+
+        Ok(())
+    }
+}
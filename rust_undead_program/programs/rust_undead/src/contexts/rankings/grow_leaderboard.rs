@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
+
+// boards are too big to create in one go, each call extends one by the max
+// realloc step until it reaches Leaderboard::SPACE. Anyone can pay for it
+#[derive(Accounts)]
+pub struct GrowLeaderboard<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = !Leaderboard::is_allocated(leaderboard.as_ref()) @ RustUndeadError::LeaderboardAllocated,
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> GrowLeaderboard<'info> {
+    pub fn grow_leaderboard(&mut self) -> Result<()> {
+        let info = self.leaderboard.to_account_info();
+        let new_len = (info.data_len() + LEADERBOARD_GROWTH_STEP).min(Leaderboard::SPACE);
+
+        let rent_due = Rent::get()?.minimum_balance(new_len).saturating_sub(info.lamports());
+        if rent_due > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.payer.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+
+        info.resize(new_len)?;
+        msg!("📈 Leaderboard {} grown to {}/{} bytes", info.key(), new_len, Leaderboard::SPACE);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod grow_leaderboard;
+pub use grow_leaderboard::*;
+
+pub mod query_rank;
+pub use query_rank::*;
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::RustUndeadError;
+
+// read-only rank lookup for any board, returned to the caller as return data
+#[derive(Accounts)]
+pub struct QueryRank<'info> {
+    #[account(
+        constraint = Leaderboard::is_allocated(leaderboard.as_ref()) @ RustUndeadError::LeaderboardNotAllocated,
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+}
+
+impl<'info> QueryRank<'info> {
+    pub fn query_rank(&self, player: Pubkey) -> Result<Option<u32>> {
+        let leaderboard = self.leaderboard.load()?;
+        let rank = leaderboard.get_player_rank(player).map(|rank| rank as u32);
+
+        match rank {
+            Some(rank) => msg!("📈 {} is ranked #{} of {}", player, rank, leaderboard.len),
+            None => msg!("📈 {} is not on this leaderboard", player),
+        }
+
+        Ok(rank)
+    }
+}
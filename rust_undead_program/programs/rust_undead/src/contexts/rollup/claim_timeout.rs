@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
+use ephemeral_rollups_sdk::anchor::commit;
+use ephemeral_rollups_sdk::ephem::{commit_accounts, commit_and_undelegate_accounts};
+
+// anyone can end a battle whose current question is past its deadline.
+// A single absent player forfeits and the room is left to settle. When
+// neither player answered, or the question authority never revealed the
+// key, nobody is at fault: the room ends as no contest, the warriors are
+// healed and released, and everything goes back to the base layer so the
+// stakes can be refunded
+#[commit]
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(room_id: [u8; 32])]
+pub struct ClaimTimeout<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BATTLE, room_id.as_ref()],
+        bump,
+        constraint = battle_room.room_id == room_id @ RustUndeadError::InvalidRoomId,
+        constraint = battle_room.state == BattleState::InProgress @ RustUndeadError::InvalidBattleState,
+    )]
+    pub battle_room: Account<'info, BattleRoom>,
+
+    #[account(
+        mut,
+        constraint = warrior_a.key() == battle_room.warrior_a @ RustUndeadError::InvalidWarrior,
+    )]
+    pub warrior_a: Account<'info, UndeadWarrior>,
+
+    #[account(
+        mut,
+        constraint = warrior_b.key() == battle_room.warrior_b.unwrap() @ RustUndeadError::InvalidWarrior,
+    )]
+    pub warrior_b: Account<'info, UndeadWarrior>,
+}
+
+impl<'info> ClaimTimeout<'info> {
+    pub fn claim_timeout(
+        ctx: Context<Self>,
+        room_id: [u8; 32],
+    ) -> Result<()> {
+        let battle_room = &mut ctx.accounts.battle_room;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(battle_room.room_id == room_id, RustUndeadError::InvalidRoomId);
+        require!(
+            current_time > battle_room.question_deadline,
+            RustUndeadError::DeadlineNotReached
+        );
+
+        let player_a = battle_room.player_a;
+        let player_b = battle_room.player_b.ok_or(RustUndeadError::PlayerNotInRoom)?;
+        let a_acted = battle_room.has_player_acted(&player_a);
+        let b_acted = battle_room.has_player_acted(&player_b);
+
+        msg!("⌛ Question {} timed out", battle_room.current_question + 1);
+
+        match (a_acted, b_acted) {
+            (true, false) => {
+                battle_room.winner = Some(player_a);
+                msg!("🏳️ Player B did not respond and forfeits - {} wins", ctx.accounts.warrior_a.name);
+            },
+            (false, true) => {
+                battle_room.winner = Some(player_b);
+                msg!("🏳️ Player A did not respond and forfeits - {} wins", ctx.accounts.warrior_b.name);
+            },
+            (false, false) => {
+                battle_room.winner = None;
+                msg!("🤷 Neither player responded - no contest");
+            },
+            (true, true) => {
+                battle_room.winner = None;
+                msg!("🤷 The answer key was never revealed - no contest");
+            },
+        }
+
+        battle_room.state = BattleState::Completed;
+        battle_room.battle_duration = (current_time - battle_room.battle_start_time) as u32;
+
+        if ctx.accounts.battle_room.is_no_contest() {
+            // Release both warriors without penalties
+            let warrior_a = &mut ctx.accounts.warrior_a;
+            let warrior_b = &mut ctx.accounts.warrior_b;
+            warrior_a.current_hp = warrior_a.max_hp;
+            warrior_a.cooldown_expires_at = 0;
+            warrior_b.current_hp = warrior_b.max_hp;
+            warrior_b.cooldown_expires_at = 0;
+            warrior_a.leave_room(&room_id);
+            warrior_b.leave_room(&room_id);
+            msg!("✅ Released {} and {} from battle", warrior_a.name, warrior_b.name);
+
+            commit_and_undelegate_accounts(
+                &ctx.accounts.caller,
+                vec![
+                    &ctx.accounts.battle_room.to_account_info(),
+                    &ctx.accounts.warrior_a.to_account_info(),
+                    &ctx.accounts.warrior_b.to_account_info(),
+                ],
+                &ctx.accounts.magic_context,
+                &ctx.accounts.magic_program,
+            )?;
+            msg!("🏁 Battle ended as no contest! Returned to base layer, stakes are refundable");
+        } else {
+            commit_accounts(
+                &ctx.accounts.caller,
+                vec![
+                    &ctx.accounts.battle_room.to_account_info(),
+                    &ctx.accounts.warrior_a.to_account_info(),
+                    &ctx.accounts.warrior_b.to_account_info(),
+                ],
+                &ctx.accounts.magic_context,
+                &ctx.accounts.magic_program,
+            )?;
+            msg!("🏁 Battle completed by timeout! Final state committed to rollup for settlement");
+        }
+
+        emit_cpi!(BattleCompleted {
+            room_id,
+            winner: ctx.accounts.battle_room.winner,
+            reason: CompletionReason::Timeout,
+            player_a_score: ctx.accounts.battle_room.player_a_correct,
+            player_b_score: ctx.accounts.battle_room.player_b_correct,
+            warrior_a_hp: ctx.accounts.warrior_a.current_hp,
+            warrior_b_hp: ctx.accounts.warrior_b.current_hp,
+            duration: ctx.accounts.battle_room.battle_duration,
+        });
+
+        Ok(())
+    }
+}
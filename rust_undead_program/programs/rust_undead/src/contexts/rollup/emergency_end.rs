@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::events::*;
 use crate::constants::*;
 use crate::error::RustUndeadError;
 
@@ -12,6 +13,7 @@ use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
 // It is intended for use in extreme situations where the battle cannot continue,
 // such as a critical bug or exploit that prevents normal operation.
 #[commit]
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(room_id: [u8; 32])]
 pub struct EmergencyUndelegateAndEnd<'info> {
@@ -51,14 +53,14 @@ pub struct EmergencyUndelegateAndEnd<'info> {
 
 impl<'info> EmergencyUndelegateAndEnd<'info> {
     pub fn emergency_undelegate_and_end(
-        &mut self,
+        ctx: Context<Self>,
         room_id: [u8; 32],
     ) -> Result<()> {
-        let battle_room = &mut self.battle_room;
-        let warrior_a = &mut self.warrior_a;
-        let warrior_b = &mut self.warrior_b;
-        let config = &mut self.config;
-        let authority = self.authority.key();
+        let battle_room = &mut ctx.accounts.battle_room;
+        let warrior_a = &mut ctx.accounts.warrior_a;
+        let warrior_b = &mut ctx.accounts.warrior_b;
+        let config = &mut ctx.accounts.config;
+        let authority = ctx.accounts.authority.key();
 
         // Validate admin authorization
         require!(
@@ -72,7 +74,7 @@ impl<'info> EmergencyUndelegateAndEnd<'info> {
         msg!("🚨 EMERGENCY ADMIN INTERVENTION");
         msg!("   Admin: {}", authority);
         msg!("   Room ID: {:?}", room_id);
-        msg!("   Battle Progress: Question {}/10", battle_room.current_question + 1);
+        msg!("   Battle Progress: Question {}/{}", battle_room.current_question + 1, battle_room.question_count);
         msg!("   Duration: {} seconds", battle_duration);
 
         // Cancel Battle as No Contest
@@ -93,13 +95,15 @@ impl<'info> EmergencyUndelegateAndEnd<'info> {
         warrior_a.cooldown_expires_at = current_time + emergency_cooldown as i64;
         warrior_b.last_battle_at = current_time;
         warrior_b.cooldown_expires_at = current_time + emergency_cooldown as i64;
+        warrior_a.leave_room(&room_id);
+        warrior_b.leave_room(&room_id);
 
         msg!("⚕️ Warriors fully healed - emergency cooldown: {} seconds", emergency_cooldown);
 
         // Log Emergency Statistics
         msg!("📊 Emergency Battle Statistics:");
         msg!("   Final State: CANCELLED (No Contest)");
-        msg!("   Questions Completed: {}/10", battle_room.current_question);
+        msg!("   Questions Completed: {}/{}", battle_room.current_question, battle_room.question_count);
         msg!("   Final Scores - A: {}, B: {}", battle_room.player_a_correct, battle_room.player_b_correct);
         msg!("   Warriors Reset - {}: {}/100 HP, {}: {}/100 HP", 
             warrior_a.name, warrior_a.current_hp,
@@ -108,14 +112,14 @@ impl<'info> EmergencyUndelegateAndEnd<'info> {
 
         // Emergency Undelegate All Accounts
         commit_and_undelegate_accounts(
-            &self.authority,
+            &ctx.accounts.authority,
             vec![
-                &self.battle_room.to_account_info(),
-                &self.warrior_a.to_account_info(),
-                &self.warrior_b.to_account_info(),
+                &ctx.accounts.battle_room.to_account_info(),
+                &ctx.accounts.warrior_a.to_account_info(),
+                &ctx.accounts.warrior_b.to_account_info(),
             ],
-            &self.magic_context,
-            &self.magic_program,
+            &ctx.accounts.magic_context,
+            &ctx.accounts.magic_program,
         )?;
 
         msg!("🚨 EMERGENCY INTERVENTION COMPLETE");
@@ -124,6 +128,12 @@ impl<'info> EmergencyUndelegateAndEnd<'info> {
         msg!("⚕️ Warriors healed and on minimal cooldown until: {}", current_time + emergency_cooldown as i64);
         msg!("⚠️ No XP, achievements, or leaderboard changes applied");
 
+        emit_cpi!(RoomCancelled {
+            room_id,
+            cancelled_by: authority,
+            reason: CancelReason::Emergency,
+        });
+
         Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
+
+use ephemeral_rollups_sdk::anchor::commit;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+
+// permissionless crank for delegated rooms that never finish: once idle past
+// Config.idle_timeout the room is cancelled as no contest, both warriors are
+// healed and released, and everything goes back to the base layer
+#[commit]
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(room_id: [u8; 32])]
+pub struct ForceUndelegate<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BATTLE, room_id.as_ref()],
+        bump,
+        constraint = battle_room.room_id == room_id @ RustUndeadError::InvalidRoomId,
+        constraint = matches!(battle_room.state, BattleState::ReadyForDelegation | BattleState::InProgress) @ RustUndeadError::CannotUndelegate,
+    )]
+    pub battle_room: Account<'info, BattleRoom>,
+
+    #[account(
+        mut,
+        constraint = warrior_a.key() == battle_room.warrior_a @ RustUndeadError::InvalidWarrior,
+    )]
+    pub warrior_a: Account<'info, UndeadWarrior>,
+
+    #[account(
+        mut,
+        constraint = warrior_b.key() == battle_room.warrior_b.unwrap() @ RustUndeadError::InvalidWarrior,
+    )]
+    pub warrior_b: Account<'info, UndeadWarrior>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> ForceUndelegate<'info> {
+    pub fn force_undelegate(
+        ctx: Context<Self>,
+        room_id: [u8; 32],
+    ) -> Result<()> {
+        let battle_room = &mut ctx.accounts.battle_room;
+        let warrior_a = &mut ctx.accounts.warrior_a;
+        let warrior_b = &mut ctx.accounts.warrior_b;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(battle_room.room_id == room_id, RustUndeadError::InvalidRoomId);
+
+        let idle_for = current_time.saturating_sub(battle_room.last_activity_at);
+        require!(idle_for > ctx.accounts.config.idle_timeout, RustUndeadError::RoomNotIdle);
+
+        msg!("🧹 Force undelegating idle battle room");
+        msg!("   Room ID: {:?}", room_id);
+        msg!("   Idle for {} seconds (timeout {})", idle_for, ctx.accounts.config.idle_timeout);
+
+        // No contest
+        battle_room.state = BattleState::Cancelled;
+        battle_room.winner = None;
+        if battle_room.battle_start_time > 0 {
+            battle_room.battle_duration = (current_time - battle_room.battle_start_time) as u32;
+        }
+
+        // Release both warriors without penalties
+        warrior_a.current_hp = warrior_a.max_hp;
+        warrior_a.cooldown_expires_at = 0;
+        warrior_b.current_hp = warrior_b.max_hp;
+        warrior_b.cooldown_expires_at = 0;
+        warrior_a.leave_room(&room_id);
+        warrior_b.leave_room(&room_id);
+        msg!("✅ Released {} and {} from battle", warrior_a.name, warrior_b.name);
+
+        commit_and_undelegate_accounts(
+            &ctx.accounts.caller,
+            vec![
+                &ctx.accounts.battle_room.to_account_info(),
+                &ctx.accounts.warrior_a.to_account_info(),
+                &ctx.accounts.warrior_b.to_account_info(),
+            ],
+            &ctx.accounts.magic_context,
+            &ctx.accounts.magic_program,
+        )?;
+
+        msg!("✅ Room cancelled as no contest and returned to base layer");
+
+        emit_cpi!(RoomCancelled {
+            room_id,
+            cancelled_by: ctx.accounts.caller.key(),
+            reason: CancelReason::Idle,
+        });
+
+        Ok(())
+    }
+}
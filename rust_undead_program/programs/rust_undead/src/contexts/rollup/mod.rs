@@ -10,4 +10,12 @@ pub mod answers;
 pub use answers::*;
 
 pub mod undelegate;
-pub use undelegate::*;
\ No newline at end of file
+pub use undelegate::*;
+pub mod reveal_answer;
+pub use reveal_answer::*;
+pub mod reveal_player_answer;
+pub use reveal_player_answer::*;
+pub mod claim_timeout;
+pub use claim_timeout::*;
+pub mod force_undelegate;
+pub use force_undelegate::*;
\ No newline at end of file
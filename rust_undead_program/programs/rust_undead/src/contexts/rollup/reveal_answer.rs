@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use crate::helpers::*;
+use crate::state::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
+use ephemeral_rollups_sdk::anchor::commit;
+use ephemeral_rollups_sdk::ephem::commit_accounts;
+
+// question authority opens one entry of the committed answer key
+// once both players have revealed their sealed answers, then the round is scored
+#[commit]
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(room_id: [u8; 32])]
+pub struct RevealAnswer<'info> {
+    #[account(mut)]
+    pub question_authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = config.question_authority == question_authority.key() @ RustUndeadError::NotAuthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [BATTLE, room_id.as_ref()],
+        bump,
+        constraint = battle_room.room_id == room_id @ RustUndeadError::InvalidRoomId,
+        constraint = battle_room.state == BattleState::InProgress @ RustUndeadError::InvalidBattleState,
+    )]
+    pub battle_room: Account<'info, BattleRoom>,
+
+    #[account(
+        mut,
+        constraint = warrior_a.key() == battle_room.warrior_a @ RustUndeadError::InvalidWarrior,
+    )]
+    pub warrior_a: Account<'info, UndeadWarrior>,
+
+    #[account(
+        mut,
+        constraint = warrior_b.key() == battle_room.warrior_b.unwrap() @ RustUndeadError::InvalidWarrior,
+    )]
+    pub warrior_b: Account<'info, UndeadWarrior>,
+}
+
+impl<'info> RevealAnswer<'info> {
+    pub fn reveal_answer(
+        ctx: Context<Self>,
+        room_id: [u8; 32],
+        question_index: u8,
+        answer: u8,
+        salt: [u8; 32],
+        answer_salt: [u8; 32],
+    ) -> Result<()> {
+        let battle_room = &mut ctx.accounts.battle_room;
+        let idx = question_index as usize;
+
+        require!(battle_room.room_id == room_id, RustUndeadError::InvalidRoomId);
+        require!(
+            question_index == battle_room.current_question,
+            RustUndeadError::InvalidQuestionIndex
+        );
+        require!(
+            battle_room.is_valid_choice(question_index, answer),
+            RustUndeadError::InvalidAnswerChoice
+        );
+        require!(
+            battle_room.revealed_answers[idx].is_none(),
+            RustUndeadError::AnswerAlreadyRevealed
+        );
+
+        // never open the key while a player could still change their answer
+        require!(
+            battle_room.both_players_answered(question_index),
+            RustUndeadError::AnswersNotSubmitted
+        );
+
+        let commitment = compute_answer_commitment(&room_id, question_index, answer, &salt);
+        require!(
+            commitment == battle_room.answer_commitments[idx],
+            RustUndeadError::InvalidAnswerReveal
+        );
+
+        // and the key must agree with the answer proven against the bank
+        let question_id = battle_room.selected_questions[idx];
+        require!(
+            compute_answer_digest(question_id, answer, &answer_salt) == battle_room.answer_digests[idx],
+            RustUndeadError::AnswerNotInBank
+        );
+
+        battle_room.revealed_answers[idx] = Some(answer);
+        msg!("🔓 Answer for question {} revealed and verified against commitment and bank", idx + 1);
+
+        let resolved = resolve_question(battle_room, &mut ctx.accounts.warrior_a, &mut ctx.accounts.warrior_b)?;
+        emit_cpi!(resolved);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        battle_room.battle_duration = (current_time - battle_room.battle_start_time) as u32;
+        battle_room.last_activity_at = current_time;
+
+        // Only commit when battle is complete
+        if battle_room.state == BattleState::Completed {
+            commit_accounts(
+                &ctx.accounts.question_authority,
+                vec![
+                    &ctx.accounts.battle_room.to_account_info(),
+                    &ctx.accounts.warrior_a.to_account_info(),
+                    &ctx.accounts.warrior_b.to_account_info(),
+                ],
+                &ctx.accounts.magic_context,
+                &ctx.accounts.magic_program,
+            )?;
+            msg!("🏁 Battle completed! Final state committed to rollup for settlement");
+
+            let eliminated = ctx.accounts.warrior_a.current_hp == 0 || ctx.accounts.warrior_b.current_hp == 0;
+            emit_cpi!(BattleCompleted {
+                room_id,
+                winner: ctx.accounts.battle_room.winner,
+                reason: if eliminated { CompletionReason::Elimination } else { CompletionReason::Decision },
+                player_a_score: ctx.accounts.battle_room.player_a_correct,
+                player_b_score: ctx.accounts.battle_room.player_b_correct,
+                warrior_a_hp: ctx.accounts.warrior_a.current_hp,
+                warrior_b_hp: ctx.accounts.warrior_b.current_hp,
+                duration: ctx.accounts.battle_room.battle_duration,
+            });
+        } else {
+            msg!("⚔️ Battle continues... Question {} ready", battle_room.current_question + 1);
+        }
+
+        Ok(())
+    }
+}
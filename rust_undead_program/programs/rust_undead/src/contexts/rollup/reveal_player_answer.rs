@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::helpers::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
+
+// player opens their sealed answer once both players have sealed,
+// the round is scored after the question authority reveals the key
+#[derive(Accounts)]
+#[instruction(room_id: [u8; 32])]
+pub struct RevealPlayerAnswer<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [BATTLE, room_id.as_ref()],
+        bump,
+        constraint = battle_room.room_id == room_id @ RustUndeadError::InvalidRoomId,
+        constraint = battle_room.state == BattleState::InProgress @ RustUndeadError::InvalidBattleState,
+        constraint = battle_room.is_player_in_room(&player.key()) @ RustUndeadError::PlayerNotInRoom,
+        constraint = !battle_room.has_player_answered(&player.key(), battle_room.current_question) @ RustUndeadError::AlreadyAnswered,
+    )]
+    pub battle_room: Account<'info, BattleRoom>,
+}
+
+impl<'info> RevealPlayerAnswer<'info> {
+    pub fn reveal_player_answer(
+        &mut self,
+        room_id: [u8; 32],
+        answer: u8,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        let battle_room = &mut self.battle_room;
+        let player = self.player.key();
+        let current_q = battle_room.current_question;
+        let idx = current_q as usize;
+
+        require!(battle_room.room_id == room_id, RustUndeadError::InvalidRoomId);
+        require!(current_q < battle_room.question_count, RustUndeadError::InvalidQuestionIndex);
+        require!(battle_room.is_valid_choice(current_q, answer), RustUndeadError::InvalidAnswerChoice);
+
+        // revealing early would let the opponent seal the same answer
+        require!(battle_room.both_players_sealed(), RustUndeadError::AnswersNotSealed);
+
+        let sealed_answer = if battle_room.player_a == player {
+            battle_room.player_a_sealed_answer
+        } else {
+            battle_room.player_b_sealed_answer
+        };
+        let revealed = compute_sealed_answer(&room_id, current_q, &player, answer, &salt);
+        require!(
+            sealed_answer == Some(revealed),
+            RustUndeadError::SealedAnswerMismatch
+        );
+
+        // both salts were locked in by the seals before either was revealed,
+        // so neither player can steer the damage roll on their own
+        battle_room.damage_entropy = hashv(&[&battle_room.damage_entropy, &salt]).to_bytes();
+
+        if battle_room.player_a == player {
+            battle_room.player_a_answers[idx] = Some(answer);
+            msg!("🎯 Player A revealed answer for question {}", idx + 1);
+        } else {
+            battle_room.player_b_answers[idx] = Some(answer);
+            msg!("🎯 Player B revealed answer for question {}", idx + 1);
+        }
+
+        battle_room.last_activity_at = Clock::get()?.unix_timestamp;
+
+        if battle_room.both_players_answered(current_q) {
+            msg!("🎭 Both players revealed! Waiting for the answer key reveal for question {}", idx + 1);
+        } else {
+            msg!("⏳ Waiting for opponent to reveal question {}", idx + 1);
+        }
+
+        Ok(())
+    }
+}
@@ -1,13 +1,16 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::events::*;
 use crate::constants::*;
 use crate::error::RustUndeadError;
+use crate::helpers::{apply_level_ups, calculate_battle_cooldown};
 
 use ephemeral_rollups_sdk::anchor::commit;
 use ephemeral_rollups_sdk::ephem::commit_accounts;
 
 //undelegate all we delegated before 
 #[commit]
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(room_id: [u8; 32])]
 pub struct EndBattleRoom<'info> {
@@ -21,6 +24,7 @@ pub struct EndBattleRoom<'info> {
         constraint = battle_room.room_id == room_id @ RustUndeadError::InvalidRoomId,
         constraint = battle_room.state == BattleState::Completed @ RustUndeadError::InvalidBattleState,
         constraint = battle_room.winner.is_some() @ RustUndeadError::CannotUndelegate,
+        constraint = battle_room.settlement == SettlementStatus::Unsettled @ RustUndeadError::BattleAlreadySettled,
     )]
     pub battle_room: Account<'info, BattleRoom>,
 
@@ -35,24 +39,34 @@ pub struct EndBattleRoom<'info> {
         constraint = warrior_b.key() == battle_room.warrior_b.unwrap() @ RustUndeadError::InvalidWarrior,
     )]
     pub warrior_b: Account<'info, UndeadWarrior>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
 }
 
 
 
 impl<'info> EndBattleRoom<'info> {
     pub fn end_battle_room(
-        &mut self,
+        ctx: Context<Self>,
         room_id: [u8; 32],
     ) -> Result<()> {
-        let battle_room = &mut self.battle_room;
-        let warrior_a = &mut self.warrior_a;
-        let warrior_b = &mut self.warrior_b;
+        let battle_room = &mut ctx.accounts.battle_room;
+        let warrior_a = &mut ctx.accounts.warrior_a;
+        let warrior_b = &mut ctx.accounts.warrior_b;
 
         // Validate battle is complete and has winner
         require!(
             battle_room.state == BattleState::Completed,
             RustUndeadError::InvalidBattleState
         );
+        require!(
+            battle_room.settlement == SettlementStatus::Unsettled,
+            RustUndeadError::BattleAlreadySettled
+        );
 
         // Determine winner and loser
         let winner_player = battle_room.winner.unwrap();
@@ -98,6 +112,32 @@ impl<'info> EndBattleRoom<'info> {
         winner_warrior.experience_points = winner_warrior.experience_points.saturating_add(winner_xp);
         loser_warrior.experience_points = loser_warrior.experience_points.saturating_add(loser_xp);
 
+
+        // Level up on crossed XP thresholds
+        let max_level = ctx.accounts.config.max_level;
+        for warrior in [&mut *winner_warrior, &mut *loser_warrior] {
+            let levels_gained = apply_level_ups(warrior, max_level);
+            if levels_gained > 0 {
+                msg!("⬆️ {} reached level {}! HP: {}, ATK: {}, DEF: {}, KNOW: {}", 
+                    warrior.name,
+                    warrior.level,
+                    warrior.max_hp,
+                    warrior.base_attack,
+                    warrior.base_defense,
+                    warrior.base_knowledge
+                );
+                emit_cpi!(WarriorLeveledUp {
+                    warrior: warrior.key(),
+                    owner: warrior.owner,
+                    level: warrior.level,
+                    levels_gained,
+                    max_hp: warrior.max_hp,
+                    attack: warrior.base_attack,
+                    defense: warrior.base_defense,
+                    knowledge: warrior.base_knowledge,
+                });
+            }
+        }
        
         msg!("💎 Experience Awarded:");
         msg!("   {} gained {} XP (Base: {} + Score: {})", 
@@ -116,6 +156,34 @@ impl<'info> EndBattleRoom<'info> {
         );
         msg!("   XP Distribution - Winner: {}, Loser: {}", winner_xp, loser_xp);
 
+        // Start post-battle cooldowns
+        let current_time = Clock::get()?.unix_timestamp;
+        let winner_cooldown = calculate_battle_cooldown(&ctx.accounts.config, winner_warrior, true);
+        let loser_cooldown = calculate_battle_cooldown(&ctx.accounts.config, loser_warrior, false);
+
+        winner_warrior.last_battle_at = current_time;
+        winner_warrior.cooldown_expires_at = current_time.saturating_add(winner_cooldown);
+        loser_warrior.last_battle_at = current_time;
+        loser_warrior.cooldown_expires_at = current_time.saturating_add(loser_cooldown);
+
+        msg!("⏱️ Cooldowns - {}: {}s, {}: {}s", 
+            winner_warrior.name, winner_cooldown,
+            loser_warrior.name, loser_cooldown
+        );
+
+        // Rewards can only be applied once per room
+        battle_room.settlement = SettlementStatus::RewardsApplied;
+
+        emit_cpi!(BattleRewardsApplied {
+            room_id,
+            winner_warrior: winner_warrior.key(),
+            loser_warrior: loser_warrior.key(),
+            winner_xp,
+            loser_xp,
+            winner_cooldown_expires_at: winner_warrior.cooldown_expires_at,
+            loser_cooldown_expires_at: loser_warrior.cooldown_expires_at,
+        });
+
         
         msg!("🏁 Battle settlement complete! All delegated accounts will be undelegated from rollup");
         msg!("✅ Warriors and battle room will be returned to mainnet with updated stats");
@@ -123,14 +191,14 @@ impl<'info> EndBattleRoom<'info> {
 
         
         commit_accounts(
-            &self.authority,
+            &ctx.accounts.authority,
             vec![
-                &self.battle_room.to_account_info(),  
-                &self.warrior_a.to_account_info(),    
-                &self.warrior_b.to_account_info(),   
+                &ctx.accounts.battle_room.to_account_info(),  
+                &ctx.accounts.warrior_a.to_account_info(),    
+                &ctx.accounts.warrior_b.to_account_info(),   
             ],
-            &self.magic_context,
-            &self.magic_program,
+            &ctx.accounts.magic_context,
+            &ctx.accounts.magic_program,
         )?;
 
         Ok(())
@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::events::*;
 use crate::constants::*;
 use crate::error::RustUndeadError;
 use ephemeral_rollups_sdk::anchor::commit;
 use ephemeral_rollups_sdk::ephem::commit_accounts;
 
 #[commit]
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(room_id: [u8; 32])]
 pub struct StartBattle<'info> {
@@ -33,16 +35,23 @@ pub struct StartBattle<'info> {
         constraint = warrior_b.current_hp > 0 @ RustUndeadError::WarriorDefeated,
     )]
     pub warrior_b: Account<'info, UndeadWarrior>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = !config.is_paused @ RustUndeadError::GamePaused,
+    )]
+    pub config: Account<'info, Config>,
 }
 
 impl<'info> StartBattle<'info> {
     pub fn start_battle(
-        &mut self,
+        ctx: Context<Self>,
         room_id: [u8; 32],
     ) -> Result<()> {
-        let battle_room = &mut self.battle_room;
-        let warrior_a = &mut self.warrior_a;
-        let warrior_b = &mut self.warrior_b;
+        let battle_room = &mut ctx.accounts.battle_room;
+        let warrior_a = &mut ctx.accounts.warrior_a;
+        let warrior_b = &mut ctx.accounts.warrior_b;
         
         // Validate room ID
         require!(
@@ -59,11 +68,11 @@ impl<'info> StartBattle<'info> {
         // Ensure warriors are at full HP
         require!(
             warrior_a.current_hp == warrior_a.max_hp,
-            RustUndeadError::WarriorOnCooldown
+            RustUndeadError::WarriorNeedsHealing
         );
         require!(
             warrior_b.current_hp == warrior_b.max_hp,
-            RustUndeadError::WarriorOnCooldown
+            RustUndeadError::WarriorNeedsHealing
         );
 
         msg!("Starting battle in room: {:?}", room_id);   
@@ -78,6 +87,9 @@ impl<'info> StartBattle<'info> {
         battle_room.player_b_correct = 0;
         battle_room.battle_duration = 0;
         battle_room.battle_start_time = current_time;
+        battle_room.last_activity_at = current_time;
+        battle_room.answer_timeout = ctx.accounts.config.answer_timeout;
+        battle_room.question_deadline = current_time.saturating_add(battle_room.answer_timeout);
 
         // Log battle start
         msg!("🔥 BATTLE BEGINS!");
@@ -90,7 +102,7 @@ impl<'info> StartBattle<'info> {
         );
         msg!(
             "📚 Questions ready: {} total questions from {} concepts",
-            battle_room.selected_questions.len(),
+            battle_room.question_count,
             battle_room.selected_concepts.len()
         );
         msg!(
@@ -100,17 +112,24 @@ impl<'info> StartBattle<'info> {
 
         // Commit changes to the rollup
         commit_accounts(
-            &self.authority, 
+            &ctx.accounts.authority, 
             vec![
-                &self.battle_room.to_account_info(),
-                &self.warrior_a.to_account_info(),
-                &self.warrior_b.to_account_info(),
+                &ctx.accounts.battle_room.to_account_info(),
+                &ctx.accounts.warrior_a.to_account_info(),
+                &ctx.accounts.warrior_b.to_account_info(),
             ],
-            &self.magic_context,
-            &self.magic_program,
+            &ctx.accounts.magic_context,
+            &ctx.accounts.magic_program,
         )?;
         
         msg!("✅ Battle state committed to rollup successfully!");
+
+        emit_cpi!(BattleStarted {
+            room_id,
+            warrior_a_hp: ctx.accounts.warrior_a.current_hp,
+            warrior_b_hp: ctx.accounts.warrior_b.current_hp,
+            question_deadline: ctx.accounts.battle_room.question_deadline,
+        });
         
         Ok(())
     }
@@ -21,6 +21,8 @@ pub struct UndelegateBattleRoom<'info> {
         constraint = battle_room.room_id == room_id @ RustUndeadError::InvalidRoomId,
         constraint = battle_room.state == BattleState::Completed @ RustUndeadError::InvalidBattleState,
         constraint = battle_room.winner.is_some() @ RustUndeadError::CannotUndelegate,
+        // rewards must be applied on the rollup first, settle_battle_room only runs there
+        constraint = battle_room.settlement == SettlementStatus::RewardsApplied @ RustUndeadError::CannotUndelegate,
     )]
     pub battle_room: Account<'info, BattleRoom>,
 
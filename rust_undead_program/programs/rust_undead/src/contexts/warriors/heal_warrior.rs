@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::constants::*;
+use crate::helpers::*;
+use crate::error::RustUndeadError;
+
+// restore a warrior to full HP, free once its cooldown has run out,
+// otherwise paid to the treasury. Delegated warriors are owned by the
+// delegation program, so a warrior mid-battle can't be healed here
+#[derive(Accounts)]
+pub struct HealWarrior<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = is_warrior_holder(&warrior, &owner.key(), &warrior_nft_account) @ RustUndeadError::NotWarriorOwner,
+    )]
+    pub warrior: Account<'info, UndeadWarrior>,
+
+    // the warrior's NFT token account, required once the warrior is minted
+    pub warrior_nft_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = config.treasury @ RustUndeadError::NotAuthorized,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> HealWarrior<'info> {
+    pub fn heal_warrior(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        sync_warrior_owner(&mut self.warrior, self.owner.key());
+
+        let restored = apply_passive_regen(&mut self.warrior, &self.config, now);
+        if restored > 0 {
+            msg!("💚 {} regenerated {} HP while resting", self.warrior.name, restored);
+        }
+
+        require!(
+            self.warrior.current_hp < self.warrior.max_hp,
+            RustUndeadError::WarriorAlreadyHealed
+        );
+
+        if is_warrior_ready(&self.warrior) {
+            msg!("⚕️ Cooldown complete - healing {} for free", self.warrior.name);
+        } else {
+            let cost = self.config.heal_cost_lamports;
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.owner.to_account_info(),
+                        to: self.treasury.to_account_info(),
+                    },
+                ),
+                cost,
+            )?;
+            msg!("⚕️ Paid {} lamports to heal {} during cooldown", cost, self.warrior.name);
+        }
+
+        let old_hp = self.warrior.current_hp;
+        self.warrior.current_hp = self.warrior.max_hp;
+        self.warrior.last_healed_at = now;
+
+        msg!("💚 {} healed! HP: {} → {}", self.warrior.name, old_hp, self.warrior.current_hp);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::helpers::*;
+use crate::error::RustUndeadError;
+
+// opt-in battle log for a warrior, records are added when its rooms are closed
+#[derive(Accounts)]
+pub struct InitWarriorHistory<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = is_warrior_holder(&warrior, &owner.key(), &warrior_nft_account) @ RustUndeadError::NotWarriorOwner,
+    )]
+    pub warrior: Account<'info, UndeadWarrior>,
+
+    // the warrior's NFT token account, required once the warrior is minted
+    pub warrior_nft_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + WarriorHistory::INIT_SPACE,
+        seeds = [BATTLE_HISTORY, warrior.key().as_ref()],
+        bump,
+    )]
+    pub history: Account<'info, WarriorHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitWarriorHistory<'info> {
+    pub fn init_warrior_history(&mut self, bumps: &InitWarriorHistoryBumps) -> Result<()> {
+        self.history.set_inner(WarriorHistory {
+            warrior: self.warrior.key(),
+            records: Vec::new(),
+            next_slot: 0,
+            total_recorded: 0,
+            bump: bumps.history,
+        });
+
+        msg!("📜 Battle history started for {}", self.warrior.name);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, Token};
+use crate::state::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::helpers::*;
+use crate::error::RustUndeadError;
+
+// mint a warrior's NFT once its stats and image are final. Supply is fixed at
+// one and from then on whoever holds the token controls the warrior
+#[derive(Accounts)]
+pub struct MintWarriorNft<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = is_warrior_holder(&warrior, &owner.key(), &None) @ RustUndeadError::NotWarriorOwner,
+        constraint = !warrior.stats_pending @ RustUndeadError::WarriorStatsPending,
+        constraint = warrior.nft_mint.is_none() @ RustUndeadError::NftAlreadyMinted,
+    )]
+    pub warrior: Account<'info, UndeadWarrior>,
+
+    /// CHECK: created here as the warrior's NFT mint
+    #[account(
+        mut,
+        seeds = [WARRIOR_NFT_MINT, warrior.key().as_ref()],
+        bump,
+    )]
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: signs as mint and metadata update authority, holds no data
+    #[account(
+        seeds = [NFT_AUTHORITY],
+        bump,
+    )]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    /// CHECK: owner's associated token account, derivation checked by the associated token program
+    #[account(mut)]
+    pub owner_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: metadata PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [METADATA, TOKEN_METADATA_PROGRAM_ID.as_ref(), nft_mint.key().as_ref()],
+        seeds::program = TOKEN_METADATA_PROGRAM_ID,
+        bump,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = !config.is_paused @ RustUndeadError::GamePaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: address checked
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    /// CHECK: address checked
+    #[account(address = ASSOCIATED_TOKEN_PROGRAM_ID)]
+    pub associated_token_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MintWarriorNft<'info> {
+    pub fn mint_warrior_nft(&mut self, bumps: &MintWarriorNftBumps) -> Result<()> {
+        let warrior_key = self.warrior.key();
+        let mint = self.nft_mint.to_account_info();
+        let authority = self.nft_authority.to_account_info();
+        let authority_seeds: &[&[u8]] = &[NFT_AUTHORITY, &[bumps.nft_authority]];
+
+        open_warrior_nft_mint(
+            &self.owner,
+            &mint,
+            &authority,
+            &self.token_program,
+            &self.system_program,
+            &[WARRIOR_NFT_MINT, warrior_key.as_ref(), &[bumps.nft_mint]],
+        )?;
+
+        create_associated_token_account(
+            &self.owner,
+            &self.owner_token_account.to_account_info(),
+            &self.owner.to_account_info(),
+            &mint,
+            &self.token_program,
+            &self.associated_token_program.to_account_info(),
+            &self.system_program,
+        )?;
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::MintTo {
+                    mint: mint.clone(),
+                    to: self.owner_token_account.to_account_info(),
+                    authority: authority.clone(),
+                },
+                &[authority_seeds],
+            ),
+            1,
+        )?;
+
+        create_warrior_nft_metadata(
+            &self.metadata.to_account_info(),
+            &mint,
+            &authority,
+            &self.owner,
+            &self.token_metadata_program.to_account_info(),
+            &self.system_program,
+            &self.warrior.name,
+            &self.warrior.image_uri,
+            authority_seeds,
+        )?;
+
+        // No more can ever be minted
+        token::set_authority(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::SetAuthority {
+                    current_authority: authority,
+                    account_or_mint: mint,
+                },
+                &[authority_seeds],
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+
+        self.warrior.nft_mint = Some(self.nft_mint.key());
+
+        msg!("🖼️ {} minted as NFT {}", self.warrior.name, self.nft_mint.key());
+        msg!("   Whoever holds the token now controls the warrior");
+
+        emit!(WarriorNftMinted {
+            warrior: warrior_key,
+            mint: self.nft_mint.key(),
+            owner: self.owner.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+pub mod heal_warrior;
+pub use heal_warrior::*;
+
+pub mod init_history;
+pub use init_history::*;
+
+pub mod mint_nft;
+pub use mint_nft::*;
+
+pub mod transfer_warrior;
+pub use transfer_warrior::*;
+
+pub mod retire_warrior;
+pub use retire_warrior::*;
+
+pub mod rename_warrior;
+pub use rename_warrior::*;
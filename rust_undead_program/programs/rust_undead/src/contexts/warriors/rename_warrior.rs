@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::helpers::*;
+use crate::error::RustUndeadError;
+
+// give a warrior a new name for a fee paid to the treasury. The old name is
+// released and the new one claimed, at most once per Config.rename_cooldown.
+// A change of case only keeps the existing claim, so no new registry is passed
+#[derive(Accounts)]
+#[instruction(new_name: String)]
+pub struct RenameWarrior<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = is_warrior_holder(&warrior, &owner.key(), &warrior_nft_account) @ RustUndeadError::NotWarriorOwner,
+    )]
+    pub warrior: Account<'info, UndeadWarrior>,
+
+    // the warrior's NFT token account, required once the warrior is minted
+    pub warrior_nft_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // closed below when the name moves to a new registry
+    #[account(
+        mut,
+        seeds = [NAME_REGISTRY, &name_registry_seed(&warrior.name)],
+        bump = name_registry.bump,
+        constraint = name_registry.warrior == warrior.key() @ RustUndeadError::InvalidWarrior,
+    )]
+    pub name_registry: Account<'info, NameRegistry>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + NameRegistry::INIT_SPACE,
+        seeds = [NAME_REGISTRY, &name_registry_seed(&new_name)],
+        bump,
+    )]
+    pub new_name_registry: Option<Account<'info, NameRegistry>>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = !config.is_paused @ RustUndeadError::GamePaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        address = config.treasury @ RustUndeadError::NotAuthorized,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RenameWarrior<'info> {
+    pub fn rename_warrior(&mut self, new_name: String, bumps: &RenameWarriorBumps) -> Result<()> {
+        validate_warrior_name(&new_name)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let ready_at = self.warrior.last_renamed_at.saturating_add(self.config.rename_cooldown);
+        require!(now >= ready_at, RustUndeadError::RenameOnCooldown);
+
+        let fee = self.config.rename_fee_lamports;
+        if fee > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.owner.to_account_info(),
+                        to: self.treasury.to_account_info(),
+                    },
+                ),
+                fee,
+            )?;
+        }
+
+        let warrior_key = self.warrior.key();
+        if name_registry_seed(&new_name) == name_registry_seed(&self.warrior.name) {
+            // Same claim, only the casing changes
+            self.name_registry.name = new_name.clone();
+        } else {
+            let (Some(new_name_registry), Some(bump)) =
+                (self.new_name_registry.as_mut(), bumps.new_name_registry)
+            else {
+                return Err(ErrorCode::AccountNotEnoughKeys.into());
+            };
+            new_name_registry.set_inner(NameRegistry {
+                name: new_name.clone(),
+                warrior: warrior_key,
+                claimed_at: now,
+                bump,
+            });
+            self.name_registry.close(self.owner.to_account_info())?;
+        }
+
+        let old_name = std::mem::replace(&mut self.warrior.name, new_name);
+        self.warrior.last_renamed_at = now;
+
+        msg!("✏️ {} is now known as {}", old_name, self.warrior.name);
+        msg!("   Paid {} lamports, next rename after {}", fee, now.saturating_add(self.config.rename_cooldown));
+
+        emit!(WarriorRenamed {
+            warrior: warrior_key,
+            old_name,
+            new_name: self.warrior.name.clone(),
+            fee,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token};
+use crate::state::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::helpers::*;
+use crate::error::RustUndeadError;
+
+// retire a warrior for good: its account and name claim are closed back to the
+// owner and a memorial entry is kept in the owner's graveyard. Minted warriors
+// have their NFT burned. Profile totals are lifetime stats and stay as they are
+#[derive(Accounts)]
+pub struct RetireWarrior<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        constraint = is_warrior_holder(&warrior, &owner.key(), &warrior_nft_account) @ RustUndeadError::NotWarriorOwner,
+        constraint = warrior.current_room.is_none() @ RustUndeadError::WarriorInRoom,
+    )]
+    pub warrior: Account<'info, UndeadWarrior>,
+
+    // the warrior's NFT token account and mint, required once the warrior is minted
+    #[account(mut)]
+    pub warrior_nft_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        constraint = warrior.nft_mint == Some(nft_mint.key()) @ RustUndeadError::InvalidWarrior,
+    )]
+    pub nft_mint: Option<Box<Account<'info, Mint>>>,
+
+    // the warrior's name claim, released for anyone to take
+    #[account(
+        mut,
+        close = owner,
+        seeds = [NAME_REGISTRY, &name_registry_seed(&warrior.name)],
+        bump = name_registry.bump,
+        constraint = name_registry.warrior == warrior.key() @ RustUndeadError::InvalidWarrior,
+    )]
+    pub name_registry: Account<'info, NameRegistry>,
+
+    // the owner's lookup when they were given the warrior
+    #[account(
+        mut,
+        close = owner,
+        seeds = [WARRIOR_LOOKUP, owner.key().as_ref(), warrior.key().as_ref()],
+        bump = lookup.bump,
+        constraint = lookup.warrior == warrior.key() @ RustUndeadError::InvalidWarriorLookup,
+    )]
+    pub lookup: Option<Account<'info, WarriorLookup>>,
+
+    // needed while the warrior is queued, see leave_match_queue
+    #[account(
+        mut,
+        seeds = [MATCH_QUEUE],
+        bump = match_queue.bump,
+    )]
+    pub match_queue: Option<Box<Account<'info, MatchQueue>>>,
+
+    // battle log, closed along with the warrior when it exists
+    #[account(
+        mut,
+        close = owner,
+        seeds = [BATTLE_HISTORY, warrior.key().as_ref()],
+        bump = history.bump,
+    )]
+    pub history: Option<Account<'info, WarriorHistory>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + Graveyard::INIT_SPACE,
+        seeds = [GRAVEYARD, owner.key().as_ref()],
+        bump,
+    )]
+    pub graveyard: Box<Account<'info, Graveyard>>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = !config.is_paused @ RustUndeadError::GamePaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RetireWarrior<'info> {
+    pub fn retire_warrior(&mut self, bumps: &RetireWarriorBumps) -> Result<()> {
+        leave_match_queue(&mut self.warrior, &mut self.match_queue)?;
+        let owner = self.owner.key();
+        let warrior = &self.warrior;
+        let now = Clock::get()?.unix_timestamp;
+
+        if warrior.nft_mint.is_some() {
+            let (Some(tokens), Some(mint), Some(token_program)) =
+                (&self.warrior_nft_account, &self.nft_mint, &self.token_program)
+            else {
+                return Err(RustUndeadError::InvalidWarrior.into());
+            };
+
+            token::burn(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    token::Burn {
+                        mint: mint.to_account_info(),
+                        from: tokens.to_account_info(),
+                        authority: self.owner.to_account_info(),
+                    },
+                ),
+                1,
+            )?;
+            token::close_account(CpiContext::new(
+                token_program.to_account_info(),
+                token::CloseAccount {
+                    account: tokens.to_account_info(),
+                    destination: self.owner.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                },
+            ))?;
+            msg!("🔥 Burned NFT {}", mint.key());
+        } else {
+            // Players who were given the warrior hold a lookup for it. A minted
+            // warrior follows its token, so its holder may have none
+            require!(
+                self.lookup.is_some() == (owner != warrior.creator),
+                RustUndeadError::InvalidWarriorLookup
+            );
+        }
+
+        let graveyard = &mut self.graveyard;
+        if graveyard.owner == Pubkey::default() {
+            graveyard.owner = owner;
+            graveyard.bump = bumps.graveyard;
+        }
+        graveyard.bury(FallenWarrior {
+            warrior: warrior.key(),
+            name: warrior.name.clone(),
+            warrior_class: warrior.warrior_class,
+            level: warrior.level,
+            battles_won: warrior.battles_won,
+            battles_lost: warrior.battles_lost,
+            experience_points: warrior.experience_points,
+            rating: warrior.rating,
+            created_at: warrior.created_at,
+            retired_at: now,
+        });
+
+        msg!("🪦 {} has fallen: level {}, {}W/{}L",
+            warrior.name, warrior.level, warrior.battles_won, warrior.battles_lost
+        );
+        msg!("   {} warriors remembered in the graveyard", graveyard.total_fallen);
+        msg!("   The name {} is free to claim", warrior.name);
+
+        emit!(WarriorRetired {
+            warrior: warrior.key(),
+            owner,
+            name: warrior.name.clone(),
+            level: warrior.level,
+            battles_won: warrior.battles_won,
+            battles_lost: warrior.battles_lost,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::*;
+use crate::constants::*;
+use crate::helpers::*;
+use crate::error::RustUndeadError;
+
+// hand a warrior to another player. Its address and name claim stay the same,
+// every owner other than the creator gets a lookup record to find it by.
+// Delegated warriors belong to the delegation program and fail to load here
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+pub struct TransferWarrior<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = warrior.owner == owner.key() @ RustUndeadError::NotWarriorOwner,
+        constraint = warrior.nft_mint.is_none() @ RustUndeadError::NftWarriorTransfer,
+        constraint = warrior.current_room.is_none() @ RustUndeadError::WarriorInRoom,
+        constraint = new_owner != owner.key() @ RustUndeadError::InvalidTransferRecipient,
+    )]
+    pub warrior: Account<'info, UndeadWarrior>,
+
+    // the sender's lookup, held whenever they are not the creator
+    #[account(
+        mut,
+        close = owner,
+        seeds = [WARRIOR_LOOKUP, owner.key().as_ref(), warrior.key().as_ref()],
+        bump = previous_lookup.bump,
+        constraint = previous_lookup.warrior == warrior.key() @ RustUndeadError::InvalidWarriorLookup,
+    )]
+    pub previous_lookup: Option<Account<'info, WarriorLookup>>,
+
+    // the recipient's lookup, needed unless the warrior returns to its creator
+    #[account(
+        init,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + WarriorLookup::INIT_SPACE,
+        seeds = [WARRIOR_LOOKUP, new_owner.as_ref(), warrior.key().as_ref()],
+        bump,
+    )]
+    pub new_lookup: Option<Account<'info, WarriorLookup>>,
+
+    // needed while the warrior is queued, see leave_match_queue
+    #[account(
+        mut,
+        seeds = [MATCH_QUEUE],
+        bump = match_queue.bump,
+    )]
+    pub match_queue: Option<Box<Account<'info, MatchQueue>>>,
+
+    #[account(
+        mut,
+        seeds = [USER_PROFILE, owner.key().as_ref()],
+        bump = owner_profile.bump,
+    )]
+    pub owner_profile: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        mut,
+        seeds = [USER_ACHIEVEMENT, owner.key().as_ref()],
+        bump = owner_achievements.bump,
+    )]
+    pub owner_achievements: Box<Account<'info, UserAchievements>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + UserProfile::INIT_SPACE,
+        seeds = [USER_PROFILE, new_owner.as_ref()],
+        bump,
+    )]
+    pub recipient_profile: Box<Account<'info, UserProfile>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = ANCHOR_DISCRIMINATOR + UserAchievements::INIT_SPACE,
+        seeds = [USER_ACHIEVEMENT, new_owner.as_ref()],
+        bump,
+    )]
+    pub recipient_achievements: Box<Account<'info, UserAchievements>>,
+
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = !config.is_paused @ RustUndeadError::GamePaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TransferWarrior<'info> {
+    pub fn transfer_warrior(
+        &mut self,
+        new_owner: Pubkey,
+        bumps: &TransferWarriorBumps,
+    ) -> Result<()> {
+        require!(new_owner != Pubkey::default(), RustUndeadError::InvalidTransferRecipient);
+        leave_match_queue(&mut self.warrior, &mut self.match_queue)?;
+
+        let from = self.owner.key();
+        let warrior_key = self.warrior.key();
+        let creator = self.warrior.creator;
+
+        // Creators reach the warrior through its PDA, everyone else through a lookup
+        require!(
+            self.previous_lookup.is_some() == (from != creator),
+            RustUndeadError::InvalidWarriorLookup
+        );
+        require!(
+            self.new_lookup.is_some() == (new_owner != creator),
+            RustUndeadError::InvalidWarriorLookup
+        );
+
+        if let Some(lookup) = &mut self.new_lookup {
+            lookup.set_inner(WarriorLookup {
+                owner: new_owner,
+                warrior: warrior_key,
+                creator,
+                bump: bumps.new_lookup.unwrap(),
+            });
+        }
+
+        self.warrior.owner = new_owner;
+
+        // Sender gives up the warrior
+        let owner_profile = &mut self.owner_profile;
+        owner_profile.warriors_created = owner_profile.warriors_created.saturating_sub(1);
+        // a lower level is stored but not announced
+        let _ = update_achievement(
+            from,
+            AchievementCategory::Warrior,
+            &mut self.owner_achievements.warrior_achivement,
+            calculate_warrior_achievement(owner_profile.warriors_created),
+        );
+
+        // Recipient may be new to the game
+        let now = Clock::get()?.unix_timestamp;
+        let recipient_profile = &mut self.recipient_profile;
+        if recipient_profile.owner == Pubkey::default() {
+            recipient_profile.initialize(new_owner, now, self.config.current_season, bumps.recipient_profile);
+        }
+        recipient_profile.warriors_created = recipient_profile.warriors_created.saturating_add(1);
+
+        let recipient_achievements = &mut self.recipient_achievements;
+        if recipient_achievements.owner == Pubkey::default() {
+            recipient_achievements.initialize(new_owner, now, bumps.recipient_achievements);
+        }
+        if let Some(unlocked) = update_achievement(
+            new_owner,
+            AchievementCategory::Warrior,
+            &mut recipient_achievements.warrior_achivement,
+            calculate_warrior_achievement(recipient_profile.warriors_created),
+        ) {
+            emit!(unlocked);
+        }
+
+        msg!("🤝 {} transferred: {} -> {}", self.warrior.name, from, new_owner);
+        if new_owner == creator {
+            msg!("   Returned to its creator");
+        } else {
+            msg!("   Lookup recorded for the new owner");
+        }
+
+        emit!(WarriorTransferred {
+            warrior: warrior_key,
+            from,
+            to: new_owner,
+        });
+
+        Ok(())
+    }
+}
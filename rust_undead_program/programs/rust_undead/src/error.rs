@@ -66,7 +66,7 @@ pub enum RustUndeadError {
 	CannotAttackSelf,
 	#[msg("Invalid Question Index")]
 	 InvalidQuestionIndex,
-	#[msg("Only the room creator can cancel the battle")]
+	#[msg("Only the room creator, or the opponent once the room is idle, can cancel the battle")]
   OnlyCreatorCanCancel,
 	#[msg("Battle has already started and cannot be cancelled")]
   BattleAlreadyStarted,
@@ -84,4 +84,88 @@ pub enum RustUndeadError {
     InvalidClassRarity,
   #[msg("Image generation failed")]
     ImageGenerationFailed,
+  #[msg("Revealed answer does not match the committed answer key")]
+    InvalidAnswerReveal,
+  #[msg("Revealed answer does not match the question bank")]
+    AnswerNotInBank,
+  #[msg("Answer for this question has already been revealed")]
+    AnswerAlreadyRevealed,
+  #[msg("Both players must answer before the answer is revealed")]
+    AnswersNotSubmitted,
+  #[msg("Revealed answer does not match the sealed answer")]
+    SealedAnswerMismatch,
+  #[msg("Both players must seal their answers before revealing")]
+    AnswersNotSealed,
+  #[msg("Question is not part of the question bank")]
+    InvalidQuestionProof,
+  #[msg("Question has already been verified")]
+    QuestionAlreadyVerified,
+  #[msg("Warrior stats are still being generated")]
+    WarriorStatsPending,
+  #[msg("Game is paused")]
+    GamePaused,
+  #[msg("Warrior must be at full HP, heal or wait for regeneration")]
+    WarriorNeedsHealing,
+  #[msg("Warrior is already at full HP")]
+    WarriorAlreadyHealed,
+  #[msg("Invalid config value")]
+    InvalidConfigValue,
+  #[msg("Question deadline has not passed yet")]
+    DeadlineNotReached,
+  #[msg("Battle room has not been idle long enough")]
+    RoomNotIdle,
+  #[msg("Only cancelled, no-contest or fully settled battle rooms can be closed")]
+    RoomNotClosable,
+  #[msg("Invalid wager amount")]
+    InvalidWagerAmount,
+  #[msg("Wager accounts are missing or do not match the room stake")]
+    InvalidWagerAccounts,
+  #[msg("Room has no escrowed wager")]
+    WagerNotEscrowed,
+  #[msg("Wager must be paid out or refunded first")]
+    WagerStillEscrowed,
+  #[msg("Match queue is full")]
+    MatchQueueFull,
+  #[msg("Warrior is already queued")]
+    AlreadyQueued,
+  #[msg("Warrior is not in the match queue")]
+    NotQueued,
+  #[msg("Queued warriors are not compatible")]
+    IncompatibleMatch,
+  #[msg("Room questions have already been assigned")]
+    QuestionsAlreadyAssigned,
+  #[msg("Season leaderboard is archived")]
+    SeasonArchived,
+  #[msg("Leaderboard is not fully allocated, call grow_leaderboard")]
+    LeaderboardNotAllocated,
+  #[msg("Leaderboard is already fully allocated")]
+    LeaderboardAllocated,
+  #[msg("Leaderboard data is inconsistent")]
+    InvalidLeaderboard,
+  #[msg("Question count must be 5, 10 or 15 with one topic, option count and commitment per question")]
+    InvalidQuestionCount,
+  #[msg("Each question needs between 2 and 6 answer options")]
+    InvalidAnswerOptions,
+  #[msg("Answer is not one of the question's options")]
+    InvalidAnswerChoice,
+  #[msg("Warrior already has an NFT")]
+    NftAlreadyMinted,
+  #[msg("Warrior is committed to a battle room")]
+    WarriorInRoom,
+  #[msg("NFT warriors change hands by transferring the token")]
+    NftWarriorTransfer,
+  #[msg("Invalid transfer recipient")]
+    InvalidTransferRecipient,
+  #[msg("Names cannot start or end with spaces or contain control characters")]
+    InvalidWarriorName,
+  #[msg("Warrior was renamed too recently")]
+    RenameOnCooldown,
+  #[msg("Warrior lookup accounts do not match the owners")]
+    InvalidWarriorLookup,
+  #[msg("Idle timeout must be longer than the answer timeout")]
+    IdleTimeoutTooShort,
+  #[msg("Every question needs a non-empty answer commitment")]
+    EmptyAnswerCommitment,
+  #[msg("Warrior is waiting in the match queue")]
+    WarriorQueued,
 }
@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+// Typed events for clients and indexers, one per state transition.
+// Most are emitted with emit!. Everything that runs on the rollup, from the
+// battle start through answers, reveals, timeouts and settlement, uses
+// emit_cpi! instead, so its events land in instruction data where log
+// truncation on the rollup or base layer can't drop them. Those handlers take
+// their Context directly, since emit_cpi! reads the event authority from it.
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CancelReason {
+    Creator,      // cancelled before delegation
+    Idle,         // force undelegated after the idle timeout
+    Emergency,    // admin ended it as no contest
+    OpponentLeft, // the joiner left a room idle before delegation
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompletionReason {
+    Elimination,
+    Decision,   // all questions answered, decided on HP then score
+    Timeout,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AchievementCategory {
+    Warrior,
+    Winner,
+    Battle,
+    Overall,
+}
+
+// === WARRIORS ===
+
+#[event]
+pub struct WarriorCreated {
+    pub warrior: Pubkey,
+    pub owner: Pubkey,
+    pub name: String,
+    pub class: WarriorClass,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct WarriorStatsGenerated {
+    pub warrior: Pubkey,
+    pub attack: u16,
+    pub defense: u16,
+    pub knowledge: u16,
+    pub image_rarity: ImageRarity,
+    pub image_index: u8,
+}
+
+#[event]
+pub struct WarriorLeveledUp {
+    pub warrior: Pubkey,
+    pub owner: Pubkey,
+    pub level: u16,
+    pub levels_gained: u16,
+    pub max_hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub knowledge: u16,
+}
+
+#[event]
+pub struct WarriorNftMinted {
+    pub warrior: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct WarriorTransferred {
+    pub warrior: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+}
+
+#[event]
+pub struct WarriorRenamed {
+    pub warrior: Pubkey,
+    pub old_name: String,
+    pub new_name: String,
+    pub fee: u64,
+}
+
+#[event]
+pub struct WarriorRetired {
+    pub warrior: Pubkey,
+    pub owner: Pubkey,
+    pub name: String,
+    pub level: u16,
+    pub battles_won: u32,
+    pub battles_lost: u32,
+}
+
+#[event]
+pub struct AchievementUnlocked {
+    pub player: Pubkey,
+    pub category: AchievementCategory,
+    pub level: AchievementLevel,
+}
+
+// === ROOM LIFECYCLE ===
+
+#[event]
+pub struct RoomCreated {
+    pub room_id: [u8; 32],
+    pub player_a: Pubkey,
+    pub warrior_a: Pubkey,
+    // set when the room was created by matchmaking
+    pub player_b: Option<Pubkey>,
+    pub warrior_b: Option<Pubkey>,
+    // 0 for matched rooms until questions are assigned
+    pub question_count: u8,
+    pub wager_amount: u64,
+    pub wager_mint: Option<Pubkey>,
+}
+
+#[event]
+pub struct RoomJoined {
+    pub room_id: [u8; 32],
+    pub player_b: Pubkey,
+    pub warrior_b: Pubkey,
+}
+
+#[event]
+pub struct PlayerReady {
+    pub room_id: [u8; 32],
+    pub player: Pubkey,
+    pub both_ready: bool,
+}
+
+#[event]
+pub struct RoomCancelled {
+    pub room_id: [u8; 32],
+    pub cancelled_by: Pubkey,
+    pub reason: CancelReason,
+}
+
+#[event]
+pub struct BattleDelegated {
+    pub room_id: [u8; 32],
+    pub warrior_a: Pubkey,
+    pub warrior_b: Pubkey,
+}
+
+// === BATTLE ===
+
+#[event]
+pub struct BattleStarted {
+    pub room_id: [u8; 32],
+    pub warrior_a_hp: u16,
+    pub warrior_b_hp: u16,
+    pub question_deadline: i64,
+}
+
+#[event]
+pub struct AnswerSealed {
+    pub room_id: [u8; 32],
+    pub player: Pubkey,
+    pub question_index: u8,
+    pub both_sealed: bool,
+}
+
+#[event]
+pub struct QuestionResolved {
+    pub room_id: [u8; 32],
+    pub question_index: u8,
+    pub correct_answer: u8,
+    pub player_a_correct: bool,
+    pub player_b_correct: bool,
+    pub damage_to_a: u16,
+    pub damage_to_b: u16,
+    pub warrior_a_hp: u16,
+    pub warrior_b_hp: u16,
+}
+
+#[event]
+pub struct BattleCompleted {
+    pub room_id: [u8; 32],
+    pub winner: Option<Pubkey>,
+    pub reason: CompletionReason,
+    pub player_a_score: u8,
+    pub player_b_score: u8,
+    pub warrior_a_hp: u16,
+    pub warrior_b_hp: u16,
+    pub duration: u32,
+}
+
+// === SETTLEMENT ===
+
+// rollup side, XP and cooldowns
+#[event]
+pub struct BattleRewardsApplied {
+    pub room_id: [u8; 32],
+    pub winner_warrior: Pubkey,
+    pub loser_warrior: Pubkey,
+    pub winner_xp: u64,
+    pub loser_xp: u64,
+    pub winner_cooldown_expires_at: i64,
+    pub loser_cooldown_expires_at: i64,
+}
+
+// base layer side, profiles, ratings and leaderboards
+#[event]
+pub struct BattleSettled {
+    pub room_id: [u8; 32],
+    pub winner: Pubkey,
+    pub loser: Pubkey,
+    pub winner_points: u32,
+    pub loser_points: u32,
+    pub winner_rating: u32,
+    pub loser_rating: u32,
+    pub season: u32,
+}
+
+#[event]
+pub struct WagerSettled {
+    pub room_id: [u8; 32],
+    pub winner: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub payout: u64,
+    pub fee: u64,
+}
+
+#[event]
+pub struct WagerRefunded {
+    pub room_id: [u8; 32],
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub players_refunded: u8,
+}
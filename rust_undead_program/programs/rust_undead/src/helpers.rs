@@ -1,13 +1,501 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Token};
 use crate::state::*;
 use crate::constants::*;
+use crate::events::*;
+use crate::error::RustUndeadError;
 
 pub fn is_warrior_ready(warrior: &UndeadWarrior) -> bool {
     let current_time = Clock::get().unwrap().unix_timestamp;
     current_time >= warrior.cooldown_expires_at
 }
 
+// NFT warriors belong to whoever holds the token, the rest to their stored owner
+pub fn is_warrior_holder<'info>(
+    warrior: &UndeadWarrior,
+    player: &Pubkey,
+    nft_token_account: &Option<Box<Account<'info, TokenAccount>>>,
+) -> bool {
+    match warrior.nft_mint {
+        None => warrior.owner == *player,
+        Some(mint) => nft_token_account.as_ref().is_some_and(|tokens| {
+            tokens.mint == mint && tokens.owner == *player && tokens.amount == 1
+        }),
+    }
+}
+
+// Record the current NFT holder as owner, so profiles, queue entries and
+// rewards follow a traded warrior
+pub fn sync_warrior_owner(warrior: &mut UndeadWarrior, holder: Pubkey) {
+    if warrior.nft_mint.is_some() && warrior.owner != holder {
+        msg!("🔁 {} changed hands: {} -> {}", warrior.name, warrior.owner, holder);
+        warrior.owner = holder;
+    }
+}
+
+// Entry rules shared by rooms and the match queue, so they can't drift apart:
+// the player must hold the warrior, it must be off cooldown with its stats
+// rolled, and after passive regen it has to be back at full HP
+pub fn prepare_warrior_for_battle<'info>(
+    warrior: &mut UndeadWarrior,
+    player: Pubkey,
+    nft_token_account: &Option<Box<Account<'info, TokenAccount>>>,
+    config: &Config,
+    now: i64,
+) -> Result<()> {
+    require!(is_warrior_holder(warrior, &player, nft_token_account), RustUndeadError::NotWarriorOwner);
+    require!(!warrior.stats_pending, RustUndeadError::WarriorStatsPending);
+    require!(is_warrior_ready(warrior), RustUndeadError::WarriorOnCooldown);
+    sync_warrior_owner(warrior, player);
+
+    let restored = apply_passive_regen(warrior, config, now);
+    if restored > 0 {
+        msg!("💚 {} regenerated {} HP while resting", warrior.name, restored);
+    }
+    require!(warrior.current_hp == warrior.max_hp, RustUndeadError::WarriorNeedsHealing);
+
+    Ok(())
+}
+
+// A queued warrior can't enter a room, change hands or retire. Passing the
+// match queue takes it out first, and also clears a flag left behind by an
+// entry that expired from the queue
+pub fn leave_match_queue<'info>(
+    warrior: &mut Account<'info, UndeadWarrior>,
+    match_queue: &mut Option<Box<Account<'info, MatchQueue>>>,
+) -> Result<()> {
+    if !warrior.queued {
+        return Ok(());
+    }
+    let match_queue = match_queue.as_mut().ok_or(RustUndeadError::WarriorQueued)?;
+    if let Some(index) = match_queue.position(&warrior.key()) {
+        match_queue.entries.remove(index);
+        msg!("🚪 {} left the match queue", warrior.name);
+    }
+    warrior.queued = false;
+    Ok(())
+}
+
+// Names are shown as typed but claimed by their lowercase form, so "Bob" and "bob" clash
+pub fn validate_warrior_name(name: &str) -> Result<()> {
+    require!(!name.is_empty(), RustUndeadError::NameEmpty);
+    require!(name.len() <= 32, RustUndeadError::NameTooLong);
+    require!(
+        name.trim() == name && !name.chars().any(char::is_control),
+        RustUndeadError::InvalidWarriorName
+    );
+    Ok(())
+}
+
+// Registry seed for a name: the Unicode lowercase form, hashed so names with
+// multi-byte characters still fit a seed. Clients use sha256(name.toLowerCase())
+pub fn name_registry_seed(name: &str) -> [u8; 32] {
+    hash(name.to_lowercase().as_bytes()).to_bytes()
+}
+
+// Post-battle cooldown: winners and losers serve their own share of the base
+// cooldown, stretched by how much HP the warrior lost
+pub fn calculate_battle_cooldown(config: &Config, warrior: &UndeadWarrior, is_winner: bool) -> i64 {
+    let role_pct = if is_winner {
+        config.winner_cooldown_pct
+    } else {
+        config.loser_cooldown_pct
+    };
+    let role_cooldown = config.cooldown_time as u128 * role_pct as u128 / 100;
+
+    let max_hp = warrior.max_hp.max(1) as u128;
+    let missing_hp = max_hp.saturating_sub(warrior.current_hp as u128);
+    let hp_penalty = role_cooldown * missing_hp * config.hp_cooldown_penalty_pct as u128 / (max_hp * 100);
+
+    (role_cooldown + hp_penalty).min(i64::MAX as u128) as i64
+}
+
+// Create a program-derived account the way Anchor's init does. Anyone can
+// send lamports to a PDA before it exists, which would make create_account
+// fail, so a funded address is topped up to rent exemption, then allocated
+// and assigned instead
+pub fn create_pda_account<'info>(
+    payer: &Signer<'info>,
+    target: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    system_program: &Program<'info, System>,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let lamports = target.lamports();
+
+    if lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+                &[seeds],
+            ),
+            rent,
+            space as u64,
+            owner,
+        );
+    }
+
+    let shortfall = rent.saturating_sub(lamports);
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Allocate { account_to_allocate: target.clone() },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Assign { account_to_assign: target.clone() },
+            &[seeds],
+        ),
+        owner,
+    )
+}
+
+// Create the room's token vault at its PDA, owned by the escrow authority
+pub fn open_escrow_vault<'info>(
+    payer: &Signer<'info>,
+    vault: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    escrow: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    system_program: &Program<'info, System>,
+    vault_seeds: &[&[u8]],
+) -> Result<()> {
+    create_pda_account(payer, vault, token::TokenAccount::LEN, &token_program.key(), system_program, vault_seeds)?;
+    token::initialize_account3(CpiContext::new(
+        token_program.to_account_info(),
+        token::InitializeAccount3 {
+            account: vault.clone(),
+            mint: mint.clone(),
+            authority: escrow.clone(),
+        },
+    ))
+}
+
+// Move one player's stake into the room escrow. SPL stakes go to the token
+// vault when one is passed, otherwise SOL goes to the escrow PDA itself
+pub fn deposit_wager<'info>(
+    amount: u64,
+    player: &Signer<'info>,
+    escrow: &Option<UncheckedAccount<'info>>,
+    vault: Option<AccountInfo<'info>>,
+    player_tokens: &Option<Box<Account<'info, TokenAccount>>>,
+    token_program: &Option<Program<'info, Token>>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if let Some(vault) = vault {
+        let (Some(player_tokens), Some(token_program)) = (player_tokens, token_program) else {
+            return Err(RustUndeadError::InvalidWagerAccounts.into());
+        };
+        token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: player_tokens.to_account_info(),
+                    to: vault,
+                    authority: player.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    } else {
+        let escrow = escrow.as_ref().ok_or(RustUndeadError::InvalidWagerAccounts)?;
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: player.to_account_info(),
+                    to: escrow.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+}
+
+// Pay SOL out of a room escrow, signed by the escrow PDA
+pub fn release_escrow_sol<'info>(
+    escrow: &UncheckedAccount<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    room_id: &[u8; 32],
+    escrow_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: escrow.to_account_info(),
+                to: to.clone(),
+            },
+            &[&[WAGER_ESCROW, room_id.as_ref(), &[escrow_bump]]],
+        ),
+        amount,
+    )
+}
+
+// Pay tokens out of a room vault, signed by the escrow PDA
+pub fn release_escrow_tokens<'info>(
+    escrow: &UncheckedAccount<'info>,
+    vault: &Account<'info, TokenAccount>,
+    to: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    room_id: &[u8; 32],
+    escrow_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    require!(to.mint == vault.mint, RustUndeadError::InvalidWagerAccounts);
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: vault.to_account_info(),
+                to: to.to_account_info(),
+                authority: escrow.to_account_info(),
+            },
+            &[&[WAGER_ESCROW, room_id.as_ref(), &[escrow_bump]]],
+        ),
+        amount,
+    )
+}
+
+// Close an emptied room vault, rent goes back to whoever opened it
+pub fn close_escrow_vault<'info>(
+    escrow: &UncheckedAccount<'info>,
+    vault: &Account<'info, TokenAccount>,
+    rent_receiver: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    room_id: &[u8; 32],
+    escrow_bump: u8,
+) -> Result<()> {
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: vault.to_account_info(),
+            destination: rent_receiver.clone(),
+            authority: escrow.to_account_info(),
+        },
+        &[&[WAGER_ESCROW, room_id.as_ref(), &[escrow_bump]]],
+    ))
+}
+
+// Create a warrior's NFT mint at its PDA, zero decimals, minted by the NFT authority
+pub fn open_warrior_nft_mint<'info>(
+    payer: &Signer<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    system_program: &Program<'info, System>,
+    mint_seeds: &[&[u8]],
+) -> Result<()> {
+    create_pda_account(payer, mint, token::Mint::LEN, &token_program.key(), system_program, mint_seeds)?;
+    token::initialize_mint2(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token::InitializeMint2 { mint: mint.clone() },
+        ),
+        0,
+        &authority.key(),
+        None,
+    )
+}
+
+// Create the holder's associated token account, built by hand since the
+// associated token crate doesn't resolve against this program's solana-sdk
+pub fn create_associated_token_account<'info>(
+    payer: &Signer<'info>,
+    token_account: &AccountInfo<'info>,
+    wallet: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    associated_token_program: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(payer.key(), true),
+            AccountMeta::new(token_account.key(), false),
+            AccountMeta::new_readonly(wallet.key(), false),
+            AccountMeta::new_readonly(mint.key(), false),
+            AccountMeta::new_readonly(system_program.key(), false),
+            AccountMeta::new_readonly(token_program.key(), false),
+        ],
+        data: vec![1], // CreateIdempotent
+    };
+    invoke(
+        &ix,
+        &[
+            payer.to_account_info(),
+            token_account.clone(),
+            wallet.clone(),
+            mint.clone(),
+            system_program.to_account_info(),
+            token_program.to_account_info(),
+            associated_token_program.clone(),
+        ],
+    )?;
+    Ok(())
+}
+
+// Token metadata CreateMetadataAccountV3, serialized by hand for the same reason.
+// The NFT authority is both mint and update authority, no creators or collection
+#[allow(clippy::too_many_arguments)]
+pub fn create_warrior_nft_metadata<'info>(
+    metadata: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    token_metadata_program: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    name: &str,
+    uri: &str,
+    authority_seeds: &[&[u8]],
+) -> Result<()> {
+    const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+
+    let mut data = vec![CREATE_METADATA_ACCOUNT_V3];
+    (name, WARRIOR_NFT_SYMBOL, uri, 0u16).serialize(&mut data)?; // no seller fee
+    data.extend_from_slice(&[0, 0, 0]); // no creators, collection or uses
+    data.push(1); // mutable, so the URI can follow image updates
+    data.push(0); // no collection details
+
+    let ix = Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(metadata.key(), false),
+            AccountMeta::new_readonly(mint.key(), false),
+            AccountMeta::new_readonly(authority.key(), true),
+            AccountMeta::new(payer.key(), true),
+            AccountMeta::new_readonly(authority.key(), true),
+            AccountMeta::new_readonly(system_program.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[
+            metadata.clone(),
+            mint.clone(),
+            authority.clone(),
+            payer.to_account_info(),
+            system_program.to_account_info(),
+            token_metadata_program.clone(),
+        ],
+        &[authority_seeds],
+    )?;
+    Ok(())
+}
+
+// Lazily apply passive regeneration accrued since the last battle or heal,
+// returns the HP restored
+pub fn apply_passive_regen(warrior: &mut UndeadWarrior, config: &Config, now: i64) -> u16 {
+    let since = warrior.last_battle_at.max(warrior.last_healed_at);
+    let elapsed = now.saturating_sub(since).max(0);
+    let regen = (elapsed as u128 * config.hp_regen_per_hour as u128 / SECONDS_PER_HOUR as u128)
+        .min(u16::MAX as u128) as u16;
+
+    if regen == 0 || warrior.current_hp >= warrior.max_hp {
+        return 0;
+    }
+
+    let old_hp = warrior.current_hp;
+    warrior.current_hp = warrior.current_hp.saturating_add(regen).min(warrior.max_hp);
+
+    // Keep the unspent part of the interval so frequent calls don't slow regen
+    warrior.last_healed_at = if warrior.current_hp == warrior.max_hp {
+        now
+    } else {
+        let per_hour = config.hp_regen_per_hour as u128;
+        let used = (regen as u128 * SECONDS_PER_HOUR as u128).div_ceil(per_hour);
+        since.saturating_add(used as i64).min(now)
+    };
+
+    warrior.current_hp - old_hp
+}
+
+// Total XP needed to reach a level (level 1 starts at 0 XP)
+pub fn xp_required_for_level(level: u16) -> u64 {
+    let level = level as u64;
+    LEVEL_XP_STEP * level * level.saturating_sub(1)
+}
+
+// Stats gained per level: (max_hp, attack, defense, knowledge)
+pub fn level_up_stat_gains(class: WarriorClass) -> (u16, u16, u16, u16) {
+    match class {
+        WarriorClass::Validator => (5, 2, 2, 2),   // Balanced fighter
+        WarriorClass::Oracle => (4, 1, 1, 4),      // Knowledge specialist
+        WarriorClass::Guardian => (8, 1, 4, 1),    // Tank defender
+        WarriorClass::Daemon => (3, 4, 1, 2),      // Glass cannon
+    }
+}
+
+// Raise level and stats for every threshold the warrior's XP has crossed,
+// returns the number of levels gained
+pub fn apply_level_ups(warrior: &mut UndeadWarrior, max_level: u16) -> u16 {
+    let (hp_gain, attack_gain, defense_gain, knowledge_gain) = level_up_stat_gains(warrior.warrior_class);
+    let mut levels_gained = 0;
+
+    while warrior.level < max_level
+        && warrior.experience_points >= xp_required_for_level(warrior.level + 1)
+    {
+        warrior.level += 1;
+        warrior.max_hp = warrior.max_hp.saturating_add(hp_gain);
+        warrior.current_hp = warrior.current_hp.saturating_add(hp_gain);
+        warrior.base_attack = warrior.base_attack.saturating_add(attack_gain);
+        warrior.base_defense = warrior.base_defense.saturating_add(defense_gain);
+        warrior.base_knowledge = warrior.base_knowledge.saturating_add(knowledge_gain);
+        levels_gained += 1;
+    }
+
+    levels_gained
+}
+
+// Store a recalculated achievement level, returning the unlock for the caller
+// to emit when it went up. Levels can drop when warriors are given away
+pub fn update_achievement(
+    player: Pubkey,
+    category: AchievementCategory,
+    current: &mut AchievementLevel,
+    level: AchievementLevel,
+) -> Option<AchievementUnlocked> {
+    let unlocked = (level > *current).then_some(AchievementUnlocked { player, category, level });
+    *current = level;
+    unlocked
+}
+
 // Helper fxns to Calculate warrior achievement based on count
 pub fn calculate_warrior_achievement(warrior_count: u32) -> AchievementLevel {
       match warrior_count {
@@ -54,14 +542,22 @@ pub fn calculate_battle_achievement(battles: u32) -> AchievementLevel {
     }
 }
 
+// Position of a question on a 10-step scale, so damage phases stretch
+// over 5, 10 or 15 question battles alike
+pub fn question_phase(current_q: usize, question_count: u8) -> usize {
+    current_q * 10 / (question_count.max(1) as usize)
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn calculate_deterministic_damage_with_keys(
     attacker_warrior: &UndeadWarrior,
     defender_warrior: &UndeadWarrior,
     attacker_key: Pubkey,
     defender_key: Pubkey,
     current_q: usize,
+    question_count: u8,
     room_id: [u8; 32],
-    client_seed: u8,
+    entropy: &[u8; 32],
 ) -> Result<u16> {
     // ✅ DETERMINISTIC DAMAGE CALCULATION
     // Create unique seed for this damage event
@@ -70,17 +566,18 @@ pub fn calculate_deterministic_damage_with_keys(
     seed_data.push(current_q as u8);
     seed_data.extend_from_slice(&attacker_key.to_bytes());
     seed_data.extend_from_slice(&defender_key.to_bytes());
-    seed_data.push(client_seed);  // Add some variation
+    seed_data.extend_from_slice(entropy);  // Contributed by both players
     
     // Hash the seed data to get pseudo-random value
     let hash_result = hash(&seed_data);
     let pseudo_random = u16::from_le_bytes([hash_result.to_bytes()[0], hash_result.to_bytes()[1]]);
     
     // Get damage range based on question phase (escalating difficulty)
-    let (min_damage, max_damage) = match current_q {
-        0..=2 => (2, 10),    // Questions 1-3: Learning Phase
-        3..=6 => (6, 15),    // Questions 4-7: Pressure Phase
-        7..=9 => (10, 20),   // Questions 8-10: Deadly Phase
+    let phase = question_phase(current_q, question_count);
+    let (min_damage, max_damage) = match phase {
+        0..=2 => (2, 10),    // First 30% of questions: Learning Phase
+        3..=6 => (6, 15),    // Next 40%: Pressure Phase
+        7..=9 => (10, 20),   // Last 30%: Deadly Phase
         _ => (1, 1),         // Fallback
     };
     
@@ -101,7 +598,7 @@ pub fn calculate_deterministic_damage_with_keys(
     // Log damage calculation details
     msg!("🎲 Deterministic Damage Calculation:");
     msg!("   Question Phase: {} (Q{})", 
-        match current_q {
+        match phase {
             0..=2 => "Learning",
             3..=6 => "Pressure",
             7..=9 => "Deadly",
@@ -119,6 +616,295 @@ pub fn calculate_deterministic_damage_with_keys(
     Ok(final_damage)
 }
 
+// Elo expected score in per mille, for a player `gap` rating points below the
+// opponent. Sampled every 50 points and interpolated, so no floats on chain
+const EXPECTED_SCORE_PERMILLE: [i64; 17] = [
+    500, 429, 360, 297, 240, 192, 151, 118, 91, 70, 53, 40, 31, 23, 17, 13, 10,
+];
+
+pub fn expected_score_permille(rating: u32, opponent_rating: u32) -> i64 {
+    let gap = (opponent_rating as i64 - rating as i64).clamp(-800, 800);
+    let distance = gap.abs();
+    let step = (distance / 50) as usize;
+    let expected = if step >= 16 {
+        EXPECTED_SCORE_PERMILLE[16]
+    } else {
+        let low = EXPECTED_SCORE_PERMILLE[step];
+        let high = EXPECTED_SCORE_PERMILLE[step + 1];
+        low - (low - high) * (distance % 50) / 50
+    };
+    if gap >= 0 { expected } else { 1000 - expected }
+}
+
+// Rating after one result, provisional players move faster
+pub fn updated_rating(rating: u32, opponent_rating: u32, rated_battles: u32, won: bool) -> u32 {
+    let k = if rated_battles < RATING_PROVISIONAL_BATTLES {
+        RATING_K_PROVISIONAL
+    } else {
+        RATING_K
+    };
+    let actual = if won { 1000 } else { 0 };
+    let delta = k * (actual - expected_score_permille(rating, opponent_rating)) / 1000;
+    (rating as i64 + delta).max(MIN_RATING as i64) as u32
+}
+
+pub fn is_rating_provisional(rated_battles: u32) -> bool {
+    rated_battles < RATING_PROVISIONAL_BATTLES
+}
+
+// Concepts must exist in the bank and be picked at most once
+pub fn validate_selected_concepts(selected_concepts: &[u8; 5], concept_count: u8) -> Result<()> {
+    for &concept in selected_concepts {
+        require!((1..=concept_count).contains(&concept), RustUndeadError::InvalidConceptSelection);
+    }
+
+    let mut unique_concepts = *selected_concepts;
+    unique_concepts.sort();
+    for i in 1..unique_concepts.len() {
+        require!(unique_concepts[i] != unique_concepts[i-1], RustUndeadError::InvalidConceptSelection);
+    }
+    Ok(())
+}
+
+// Commitment the question authority publishes for each answer in the key
+pub fn compute_answer_commitment(
+    room_id: &[u8; 32],
+    question_index: u8,
+    answer: u8,
+    salt: &[u8; 32],
+) -> [u8; 32] {
+    hashv(&[room_id, &[question_index], &[answer], salt]).to_bytes()
+}
+
+// Hash a player seals their answer under; binding the player key stops
+// the opponent from replaying the same commitment
+pub fn compute_sealed_answer(
+    room_id: &[u8; 32],
+    question_index: u8,
+    player: &Pubkey,
+    answer: u8,
+    salt: &[u8; 32],
+) -> [u8; 32] {
+    hashv(&[room_id, &[question_index], player.as_ref(), &[answer], salt]).to_bytes()
+}
+
+// Salted answer digest the content authority puts in each bank leaf. The salt
+// stays off-chain with the question authority until the answer is revealed
+pub fn compute_answer_digest(question_id: u16, answer: u8, answer_salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[&question_id.to_le_bytes(), &[answer], answer_salt]).to_bytes()
+}
+
+// Leaf of the question bank tree. The answer enters as a salted digest
+// published by the content authority so proofs never leak it
+pub fn compute_question_leaf(
+    question_id: u16,
+    concept: u8,
+    topic: u8,
+    answer_options: u8,
+    answer_digest: &[u8; 32],
+) -> [u8; 32] {
+    hashv(&[&question_id.to_le_bytes(), &[concept], &[topic], &[answer_options], answer_digest]).to_bytes()
+}
+
+// Sorted-pair merkle proof, so callers don't need to pass leaf positions
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let mut computed = leaf;
+    for node in proof {
+        computed = if computed <= *node {
+            hashv(&[&computed, node]).to_bytes()
+        } else {
+            hashv(&[node, &computed]).to_bytes()
+        };
+    }
+    computed == *root
+}
+
+// Score the current question once both answers and the answer key are in,
+// apply damage and advance the battle (or finish it). Returns the round's
+// result for the caller to emit
+pub fn resolve_question(
+    battle_room: &mut BattleRoom,
+    warrior_a: &mut UndeadWarrior,
+    warrior_b: &mut UndeadWarrior,
+) -> Result<QuestionResolved> {
+    let current_q = battle_room.current_question as usize;
+    let question_count = battle_room.question_count;
+    require!(current_q < question_count as usize, RustUndeadError::InvalidQuestionIndex);
+
+    let player_a_answer = battle_room.player_a_answers[current_q]
+        .ok_or(RustUndeadError::AnswersNotSubmitted)?;
+    let player_b_answer = battle_room.player_b_answers[current_q]
+        .ok_or(RustUndeadError::AnswersNotSubmitted)?;
+    let correct_answer = battle_room.revealed_answers[current_q]
+        .ok_or(RustUndeadError::InvalidAnswerReveal)?;
+
+    // Reveal both answers simultaneously
+    msg!("📊 Question {} Results:", current_q + 1);
+    msg!("   Player A answered: {} ({})", 
+        player_a_answer, 
+        if player_a_answer == correct_answer { "✅ CORRECT" } else { "❌ WRONG" }
+    );
+    msg!("   Player B answered: {} ({})", 
+        player_b_answer, 
+        if player_b_answer == correct_answer { "✅ CORRECT" } else { "❌ WRONG" }
+    );
+    msg!("   Correct answer was: {}", correct_answer);
+
+    // ✅ Process answers and update scores
+    let player_a_correct = player_a_answer == correct_answer;
+    let player_b_correct = player_b_answer == correct_answer;
+
+    if player_a_correct {
+        battle_room.player_a_correct += 1;
+        msg!("⚔️ Player A gets point!");
+    }
+
+    if player_b_correct {
+        battle_room.player_b_correct += 1;
+        msg!("⚔️ Player B gets point!");
+    }
+
+    msg!("📊 Updated Scores - A: {}, B: {}", 
+        battle_room.player_a_correct, battle_room.player_b_correct);
+
+    let warrior_a_key = battle_room.warrior_a;
+    let warrior_b_key = battle_room.warrior_b.ok_or(RustUndeadError::InvalidWarrior)?;
+    let mut damage_to_a = 0;
+    let mut damage_to_b = 0;
+
+    // ✅ Handle DETERMINISTIC damage calculation for each correct answer
+    if player_a_correct {
+        msg!("🗡️ Player A correct - calculating damage to Player B's warrior...");
+
+        let final_damage = calculate_deterministic_damage_with_keys(
+            warrior_a,
+            warrior_b,
+            warrior_a_key,
+            warrior_b_key,
+            current_q,
+            question_count,
+            battle_room.room_id,
+            &battle_room.damage_entropy,
+        )?;
+
+        let old_hp = warrior_b.current_hp;
+        warrior_b.current_hp = warrior_b.current_hp.saturating_sub(final_damage);
+        damage_to_b = old_hp - warrior_b.current_hp;
+
+        msg!("🩸 {} takes {} damage! HP: {} → {}", 
+            warrior_b.name, final_damage, old_hp, warrior_b.current_hp);
+
+        // Check for elimination
+        if warrior_b.current_hp == 0 {
+            battle_room.winner = Some(battle_room.player_a);
+            battle_room.state = BattleState::Completed;
+            msg!("💀 {} has been defeated!", warrior_b.name);
+            msg!("🏆 {} wins by elimination!", warrior_a.name);
+        } else if warrior_b.current_hp <= 10 {
+            msg!("⚠️ {} is critically wounded! ({} HP remaining)", 
+                warrior_b.name, warrior_b.current_hp);
+        }
+    }
+
+    if player_b_correct && battle_room.state != BattleState::Completed {
+        msg!("🗡️ Player B correct - calculating damage to Player A's warrior...");
+
+        let final_damage = calculate_deterministic_damage_with_keys(
+            warrior_b,
+            warrior_a,
+            warrior_b_key,
+            warrior_a_key,
+            current_q,
+            question_count,
+            battle_room.room_id,
+            &battle_room.damage_entropy,
+        )?;
+
+        let old_hp = warrior_a.current_hp;
+        warrior_a.current_hp = warrior_a.current_hp.saturating_sub(final_damage);
+        damage_to_a = old_hp - warrior_a.current_hp;
+
+        msg!("🩸 {} takes {} damage! HP: {} → {}", 
+            warrior_a.name, final_damage, old_hp, warrior_a.current_hp);
+
+        // Check for elimination
+        if warrior_a.current_hp == 0 {
+            battle_room.winner = battle_room.player_b;
+            battle_room.state = BattleState::Completed;
+            msg!("💀 {} has been defeated!", warrior_a.name);
+            msg!("🏆 {} wins by elimination!", warrior_b.name);
+        } else if warrior_a.current_hp <= 10 {
+            msg!("⚠️ {} is critically wounded! ({} HP remaining)", 
+                warrior_a.name, warrior_a.current_hp);
+        }
+    }
+
+    if !player_a_correct && !player_b_correct {
+        msg!("❌ Neither player correct - no damage dealt, moving to next question");
+    }
+
+    let resolved = QuestionResolved {
+        room_id: battle_room.room_id,
+        question_index: current_q as u8,
+        correct_answer,
+        player_a_correct,
+        player_b_correct,
+        damage_to_a,
+        damage_to_b,
+        warrior_a_hp: warrior_a.current_hp,
+        warrior_b_hp: warrior_b.current_hp,
+    };
+
+    // ✅ Advance question or end battle
+    battle_room.player_a_sealed_answer = None;
+    battle_room.player_b_sealed_answer = None;
+
+    let last_question = current_q + 1 == question_count as usize;
+    if !last_question && battle_room.state != BattleState::Completed {
+        battle_room.current_question += 1;
+        battle_room.question_deadline = Clock::get()?.unix_timestamp
+            .saturating_add(battle_room.answer_timeout);
+        msg!("📋 Moving to question {}", battle_room.current_question + 1);
+    } else if last_question && battle_room.state != BattleState::Completed {
+        msg!("🏁 All questions completed! Determining final winner by HP...");
+
+        decide_winner_by_hp(battle_room, warrior_a, warrior_b);
+        battle_room.state = BattleState::Completed;
+    }
+
+    Ok(resolved)
+}
+
+// Winner by remaining HP, then by score, Player A on a perfect tie
+pub fn decide_winner_by_hp(
+    battle_room: &mut BattleRoom,
+    warrior_a: &UndeadWarrior,
+    warrior_b: &UndeadWarrior,
+) {
+    let warrior_a_hp = warrior_a.current_hp;
+    let warrior_b_hp = warrior_b.current_hp;
+
+    if warrior_a_hp > warrior_b_hp {
+        battle_room.winner = Some(battle_room.player_a);
+        msg!("🏆 Player A wins with {} HP vs {} HP!", warrior_a_hp, warrior_b_hp);
+    } else if warrior_b_hp > warrior_a_hp {
+        battle_room.winner = battle_room.player_b;
+        msg!("🏆 Player B wins with {} HP vs {} HP!", warrior_b_hp, warrior_a_hp);
+    } else {
+        let (score_a, score_b) = battle_room.get_scores();
+        if score_a > score_b {
+            battle_room.winner = Some(battle_room.player_a);
+            msg!("🏆 HP tied at {}! Player A wins by score: {} vs {}", warrior_a_hp, score_a, score_b);
+        } else if score_b > score_a {
+            battle_room.winner = battle_room.player_b;
+            msg!("🏆 HP tied at {}! Player B wins by score: {} vs {}", warrior_b_hp, score_b, score_a);
+        } else {
+            battle_room.winner = Some(battle_room.player_a);
+            msg!("🏆 Perfect tie! HP: {}, Score: {} each - Player A wins by default", warrior_a_hp, score_a);
+        }
+    }
+}
+
 pub fn get_class_folder_hash(class: WarriorClass) -> &'static str {
     match class {
         WarriorClass::Guardian => GUARDIAN_FOLDER_HASH,
@@ -128,46 +914,85 @@ pub fn get_class_folder_hash(class: WarriorClass) -> &'static str {
     }
 }
 
+// Independent sub-seed per roll, so stats from one VRF output aren't correlated
+pub fn derive_randomness(randomness: &[u8; 32], index: u8) -> [u8; 32] {
+    hashv(&[randomness, &[index]]).to_bytes()
+}
+
+// Combat stats from VRF randomness, same class ranges as temp_stats_rand
+pub fn generate_warrior_stats(randomness: &[u8; 32], class: WarriorClass) -> (u16, u16, u16) {
+    let attack_rand = derive_randomness(randomness, 0);
+    let defense_rand = derive_randomness(randomness, 1);
+    let knowledge_rand = derive_randomness(randomness, 2);
+
+    let ((atk_min, atk_max), (def_min, def_max), (know_min, know_max)) = match class {
+        WarriorClass::Validator => ((60, 100), (40, 100), (40, 80)),   // Balanced fighter
+        WarriorClass::Oracle => ((50, 100), (40, 80), (100, 141)),     // Knowledge specialist
+        WarriorClass::Guardian => ((40, 61), (100, 140), (50, 100)),   // Tank defender
+        WarriorClass::Daemon => ((100, 140), (40, 61), (50, 100)),     // Glass cannon
+    };
+
+    let attack = ephemeral_vrf_sdk::rnd::random_u8_with_range(&attack_rand, atk_min, atk_max);
+    let defense = ephemeral_vrf_sdk::rnd::random_u8_with_range(&defense_rand, def_min, def_max);
+    let knowledge = ephemeral_vrf_sdk::rnd::random_u8_with_range(&knowledge_rand, know_min, know_max);
+
+    msg!(
+        "⚔️ Combat Profile: ATK {} | DEF {} | KNOW {} | Strategy: {}",
+        attack,
+        defense,
+        knowledge,
+        match class {
+            WarriorClass::Validator => "Balanced fighter",
+            WarriorClass::Oracle => "Knowledge specialist",
+            WarriorClass::Guardian => "Tank defender",
+            WarriorClass::Daemon => "Glass cannon",
+        }
+    );
+
+    (attack as u16, defense as u16, knowledge as u16)
+}
+
 // Generate image selection and URL from VRF randomness
-// pub fn generate_warrior_image(
-//     randomness_slice: &[u8; 32],
-//     class: WarriorClass
-// ) -> Result<(ImageRarity, u8, String)> {
+pub fn generate_warrior_image(
+    randomness_slice: &[u8; 32],
+    class: WarriorClass
+) -> Result<(ImageRarity, u8, String)> {
     
-//     // Step 1: Determine rarity using VRF (65% common, 25% uncommon, 10% rare) range 1-100
-//     let rarity_roll = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness_slice, 1, 101);
+    // Step 1: Determine rarity using VRF (65% common, 25% uncommon, 10% rare) range 1-100
+    let rarity_roll = ephemeral_vrf_sdk::rnd::random_u8_with_range(randomness_slice, 1, 100);
     
-//     let (rarity, rarity_prefix, max_count) = match rarity_roll {
-//         1..=65 => (ImageRarity::Common, "c", COMMON_COUNT),      // 65% chance
-//         66..=90 => (ImageRarity::Uncommon, "u", UNCOMMON_COUNT), // 25% chance
-//         91..=100 => (ImageRarity::Rare, "r", RARE_COUNT),        // 10% chance
-//         _ => (ImageRarity::Common, "c", COMMON_COUNT),
-//     };
+    let (rarity, rarity_prefix, max_count) = match rarity_roll {
+        1..=65 => (ImageRarity::Common, "c", COMMON_COUNT),      // 65% chance
+        66..=90 => (ImageRarity::Uncommon, "u", UNCOMMON_COUNT), // 25% chance
+        91..=100 => (ImageRarity::Rare, "r", RARE_COUNT),        // 10% chance
+        _ => (ImageRarity::Common, "c", COMMON_COUNT),
+    };
     
-//     // Step 2: Select random image within rarity (1-based numbering: c1, c2, etc.)
-//     let image_number = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness_slice, 1, max_count + 1);
+    // Step 2: Select random image within rarity (1-based numbering: c1, c2, etc.)
+    let image_randomness = derive_randomness(randomness_slice, 3);
+    let image_number = ephemeral_vrf_sdk::rnd::random_u8_with_range(&image_randomness, 1, max_count);
     
-//     // Step 3: Construct full IPFS URL
-//     let folder_hash = get_class_folder_hash(class);
-//     let image_url = format!(
-//         "{}/{}/{}{}.png",
-//         IPFS_GATEWAY,
-//         folder_hash,
-//         rarity_prefix,
-//         image_number
-//     );
+    // Step 3: Construct full IPFS URL
+    let folder_hash = get_class_folder_hash(class);
+    let image_url = format!(
+        "{}/{}/{}{}.png",
+        IPFS_GATEWAY,
+        folder_hash,
+        rarity_prefix,
+        image_number
+    );
     
-//     msg!(
-//         "🎨 Selected {} {} image: {}{}.png → {}",
-//         class.to_string(),
-//         rarity.to_string(),
-//         rarity_prefix,
-//         image_number,
-//         image_url
-//     );
+    msg!(
+        "🎨 Selected {} {} image: {}{}.png → {}",
+        class.to_string(),
+        rarity.to_string(),
+        rarity_prefix,
+        image_number,
+        image_url
+    );
     
-//     Ok((rarity, image_number, image_url))
-// }
+    Ok((rarity, image_number, image_url))
+}
 
 
 pub fn temp_img_rand(
@@ -311,4 +1136,291 @@ let mut seed_data = Vec::new();
 
 
 Ok((attack, defense, knowledge))
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_warrior(class: WarriorClass) -> UndeadWarrior {
+        UndeadWarrior {
+            name: "Test Warrior".to_string(),
+            owner: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            warrior_index: 0,
+            dna: [0; 8],
+            created_at: 0,
+            base_attack: 50,
+            base_defense: 50,
+            base_knowledge: 50,
+            current_hp: 100,
+            max_hp: 100,
+            warrior_class: class,
+            battles_won: 0,
+            battles_lost: 0,
+            experience_points: 0,
+            level: 1,
+            rating: DEFAULT_RATING,
+            rated_battles: 0,
+            last_battle_at: 0,
+            cooldown_expires_at: 0,
+            last_healed_at: 0,
+            last_renamed_at: 0,
+            stats_pending: false,
+            nft_mint: None,
+            current_room: None,
+            queued: false,
+            bump: 0,
+            image_rarity: ImageRarity::Common,
+            image_index: 0,
+            image_uri: String::new(),
+        }
+    }
+
+    fn test_config(hp_regen_per_hour: u16) -> Config {
+        Config {
+            admin: Pubkey::new_unique(),
+            pending_admin: None,
+            question_authority: Pubkey::new_unique(),
+            total_warriors: 0,
+            cooldown_time: 3600,
+            winner_cooldown_pct: DEFAULT_WINNER_COOLDOWN_PCT,
+            loser_cooldown_pct: DEFAULT_LOSER_COOLDOWN_PCT,
+            hp_cooldown_penalty_pct: DEFAULT_HP_COOLDOWN_PENALTY_PCT,
+            hp_regen_per_hour,
+            heal_cost_lamports: DEFAULT_HEAL_COST_LAMPORTS,
+            rename_fee_lamports: DEFAULT_RENAME_FEE_LAMPORTS,
+            rename_cooldown: DEFAULT_RENAME_COOLDOWN,
+            treasury: Pubkey::new_unique(),
+            wager_fee_bps: DEFAULT_WAGER_FEE_BPS,
+            max_level: DEFAULT_MAX_LEVEL,
+            answer_timeout: DEFAULT_ANSWER_TIMEOUT,
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
+            total_battles: 0,
+            current_season: 1,
+            season_started_at: 0,
+            is_paused: false,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    fn pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        if a <= b {
+            hashv(&[a, b]).to_bytes()
+        } else {
+            hashv(&[b, a]).to_bytes()
+        }
+    }
+
+    fn leaf(n: u8) -> [u8; 32] {
+        hash(&[n]).to_bytes()
+    }
+
+    #[test]
+    fn merkle_proof_verifies_every_leaf() {
+        // four leaves, one level of pairs under the root
+        let leaves = [leaf(0), leaf(1), leaf(2), leaf(3)];
+        let left = pair(&leaves[0], &leaves[1]);
+        let right = pair(&leaves[2], &leaves[3]);
+        let root = pair(&left, &right);
+
+        assert!(verify_merkle_proof(&[leaves[1], right], &root, leaves[0]));
+        assert!(verify_merkle_proof(&[leaves[0], right], &root, leaves[1]));
+        assert!(verify_merkle_proof(&[leaves[3], left], &root, leaves[2]));
+        assert!(verify_merkle_proof(&[leaves[2], left], &root, leaves[3]));
+    }
+
+    #[test]
+    fn merkle_proof_ignores_sibling_order() {
+        let (a, b) = (leaf(7), leaf(8));
+        let root = pair(&a, &b);
+        assert!(verify_merkle_proof(&[b], &root, a));
+        assert!(verify_merkle_proof(&[a], &root, b));
+    }
+
+    #[test]
+    fn merkle_proof_rejects_wrong_leaf_or_path() {
+        let leaves = [leaf(0), leaf(1), leaf(2), leaf(3)];
+        let left = pair(&leaves[0], &leaves[1]);
+        let right = pair(&leaves[2], &leaves[3]);
+        let root = pair(&left, &right);
+
+        assert!(!verify_merkle_proof(&[leaves[1], right], &root, leaf(9)));
+        assert!(!verify_merkle_proof(&[leaves[2], right], &root, leaves[0]));
+        assert!(!verify_merkle_proof(&[leaves[1]], &root, leaves[0]));
+    }
+
+    #[test]
+    fn merkle_proof_of_single_leaf_tree_is_empty() {
+        let only = leaf(1);
+        assert!(verify_merkle_proof(&[], &only, only));
+        assert!(!verify_merkle_proof(&[], &only, leaf(2)));
+    }
+
+    #[test]
+    fn regen_restores_hp_for_elapsed_time() {
+        let config = test_config(20);
+        let mut warrior = test_warrior(WarriorClass::Validator);
+        warrior.current_hp = 50;
+
+        assert_eq!(apply_passive_regen(&mut warrior, &config, SECONDS_PER_HOUR), 20);
+        assert_eq!(warrior.current_hp, 70);
+        assert_eq!(warrior.last_healed_at, SECONDS_PER_HOUR);
+    }
+
+    #[test]
+    fn regen_stops_at_max_hp() {
+        let config = test_config(20);
+        let mut warrior = test_warrior(WarriorClass::Validator);
+        warrior.current_hp = 95;
+
+        assert_eq!(apply_passive_regen(&mut warrior, &config, 5 * SECONDS_PER_HOUR), 5);
+        assert_eq!(warrior.current_hp, 100);
+        assert_eq!(warrior.last_healed_at, 5 * SECONDS_PER_HOUR);
+    }
+
+    #[test]
+    fn regen_keeps_unspent_time_between_calls() {
+        // 20 HP an hour is one HP every 180 seconds
+        let config = test_config(20);
+        let mut warrior = test_warrior(WarriorClass::Validator);
+        warrior.current_hp = 50;
+
+        assert_eq!(apply_passive_regen(&mut warrior, &config, 200), 1);
+        assert_eq!(warrior.last_healed_at, 180);
+        assert_eq!(apply_passive_regen(&mut warrior, &config, 360), 1);
+        assert_eq!(warrior.current_hp, 52);
+    }
+
+    #[test]
+    fn regen_counts_from_the_later_of_battle_and_heal() {
+        let config = test_config(20);
+        let mut warrior = test_warrior(WarriorClass::Validator);
+        warrior.current_hp = 50;
+        warrior.last_healed_at = 1_000;
+        warrior.last_battle_at = 10_000;
+
+        assert_eq!(apply_passive_regen(&mut warrior, &config, 10_000 + SECONDS_PER_HOUR / 2), 10);
+        assert_eq!(warrior.current_hp, 60);
+    }
+
+    #[test]
+    fn regen_leaves_healthy_warriors_alone() {
+        let config = test_config(20);
+        let mut warrior = test_warrior(WarriorClass::Validator);
+        warrior.last_healed_at = 100;
+
+        assert_eq!(apply_passive_regen(&mut warrior, &config, SECONDS_PER_HOUR), 0);
+        assert_eq!(warrior.last_healed_at, 100);
+
+        // no time passed, nothing to restore
+        warrior.current_hp = 10;
+        assert_eq!(apply_passive_regen(&mut warrior, &config, 100), 0);
+        assert_eq!(warrior.current_hp, 10);
+    }
+
+    #[test]
+    fn level_thresholds_grow_quadratically() {
+        assert_eq!(xp_required_for_level(1), 0);
+        assert_eq!(xp_required_for_level(2), 100);
+        assert_eq!(xp_required_for_level(3), 300);
+        assert_eq!(xp_required_for_level(4), 600);
+    }
+
+    #[test]
+    fn level_up_waits_for_the_threshold() {
+        let mut warrior = test_warrior(WarriorClass::Guardian);
+        warrior.experience_points = 99;
+
+        assert_eq!(apply_level_ups(&mut warrior, DEFAULT_MAX_LEVEL), 0);
+        assert_eq!(warrior.level, 1);
+        assert_eq!(warrior.max_hp, 100);
+    }
+
+    #[test]
+    fn level_up_applies_class_gains() {
+        let mut warrior = test_warrior(WarriorClass::Guardian);
+        warrior.current_hp = 60;
+        warrior.experience_points = 100;
+
+        assert_eq!(apply_level_ups(&mut warrior, DEFAULT_MAX_LEVEL), 1);
+        assert_eq!(warrior.level, 2);
+        assert_eq!(warrior.max_hp, 108);
+        assert_eq!(warrior.current_hp, 68);
+        assert_eq!(
+            (warrior.base_attack, warrior.base_defense, warrior.base_knowledge),
+            (51, 54, 51)
+        );
+    }
+
+    #[test]
+    fn level_up_crosses_several_thresholds_at_once() {
+        let mut warrior = test_warrior(WarriorClass::Daemon);
+        warrior.experience_points = 650;
+
+        assert_eq!(apply_level_ups(&mut warrior, DEFAULT_MAX_LEVEL), 3);
+        assert_eq!(warrior.level, 4);
+        assert_eq!(warrior.max_hp, 109);
+        assert_eq!(warrior.base_attack, 62);
+    }
+
+    #[test]
+    fn level_up_stops_at_the_cap() {
+        let mut warrior = test_warrior(WarriorClass::Oracle);
+        warrior.experience_points = 1_000_000;
+
+        assert_eq!(apply_level_ups(&mut warrior, 3), 2);
+        assert_eq!(warrior.level, 3);
+        assert_eq!(apply_level_ups(&mut warrior, 3), 0);
+        assert_eq!(warrior.level, 3);
+    }
+
+    #[test]
+    fn expected_score_is_even_between_equals() {
+        assert_eq!(expected_score_permille(1200, 1200), 500);
+    }
+
+    #[test]
+    fn expected_score_interpolates_between_samples() {
+        // 25 points is halfway between the 500 and 429 samples
+        assert_eq!(expected_score_permille(1200, 1225), 465);
+        assert_eq!(expected_score_permille(1225, 1200), 535);
+        assert_eq!(expected_score_permille(1200, 1300), 360);
+    }
+
+    #[test]
+    fn expected_score_is_symmetric_and_clamped() {
+        for (a, b) in [(1200, 1325), (900, 1450), (2000, 1210), (1500, 1501)] {
+            assert_eq!(expected_score_permille(a, b) + expected_score_permille(b, a), 1000);
+        }
+        assert_eq!(expected_score_permille(1000, 1800), 10);
+        assert_eq!(expected_score_permille(1000, 3000), 10);
+        assert_eq!(expected_score_permille(3000, 1000), 990);
+    }
+
+    #[test]
+    fn rating_moves_by_k_times_surprise() {
+        let established = RATING_PROVISIONAL_BATTLES;
+        assert_eq!(updated_rating(1200, 1200, established, true), 1216);
+        assert_eq!(updated_rating(1200, 1200, established, false), 1184);
+
+        // beating a much stronger opponent pays more than beating an equal
+        assert!(updated_rating(1200, 1600, established, true) > 1216);
+        assert!(updated_rating(1600, 1200, established, true) < 1616);
+    }
+
+    #[test]
+    fn provisional_ratings_move_faster() {
+        assert!(is_rating_provisional(RATING_PROVISIONAL_BATTLES - 1));
+        assert!(!is_rating_provisional(RATING_PROVISIONAL_BATTLES));
+        assert_eq!(updated_rating(1200, 1200, 0, true), 1232);
+        assert_eq!(updated_rating(1200, 1200, 0, false), 1168);
+    }
+
+    #[test]
+    fn rating_never_drops_below_the_floor() {
+        assert_eq!(updated_rating(MIN_RATING, MIN_RATING, 0, false), MIN_RATING);
+        assert_eq!(updated_rating(MIN_RATING + 10, MIN_RATING + 10, 0, false), MIN_RATING);
+    }
+}
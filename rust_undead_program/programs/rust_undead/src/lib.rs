@@ -4,10 +4,13 @@ pub mod contexts;
 pub mod state;
 pub mod helpers;
 pub mod constants;
+pub mod events;
 use anchor_lang::prelude::*;
 use ephemeral_vrf_sdk::anchor::vrf;
-// use ephemeral_vrf_sdk::instructions::{create_request_randomness_ix, RequestRandomnessParams};
-// use ephemeral_vrf_sdk::types::SerializableAccountMeta;
+#[cfg(not(feature = "deterministic-stats"))]
+use ephemeral_vrf_sdk::instructions::{create_request_randomness_ix, RequestRandomnessParams};
+#[cfg(not(feature = "deterministic-stats"))]
+use ephemeral_vrf_sdk::types::SerializableAccountMeta;
 
 use ephemeral_rollups_sdk::anchor::ephemeral;
 
@@ -17,6 +20,7 @@ pub use contexts::*;
 pub use error::*;
 pub use state::*;
 pub use helpers::*;
+pub use events::*;
 
 declare_id!("Fd6VNGBUidnLf9cS3q9mMbWBXZDFLA1QSdm88nFEEjty");
 
@@ -30,8 +34,79 @@ pub mod rust_undead {
 pub fn initialize(
     ctx: Context<Initialize>,
 	cooldown_time: u64,
+	question_authority: Pubkey,
 ) -> Result<()> {
-    ctx.accounts.initialize(cooldown_time, &ctx.bumps)
+    ctx.accounts.initialize(cooldown_time, question_authority, &ctx.bumps)
+}
+
+
+// admin pause switch
+pub fn pause_game(ctx: Context<SetPause>) -> Result<()> {
+    ctx.accounts.set_paused(true)
+}
+
+pub fn unpause_game(ctx: Context<SetPause>) -> Result<()> {
+    ctx.accounts.set_paused(false)
+}
+
+// admin rotation and tunables
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    ctx.accounts.propose_admin(new_admin)
+}
+
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    ctx.accounts.accept_admin()
+}
+
+pub fn update_config(ctx: Context<UpdateConfig>, update: ConfigUpdate) -> Result<()> {
+    ctx.accounts.update_config(update)
+}
+
+// extend a leaderboard toward its full size
+pub fn grow_leaderboard(ctx: Context<GrowLeaderboard>) -> Result<()> {
+    ctx.accounts.grow_leaderboard()
+}
+
+// rank of a player on any leaderboard
+pub fn query_rank(ctx: Context<QueryRank>, player: Pubkey) -> Result<Option<u32>> {
+    ctx.accounts.query_rank(player)
+}
+
+// create the next season's board ahead of start_new_season
+pub fn create_season_leaderboard(ctx: Context<CreateSeasonLeaderboard>) -> Result<()> {
+    ctx.accounts.create_season_leaderboard()
+}
+
+// archive the current season's board and open the next season
+pub fn start_new_season(ctx: Context<StartNewSeason>) -> Result<()> {
+    ctx.accounts.start_new_season()
+}
+
+
+// question bank
+pub fn initialize_question_bank(
+    ctx: Context<InitializeQuestionBank>,
+    content_authority: Pubkey,
+    merkle_root: [u8; 32],
+    question_count: u32,
+    concept_count: u8,
+) -> Result<()> {
+    ctx.accounts.initialize_question_bank(
+        content_authority,
+        merkle_root,
+        question_count,
+        concept_count,
+        &ctx.bumps
+    )
+}
+
+pub fn update_question_bank(
+    ctx: Context<UpdateQuestionBank>,
+    merkle_root: [u8; 32],
+    question_count: u32,
+    concept_count: u8,
+) -> Result<()> {
+    ctx.accounts.update_question_bank(merkle_root, question_count, concept_count)
 }
 
 
@@ -44,20 +119,35 @@ pub fn create_warrior(
     client_seed: u8,
 ) -> Result<()> {
     // Validation. 
-    require!(name.len() <= 32, RustUndeadError::NameTooLong);
-    require!(name.len() > 0, RustUndeadError::NameEmpty);
+    validate_warrior_name(&name)?;
+
+    let warrior_key = ctx.accounts.warrior.key();
+
+    // Claim the name for this warrior
+    ctx.accounts.name_registry.set_inner(NameRegistry {
+        name: name.clone(),
+        warrior: warrior_key,
+        claimed_at: Clock::get()?.unix_timestamp,
+        bump: ctx.bumps.name_registry,
+    });
 
     let warrior = &mut ctx.accounts.warrior;
     warrior.name = name;
     warrior.owner = ctx.accounts.player.key();
+    warrior.creator = ctx.accounts.player.key();
+    warrior.warrior_index = ctx.accounts.user_profile.next_warrior_index;
+    warrior.nft_mint = None;
+    warrior.current_room = None;
     warrior.dna = dna;
     warrior.created_at = Clock::get()?.unix_timestamp;
     warrior.warrior_class = class;
     warrior.last_battle_at = 0;
+    warrior.last_healed_at = 0;
+    warrior.last_renamed_at = 0;
     warrior.cooldown_expires_at = 0;
     warrior.bump = ctx.bumps.warrior;
 
-    // Initialize battle stats (will be set by deterministic generation)
+    // Initialize battle stats (set by VRF callback or deterministic generation)
     warrior.base_attack = 0;
     warrior.base_defense = 0;
     warrior.base_knowledge = 0;
@@ -67,199 +157,306 @@ pub fn create_warrior(
     warrior.battles_lost = 0;
     warrior.experience_points = 0;
     warrior.level = 1;
+    warrior.rating = DEFAULT_RATING;
+    warrior.rated_battles = 0;
 
     // Update user profile
+    let player_key = ctx.accounts.player.key();
+    let now = Clock::get()?.unix_timestamp;
     let user_profile = &mut ctx.accounts.user_profile;
     if user_profile.owner == Pubkey::default() {
         // First time initialization
-        user_profile.owner = ctx.accounts.player.key();
-        user_profile.join_date = Clock::get()?.unix_timestamp;
-        user_profile.warriors_created = 1;
-        user_profile.total_battles_won = 0;
-        user_profile.total_battles_lost = 0;
-        user_profile.total_battles_fought = 0;
-        user_profile.total_points = 0;
-        user_profile.bump = ctx.bumps.user_profile;
-    } else {
-        // Increment warrior count
-        user_profile.warriors_created = user_profile.warriors_created.saturating_add(1);
+        user_profile.initialize(player_key, now, ctx.accounts.config.current_season, ctx.bumps.user_profile);
     }
+    // Increment warrior count
+    user_profile.warriors_created = user_profile.warriors_created.saturating_add(1);
+    user_profile.next_warrior_index = user_profile.next_warrior_index.saturating_add(1);
             
     // Update achievements
     let user_achievements = &mut ctx.accounts.user_achievements;
     if user_achievements.owner == Pubkey::default() {
-        // First time initialization
-        user_achievements.owner = ctx.accounts.player.key();
-        user_achievements.overall_achievements = AchievementLevel::None;
-        user_achievements.warrior_achivement = AchievementLevel::Bronze; // First warrior
-        user_achievements.winner_achievement = AchievementLevel::None;
-        user_achievements.battle_achievement = AchievementLevel::None;
-        user_achievements.first_warrior_date = Clock::get()?.unix_timestamp;
-        user_achievements.bump = ctx.bumps.user_achievements;
-
-        // Set initial warrior achievement based on first warrior creation
-        user_achievements.warrior_achivement = calculate_warrior_achievement(user_profile.warriors_created);
-    } else {
-        // Update warrior achievement based on count
-        user_achievements.warrior_achivement = calculate_warrior_achievement(user_profile.warriors_created);
+        // First time initialization, the first warrior unlocks Bronze below
+        user_achievements.initialize(player_key, now, ctx.bumps.user_achievements);
+    }
+
+    // Update warrior achievement based on count
+    if let Some(unlocked) = update_achievement(
+        player_key,
+        AchievementCategory::Warrior,
+        &mut user_achievements.warrior_achivement,
+        calculate_warrior_achievement(user_profile.warriors_created),
+    ) {
+        emit!(unlocked);
     }
     
     // Update overall points and achievements
     user_profile.total_points = user_profile.total_points.saturating_add(100);
-    user_achievements.overall_achievements = calculate_overall_achievement(user_profile.total_points);
-    
-    msg!("Warrior '{}' created with 100 HP, using deterministic stat generation...", warrior.name);
-
-    // VRF for combat stats generation (COMMENTED OUT - Activate later)
-    // let ix = create_request_randomness_ix(RequestRandomnessParams { 
-    //     payer: ctx.accounts.player.key(), 
-    //     oracle_queue: ctx.accounts.oracle_queue.key(), 
-    //     callback_program_id: ID, 
-    //     callback_discriminator: instruction::CallbackWarriorStats::DISCRIMINATOR.to_vec(),
-    //     caller_seed: [client_seed; 32], 
-    //     accounts_metas: Some(vec![SerializableAccountMeta{
-    //         pubkey: ctx.accounts.warrior.key(),
-    //         is_signer: false,
-    //         is_writable: true
-    //     }]), 
-    //     ..Default::default()
-    // });
-    // ctx.accounts.invoke_signed_vrf(&ctx.accounts.player.to_account_info(), &ix)?;
-
-    let player_key = ctx.accounts.player.key();
+    if let Some(unlocked) = update_achievement(
+        player_key,
+        AchievementCategory::Overall,
+        &mut user_achievements.overall_achievements,
+        calculate_overall_achievement(user_profile.total_points),
+    ) {
+        emit!(unlocked);
+    }
 
-    match temp_stats_rand(player_key, dna, client_seed, class) {
-        Ok((attack, defense, knowledge)) => {
-            warrior.base_attack = attack;
-            warrior.base_defense = defense;
-            warrior.base_knowledge = knowledge;
+    emit!(WarriorCreated {
+        warrior: warrior_key,
+        owner: player_key,
+        name: warrior.name.clone(),
+        class,
+        created_at: warrior.created_at,
+    });
+    
+    // Localnet has no VRF oracle, so stats come from the warrior's own seeds
+    #[cfg(feature = "deterministic-stats")]
+    {
+        msg!("Warrior '{}' created with 100 HP, using deterministic stat generation...", warrior.name);
+
+        match temp_stats_rand(player_key, dna, client_seed, class) {
+            Ok((attack, defense, knowledge)) => {
+                warrior.base_attack = attack;
+                warrior.base_defense = defense;
+                warrior.base_knowledge = knowledge;
             
-            msg!("🔧 Deterministic stats generated - ATK: {}, DEF: {}, KNOW: {}", 
-                 attack, defense, knowledge);
-        },
-        Err(e) => {
-            let (default_attack, default_defense, default_knowledge) = match class {
-                WarriorClass::Daemon => (120, 50, 75),      // Glass cannon
-                WarriorClass::Guardian => (50, 120, 75),    // Tank
-                WarriorClass::Oracle => (75, 60, 120),      // Knowledge specialist
-                WarriorClass::Validator => (80, 80, 60),    // Balanced
-            };
+                msg!("🔧 Deterministic stats generated - ATK: {}, DEF: {}, KNOW: {}", 
+                     attack, defense, knowledge);
+            },
+            Err(e) => {
+                let (default_attack, default_defense, default_knowledge) = match class {
+                    WarriorClass::Daemon => (120, 50, 75),      // Glass cannon
+                    WarriorClass::Guardian => (50, 120, 75),    // Tank
+                    WarriorClass::Oracle => (75, 60, 120),      // Knowledge specialist
+                    WarriorClass::Validator => (80, 80, 60),    // Balanced
+                };
             
-            msg!("⚠️ Stats generation failed: {:?}, using class-appropriate defaults", e);
-            warrior.base_attack = default_attack;
-            warrior.base_defense = default_defense;
-            warrior.base_knowledge = default_knowledge;
+                msg!("⚠️ Stats generation failed: {:?}, using class-appropriate defaults", e);
+                warrior.base_attack = default_attack;
+                warrior.base_defense = default_defense;
+                warrior.base_knowledge = default_knowledge;
+            }
         }
-    }
 
-    match temp_img_rand(player_key, dna, client_seed, class) {
-        Ok((rarity, index, url)) => {
-            warrior.image_rarity = rarity;
-            warrior.image_index = index; 
-            warrior.image_uri = url;
+        match temp_img_rand(player_key, dna, client_seed, class) {
+            Ok((rarity, index, url)) => {
+                warrior.image_rarity = rarity;
+                warrior.image_index = index; 
+                warrior.image_uri = url;
             
-            msg!("🎨 Image generated successfully: {} {} #{}", 
-                rarity.to_string(), 
-                class.to_string(), 
-                index
-            );
-        },
-        Err(e) => {
-            // Fallback to default values if image generation fails
-            msg!("⚠️ Image generation failed: {:?}, using defaults", e);
-            warrior.image_rarity = ImageRarity::Common;
-            warrior.image_index = 1;
-            warrior.image_uri = format!(
-                "{}/{}/c1.png", 
-                IPFS_GATEWAY,
-                get_class_folder_hash(class)
-            );
+                msg!("🎨 Image generated successfully: {} {} #{}", 
+                    rarity.to_string(), 
+                    class.to_string(), 
+                    index
+                );
+            },
+            Err(e) => {
+                // Fallback to default values if image generation fails
+                msg!("⚠️ Image generation failed: {:?}, using defaults", e);
+                warrior.image_rarity = ImageRarity::Common;
+                warrior.image_index = 1;
+                warrior.image_uri = format!(
+                    "{}/{}/c1.png", 
+                    IPFS_GATEWAY,
+                    get_class_folder_hash(class)
+                );
+            }
         }
+
+        warrior.stats_pending = false;
+        emit!(WarriorStatsGenerated {
+            warrior: warrior_key,
+            attack: warrior.base_attack,
+            defense: warrior.base_defense,
+            knowledge: warrior.base_knowledge,
+            image_rarity: warrior.image_rarity,
+            image_index: warrior.image_index,
+        });
+        msg!("✅ Warrior '{}' ({:?}) created successfully! ATK: {}, DEF: {}, KNOW: {}, HP: {}/{}", 
+             warrior.name,
+             warrior.warrior_class,
+             warrior.base_attack, 
+             warrior.base_defense, 
+             warrior.base_knowledge,
+             warrior.current_hp,
+             warrior.max_hp
+        );
+    }
+
+    // Stats and image stay pending until the VRF oracle calls back
+    #[cfg(not(feature = "deterministic-stats"))]
+    {
+        warrior.stats_pending = true;
+        warrior.image_rarity = ImageRarity::Common;
+        warrior.image_index = 0;
+        warrior.image_uri = String::new();
+
+        msg!("Warrior '{}' created with 100 HP, requesting VRF stat generation...", warrior.name);
+
+        let ix = create_request_randomness_ix(RequestRandomnessParams { 
+            payer: ctx.accounts.player.key(), 
+            oracle_queue: ctx.accounts.oracle_queue.key(), 
+            callback_program_id: ID, 
+            callback_discriminator: instruction::CallbackWarriorStats::DISCRIMINATOR.to_vec(),
+            caller_seed: [client_seed; 32], 
+            accounts_metas: Some(vec![SerializableAccountMeta{
+                pubkey: warrior_key,
+                is_signer: false,
+                is_writable: true
+            }]), 
+            ..Default::default()
+        });
+        ctx.accounts.invoke_signed_vrf(&ctx.accounts.player.to_account_info(), &ix)?;
     }
-    msg!("✅ Warrior '{}' ({:?}) created successfully! ATK: {}, DEF: {}, KNOW: {}, HP: {}/{}", 
-         warrior.name,
-         warrior.warrior_class,
-         warrior.base_attack, 
-         warrior.base_defense, 
-         warrior.base_knowledge,
-         warrior.current_hp,
-         warrior.max_hp
-    );
     
     Ok(())
 }
 
 
+// restore a warrior to full HP
+pub fn heal_warrior(ctx: Context<HealWarrior>) -> Result<()> {
+    ctx.accounts.heal_warrior()
+}
+
+// mint the warrior as an NFT once its stats and image are final
+pub fn mint_warrior_nft(ctx: Context<MintWarriorNft>) -> Result<()> {
+    ctx.accounts.mint_warrior_nft(&ctx.bumps)
+}
+
+// hand a warrior to another player outside of battle
+pub fn transfer_warrior(ctx: Context<TransferWarrior>, new_owner: Pubkey) -> Result<()> {
+    ctx.accounts.transfer_warrior(new_owner, &ctx.bumps)
+}
+
+// close a warrior for good, leaving a memorial entry
+pub fn retire_warrior(ctx: Context<RetireWarrior>) -> Result<()> {
+    ctx.accounts.retire_warrior(&ctx.bumps)
+}
+
+// claim a new name for a warrior, paid and rate limited
+pub fn rename_warrior(ctx: Context<RenameWarrior>, new_name: String) -> Result<()> {
+    ctx.accounts.rename_warrior(new_name, &ctx.bumps)
+}
+
+// opt-in battle log kept when rooms are closed
+pub fn init_warrior_history(ctx: Context<InitWarriorHistory>) -> Result<()> {
+    ctx.accounts.init_warrior_history(&ctx.bumps)
+}
+
+
 //create battle room
+#[allow(clippy::too_many_arguments)]
 pub fn create_battle_room(
   ctx: Context<CreateBattleRoom>,
   room_id: [u8; 32],
-  warrior_name: String,
   selected_concepts: [u8; 5],
-  selected_topics: [u8; 10],
-  selected_questions: [u16; 10],
-  correct_answers: [bool; 10],
+  selected_topics: Vec<u8>,
+  selected_questions: Vec<u16>,
+  answer_options: Vec<u8>,
+  answer_commitments: Vec<[u8; 32]>,
+  wager_amount: u64,
 ) -> Result<()> {
     msg!("🏛️ Creating battle room with ID: {:?}", room_id);
-    msg!("⚔️ Warrior: {}", warrior_name);
+    msg!("⚔️ Warrior: {}", ctx.accounts.warrior_a.name);
     msg!("📚 Concepts: {:?}", selected_concepts);
     msg!("📖 Topics: {:?}", selected_topics);
-    msg!("❓ Questions: {:?}", selected_questions);
-    msg!("✅ Answers: {:?}", correct_answers);
+    msg!("❓ Questions ({}): {:?}", selected_questions.len(), selected_questions);
+    msg!("🔒 Answer key committed by question authority");
     
     ctx.accounts.create_battle_room(
         room_id, 
-        warrior_name, 
         selected_concepts,
         selected_topics, 
         selected_questions,  
-        correct_answers, 
+        answer_options,
+        answer_commitments, 
+        wager_amount,
         &ctx.bumps
     )
 }
 
 
+// question authority fills in the content of a matched room
+pub fn assign_room_questions(
+    ctx: Context<AssignRoomQuestions>,
+    room_id: [u8; 32],
+    selected_concepts: [u8; 5],
+    selected_topics: Vec<u8>,
+    selected_questions: Vec<u16>,
+    answer_options: Vec<u8>,
+    answer_commitments: Vec<[u8; 32]>,
+) -> Result<()> {
+    ctx.accounts.assign_room_questions(
+        room_id,
+        selected_concepts,
+        selected_topics,
+        selected_questions,
+        answer_options,
+        answer_commitments,
+    )
+}
+
+// prove one selected question against the question bank
+pub fn verify_room_question(
+    ctx: Context<VerifyRoomQuestion>,
+    room_id: [u8; 32],
+    question_index: u8,
+    concept: u8,
+    answer_digest: [u8; 32],
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    ctx.accounts.verify_room_question(room_id, question_index, concept, answer_digest, proof)
+}
+
 // join battle room 
 pub fn join_battle_room(
     ctx: Context<JoinBattleRoom>,
     room_id: [u8; 32],
-    warrior_name: String,
 ) -> Result<()> {
-ctx.accounts.join_battle_room(room_id, warrior_name)
+ctx.accounts.join_battle_room(room_id)
+}
+
+// matchmaking
+pub fn initialize_match_queue(ctx: Context<InitializeMatchQueue>) -> Result<()> {
+    ctx.accounts.initialize_match_queue(&ctx.bumps)
+}
+
+pub fn enqueue_warrior(ctx: Context<EnqueueWarrior>) -> Result<()> {
+    ctx.accounts.enqueue_warrior()
+}
+
+pub fn dequeue_warrior(ctx: Context<DequeueWarrior>) -> Result<()> {
+    ctx.accounts.dequeue_warrior()
+}
+
+// crank, pair two queued warriors into a new room
+pub fn match_players(
+    ctx: Context<MatchPlayers>,
+    room_id: [u8; 32],
+) -> Result<()> {
+    ctx.accounts.match_players(room_id, &ctx.bumps)
 }
 
 //signal ready
 pub fn signal_ready(
     ctx: Context<SignalReady>,
     room_id: [u8; 32],
-    warrior_name: String,
 ) -> Result<()> {
-ctx.accounts.signal_ready(room_id, warrior_name)
+ctx.accounts.signal_ready(room_id)
 }
 
 //delegate to rollup
 pub fn delegate_battle(
     ctx: Context<DelegateBattle>,
     room_id: [u8; 32],
-    player_a: Pubkey,
-    warrior_a_name: String,
-    player_b: Pubkey,
-    warrior_b_name: String,
 ) -> Result<()> {
-ctx.accounts.delegate_to_rollup(
-    room_id, 
-    player_a, 
-    warrior_a_name, 
-    player_b, 
-    warrior_b_name
-)
+ctx.accounts.delegate_to_rollup(room_id)
 }
 //start battle
 pub fn start_battle(
     ctx: Context<StartBattle>,
     room_id: [u8; 32],
 ) -> Result<()> {
-ctx.accounts.start_battle(room_id)
+StartBattle::start_battle(ctx, room_id)
 }
 
 //cancel battle
@@ -275,18 +472,47 @@ ctx.accounts.cancel_battle_room(room_id)
 pub fn answer_question(
     ctx: Context<AnswerQuestion>,
     room_id: [u8; 32],
-    answer: bool,
-    client_seed: u8,
+    sealed_answer: [u8; 32],
+) -> Result<()> {
+    AnswerQuestion::answer_question(ctx, room_id, sealed_answer)
+}
+
+// open the sealed answer once both players have sealed theirs
+pub fn reveal_player_answer(
+    ctx: Context<RevealPlayerAnswer>,
+    room_id: [u8; 32],
+    answer: u8,
+    salt: [u8; 32],
+) -> Result<()> {
+    ctx.accounts.reveal_player_answer(room_id, answer, salt)
+}
+
+// question authority reveals the committed answer once both players answered
+pub fn reveal_answer(
+    ctx: Context<RevealAnswer>,
+    room_id: [u8; 32],
+    question_index: u8,
+    answer: u8,
+    salt: [u8; 32],
+    answer_salt: [u8; 32],
 ) -> Result<()> {
-    ctx.accounts.answer_question(room_id, answer, client_seed)
+    RevealAnswer::reveal_answer(ctx, room_id, question_index, answer, salt, answer_salt)
 }
 
  
+// end a battle whose current question is past its deadline
+pub fn claim_timeout(
+    ctx: Context<ClaimTimeout>,
+    room_id: [u8; 32],
+) -> Result<()> {
+    ClaimTimeout::claim_timeout(ctx, room_id)
+}
+
 pub fn settle_battle_room(
     ctx: Context<EndBattleRoom>,
     room_id: [u8; 32],
 ) -> Result<()> {
-    ctx.accounts.end_battle_room(room_id)
+    EndBattleRoom::end_battle_room(ctx, room_id)
 }
 
 pub fn undelegate_battle_room(
@@ -296,6 +522,14 @@ pub fn undelegate_battle_room(
     ctx.accounts.undelegate_battle_room(room_id)
 }
 
+// crank for delegated rooms left idle past the timeout
+pub fn force_undelegate(
+    ctx: Context<ForceUndelegate>,
+    room_id: [u8; 32],
+) -> Result<()> {
+    ForceUndelegate::force_undelegate(ctx, room_id)
+}
+
 // cancel battle room if no one joined 
 pub fn cancel_empty_battle_room(
     ctx:Context<CancelBattleRoom>,
@@ -309,106 +543,108 @@ pub fn update_final_state(
     ctx: Context<UpdateState>,
     room_id: [u8; 32],
 ) -> Result<()> {
-    ctx.accounts.update_state(room_id)
+    UpdateState::update_state(ctx, room_id)
 }
 
-// emergency cancel battle room
-pub fn emergency_cancel_battle(
-    ctx: Context<EmergencyUndelegateAndEnd>,
+// pay a wagered pot to the winner, minus the protocol fee
+pub fn settle_wager(
+    ctx: Context<SettleWager>,
     room_id: [u8; 32],
 ) -> Result<()> {
-    ctx.accounts.emergency_undelegate_and_end(room_id)
+    ctx.accounts.settle_wager(room_id, &ctx.bumps)
 }
 
+// return stakes from a cancelled room
+pub fn refund_wager(
+    ctx: Context<RefundWager>,
+    room_id: [u8; 32],
+) -> Result<()> {
+    ctx.accounts.refund_wager(room_id, &ctx.bumps)
 }
 
+// close a cancelled or settled room, rent goes back to the creator
+pub fn close_battle_room(
+    ctx: Context<CloseBattleRoom>,
+    room_id: [u8; 32],
+) -> Result<()> {
+    ctx.accounts.close_battle_room(room_id)
+}
 
+// emergency cancel battle room
+pub fn emergency_cancel_battle(
+    ctx: Context<EmergencyUndelegateAndEnd>,
+    room_id: [u8; 32],
+) -> Result<()> {
+    EmergencyUndelegateAndEnd::emergency_undelegate_and_end(ctx, room_id)
+}
 
-
-
-    // keep in case vrf comes back up
-// pub fn callback_warrior_stats(
-//   ctx: Context<CallbackWarriorStats>,
-//   randomness: [u8; 32],
-// ) -> Result<()> {
-//     msg!("🎲 VRF CALLBACK TRIGGERED! Starting warrior stat generation...");
-    
-//     let warrior = &mut ctx.accounts.warrior;
-//     let class = warrior.warrior_class;
+// VRF oracle callback, finalizes stats and image for a pending warrior
+pub fn callback_warrior_stats(
+  ctx: Context<CallbackWarriorStats>,
+  randomness: [u8; 32],
+) -> Result<()> {
+    msg!("🎲 VRF CALLBACK TRIGGERED! Starting warrior stat generation...");
     
-//     msg!("Generating random combat stats for warrior: {} (class: {:?})", warrior.name, class);
+    let warrior = &mut ctx.accounts.warrior;
+    let class = warrior.warrior_class;
     
-//     // Generate stats based on class specialization with FIXED ranges
-//     let (attack, defense, knowledge) = match class {
-//         WarriorClass::Validator => {
-//             // Balanced fighter - good at everything (60-99 range)
-//             let attack = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness, 60, 100);   
-//             let defense = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness, 60, 100);  
-//             let knowledge = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness, 40, 80); 
-//             (attack, defense, knowledge)
-//         },
-//         WarriorClass::Oracle => {
-//             // Knowledge specialist - high knowledge, moderate combat
-//             let attack = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness, 50, 100);   
-//             let defense = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness, 40, 80);  
-//             let knowledge = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness, 100, 141); // 100-140
-//             (attack, defense, knowledge)
-//         },
-//         WarriorClass::Guardian => {
-//             // Tank - high defense, lower attack, good knowledge
-//             let attack = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness, 40, 61);    // 40-60
-//             let defense = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness, 100, 141); // 100-140
-//             let knowledge = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness, 50, 100); 
-//             (attack, defense, knowledge)
-//         },
-//         WarriorClass::Daemon => {
-//             // Glass cannon - high attack, lower defense, good knowledge  
-//             let attack = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness, 100, 141);  // 100-140
-//             let defense = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness, 40, 61);   // 40-60
-//             let knowledge = ephemeral_vrf_sdk::rnd::random_u8_with_range(&randomness, 50, 100); 
-//             (attack, defense, knowledge)
-//         },
-//     };
-
-//     // Set the generated combat stats
-//     warrior.base_attack = attack as u16;
-//     warrior.base_defense = defense as u16;
-//     warrior.base_knowledge = knowledge as u16;
+    msg!("Generating random combat stats for warrior: {} (class: {:?})", warrior.name, class);
     
+    let (attack, defense, knowledge) = generate_warrior_stats(&randomness, class);
+    warrior.base_attack = attack;
+    warrior.base_defense = defense;
+    warrior.base_knowledge = knowledge;
+
+    match generate_warrior_image(&randomness, class) {
+        Ok((rarity, index, url)) => {
+            warrior.image_rarity = rarity;
+            warrior.image_index = index;
+            warrior.image_uri = url;
+        },
+        Err(e) => {
+            msg!("⚠️ Image generation failed: {:?}, using defaults", e);
+            warrior.image_rarity = ImageRarity::Common;
+            warrior.image_index = 1;
+            warrior.image_uri = format!(
+                "{}/{}/c1.png", 
+                IPFS_GATEWAY,
+                get_class_folder_hash(class)
+            );
+        }
+    }
+
+    warrior.stats_pending = false;
+    emit!(WarriorStatsGenerated {
+        warrior: warrior.key(),
+        attack,
+        defense,
+        knowledge,
+        image_rarity: warrior.image_rarity,
+        image_index: warrior.image_index,
+    });
    
-//     msg!(
-//         "✅ VRF CALLBACK COMPLETED! Warrior '{}' combat stats finalized - ATK: {}, DEF: {}, KNOW: {}, HP: {}/{}",
-//         warrior.name,
-//         warrior.base_attack,
-//         warrior.base_defense,
-//         warrior.base_knowledge,
-//         warrior.current_hp,
-//         warrior.max_hp
-//     );
-    
-//     msg!(
-//         "🎯 Class: {} | Image: {} #{} | URI: {}",
-//         class.to_string(),
-//         warrior.image_rarity.to_string(),
-//         warrior.image_index,
-//         warrior.image_uri
-//     );
+    msg!(
+        "✅ VRF CALLBACK COMPLETED! Warrior '{}' combat stats finalized - ATK: {}, DEF: {}, KNOW: {}, HP: {}/{}",
+        warrior.name,
+        warrior.base_attack,
+        warrior.base_defense,
+        warrior.base_knowledge,
+        warrior.current_hp,
+        warrior.max_hp
+    );
     
-//     msg!(
-//         "⚔️ Combat Profile: ATK {} | DEF {} | KNOW {} | Strategy: {}",
-//         warrior.base_attack,
-//         warrior.base_defense, 
-//         warrior.base_knowledge,
-//         match class {
-//             WarriorClass::Validator => "Balanced fighter",
-//             WarriorClass::Oracle => "Knowledge specialist", 
-//             WarriorClass::Guardian => "Tank defender",
-//             WarriorClass::Daemon => "Glass cannon",
-//         }
-//     );
+    msg!(
+        "🎯 Class: {} | Image: {} #{} | URI: {}",
+        class.to_string(),
+        warrior.image_rarity.to_string(),
+        warrior.image_index,
+        warrior.image_uri
+    );
     
-//     Ok(())
-// }
+    Ok(())
+}
+
+}
 
 
 
@@ -420,23 +656,33 @@ pub fn emergency_cancel_battle(
 pub struct CreateWarrior<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = ANCHOR_DISCRIMINATOR + UserProfile::INIT_SPACE,
+        seeds = [USER_PROFILE, player.key().as_ref()],
+        bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
     #[account(
         init,
         payer = player,
         space = ANCHOR_DISCRIMINATOR + UndeadWarrior::INIT_SPACE,
-        seeds = [UNDEAD_WARRIOR, player.key().as_ref(), name.as_bytes()],
+        seeds = [UNDEAD_WARRIOR, player.key().as_ref(), user_profile.next_warrior_index.to_le_bytes().as_ref()],
         bump
     )]
     pub warrior: Account<'info, UndeadWarrior>,
-    
+
     #[account(
-        init_if_needed,
+        init,
         payer = player,
-        space = ANCHOR_DISCRIMINATOR + UserProfile::INIT_SPACE,
-        seeds = [USER_PROFILE, player.key().as_ref()],
+        space = ANCHOR_DISCRIMINATOR + NameRegistry::INIT_SPACE,
+        seeds = [NAME_REGISTRY, &name_registry_seed(&name)],
         bump
     )]
-    pub user_profile: Account<'info, UserProfile>,
+    pub name_registry: Account<'info, NameRegistry>,
     
     #[account(
         init_if_needed,
@@ -447,6 +693,13 @@ pub struct CreateWarrior<'info> {
     )]
     pub user_achievements: Account<'info, UserAchievements>,
 
+    #[account(
+        seeds = [CONFIG],
+        bump = config.bump,
+        constraint = !config.is_paused @ RustUndeadError::GamePaused,
+    )]
+    pub config: Account<'info, Config>,
+
 		 /// CHECK: The oracle queue
 		 #[account(
 			mut,
@@ -458,14 +711,17 @@ pub struct CreateWarrior<'info> {
 }
 
 
-// #[derive(Accounts)]
-// pub struct CallbackWarriorStats<'info> {
-// 	/// This check ensure that the vrf_program_identity (which is a PDA) is a singer
-//   /// enforcing the callback is executed by the VRF program through CPI
-// #[account(
-//     address = ephemeral_vrf_sdk::consts::VRF_PROGRAM_IDENTITY
-// )]
-// pub vrf_program_identity: Signer<'info>,
-// #[account(mut)]
-// pub warrior : Account<'info,UndeadWarrior>,
-// }
+#[derive(Accounts)]
+pub struct CallbackWarriorStats<'info> {
+    /// This check ensure that the vrf_program_identity (which is a PDA) is a singer
+    /// enforcing the callback is executed by the VRF program through CPI
+    #[account(
+        address = ephemeral_vrf_sdk::consts::VRF_PROGRAM_IDENTITY
+    )]
+    pub vrf_program_identity: Signer<'info>,
+    #[account(
+        mut,
+        constraint = warrior.stats_pending @ RustUndeadError::InvalidWarriorState,
+    )]
+    pub warrior: Account<'info, UndeadWarrior>,
+}
@@ -1,35 +1,53 @@
 use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::error::RustUndeadError;
 
 #[account]
 #[derive(InitSpace)]
 pub struct BattleRoom {
     pub room_id: [u8; 32],                    // Unique room identifier
     pub created_at: i64,                      // Room creation timestamp
+    pub rent_payer: Pubkey,                   // Gets the rent back when the room is closed
     pub player_a: Pubkey,                     // Room creator
     pub player_b: Option<Pubkey>,             // Room joiner (None until joined)
     pub warrior_a: Pubkey,                    // Player A's warrior
     pub warrior_b: Option<Pubkey>,            // Player B's warrior (None until joined)
     pub selected_concepts: [u8; 5],           // VRF-selected concept IDs [2, 5, 7, 9, 10]
-    pub selected_topics : [u8; 10],
-    pub selected_questions: [u16; 10],        // VRF-selected question IDs [23, 37,68, ...]
-    pub correct_answers: [bool; 10],          // Correct answers for questions
+    pub question_count: u8,                   // 5, 10 or 15, 0 until questions are set
+    pub selected_topics : [u8; MAX_QUESTIONS],
+    pub selected_questions: [u16; MAX_QUESTIONS], // VRF-selected question IDs [23, 37,68, ...]
+    pub answer_options: [u8; MAX_QUESTIONS],  // Number of choices per question, 2 for true/false
+    pub questions_verified: u16,              // Bitmask of questions proven against the question bank
+    pub answer_digests: [[u8; 32]; MAX_QUESTIONS], // Bank answer digest proven per question, checked on reveal
+    pub answer_commitments: [[u8; 32]; MAX_QUESTIONS], // hash(room_id, index, choice, salt) per question
+    pub revealed_answers: [Option<u8>; MAX_QUESTIONS], // Choices revealed by the question authority
     pub state: BattleState,                   // Current battle phase
+    pub settlement: SettlementStatus,         // How far post-battle rewards have been applied
     pub player_a_ready: bool,                 // Player A ready for battle
     pub player_b_ready: bool,                 // Player B ready for battle
-    pub current_question: u8,                 // Current question index (0-9)
-    pub player_a_answers: [Option<bool>; 10], // Player A's answers
-    pub player_b_answers: [Option<bool>; 10], // Player B's answers
+    pub current_question: u8,                 // Current question index (0 to question_count - 1)
+    pub player_a_answers: [Option<u8>; MAX_QUESTIONS], // Player A's revealed choices
+    pub player_b_answers: [Option<u8>; MAX_QUESTIONS], // Player B's revealed choices
+    pub player_a_sealed_answer: Option<[u8; 32]>, // Player A's hash(answer, salt) for the current question
+    pub player_b_sealed_answer: Option<[u8; 32]>, // Player B's hash(answer, salt) for the current question
+    pub damage_entropy: [u8; 32],             // Running mix of both players' revealed salts
     pub player_a_correct: u8,                 // Player A's correct count
     pub player_b_correct: u8,                 // Player B's correct count
     pub winner: Option<Pubkey>,               // Battle winner
     pub battle_duration: u32,                 // Battle time in seconds
     pub bump: u8,  
     pub battle_start_time: i64,                    
+    pub answer_timeout: i64,                  // Seconds each question stays open, copied from Config at start
+    pub question_deadline: i64,               // After this, claim_timeout can end the battle
+    pub last_activity_at: i64,                // Last join/ready/start/answer, for force_undelegate and cancel
+    pub wager_amount: u64,                    // Stake per player, 0 for an XP only battle
+    pub wager_mint: Option<Pubkey>,           // None for SOL, otherwise the SPL mint staked
+    pub wager_status: WagerStatus,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum BattleState {
-    Created,              // Room created, selecting concepts
+    Created,              // Room created, verifying questions against the bank
     Joined,              // Player B joined, selecting questions
     QuestionsSelected,    // Questions ready, study phase
     ReadyForDelegation,   // Both ready, can delegate
@@ -42,6 +60,29 @@ impl Space for BattleState {
     const INIT_SPACE: usize = 1;
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SettlementStatus {
+    Unsettled,            // Battle not settled yet
+    RewardsApplied,       // Warrior XP and records applied on the rollup
+    Settled,              // Profiles, achievements and leaderboard updated on base layer
+}
+
+impl Space for SettlementStatus {
+    const INIT_SPACE: usize = 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WagerStatus {
+    None,                 // No stakes on this room
+    Escrowed,             // Stakes held by the room escrow
+    Paid,                 // Pot paid out to the winner
+    Refunded,             // Stakes returned after a cancellation
+}
+
+impl Space for WagerStatus {
+    const INIT_SPACE: usize = 1;
+}
+
 // create fxns that can be used for constraints and validations
 impl BattleRoom {
     // === PLAYER VALIDATION ===
@@ -67,6 +108,78 @@ impl BattleRoom {
         matches!(self.state, BattleState::Completed)
     }
     
+    // === QUESTION VERIFICATION ===
+    pub fn is_question_verified(&self, question_idx: u8) -> bool {
+        self.questions_verified & (1 << question_idx) != 0
+    }
+
+    // cancelled before the battle, or ended by the admin with no winner
+    pub fn is_no_contest(&self) -> bool {
+        self.state == BattleState::Cancelled
+            || (self.state == BattleState::Completed && self.winner.is_none())
+    }
+
+    // matched rooms are opened without content until the question authority assigns it
+    pub fn has_answer_key(&self) -> bool {
+        self.question_count > 0
+    }
+
+    pub fn all_questions_verified(&self) -> bool {
+        self.has_answer_key() && self.questions_verified == (1 << self.question_count) - 1
+    }
+
+    // === QUESTION SETUP ===
+    // Every list holds one entry per question, and its length picks the question count
+    pub fn set_questions(
+        &mut self,
+        selected_topics: &[u8],
+        selected_questions: &[u16],
+        answer_options: &[u8],
+        answer_commitments: &[[u8; 32]],
+    ) -> Result<()> {
+        let count = selected_questions.len();
+        require!(
+            count <= MAX_QUESTIONS && ALLOWED_QUESTION_COUNTS.contains(&(count as u8)),
+            RustUndeadError::InvalidQuestionCount
+        );
+        require!(
+            selected_topics.len() == count
+                && answer_options.len() == count
+                && answer_commitments.len() == count,
+            RustUndeadError::InvalidQuestionCount
+        );
+        require!(
+            answer_options.iter().all(|options| (MIN_ANSWER_OPTIONS..=MAX_ANSWER_OPTIONS).contains(options)),
+            RustUndeadError::InvalidAnswerOptions
+        );
+        require!(
+            answer_commitments.iter().all(|c| *c != [0u8; 32]),
+            RustUndeadError::EmptyAnswerCommitment
+        );
+
+        self.question_count = count as u8;
+        self.selected_topics[..count].copy_from_slice(selected_topics);
+        self.selected_questions[..count].copy_from_slice(selected_questions);
+        self.answer_options[..count].copy_from_slice(answer_options);
+        self.answer_commitments[..count].copy_from_slice(answer_commitments);
+        Ok(())
+    }
+
+    pub fn is_valid_choice(&self, question_idx: u8, choice: u8) -> bool {
+        question_idx < self.question_count && choice < self.answer_options[question_idx as usize]
+    }
+
+    // === TIMEOUT ===
+    // A player is done with the current question once sealed, and once
+    // revealed after both have sealed
+    pub fn has_player_acted(&self, player: &Pubkey) -> bool {
+        if self.both_players_sealed() {
+            self.has_player_answered(player, self.current_question)
+        } else {
+            self.has_player_sealed(player)
+        }
+    }
+
     // === PROGRESS ===
     pub fn get_scores(&self) -> (u8, u8) {
         (self.player_a_correct, self.player_b_correct)
@@ -74,7 +187,7 @@ impl BattleRoom {
     
     pub fn has_player_answered(&self, player: &Pubkey, question_idx: u8) -> bool {
         let idx = question_idx as usize;
-        if idx >= 10 { return false; }
+        if idx >= MAX_QUESTIONS { return false; }
         
         if self.player_a == *player {
             self.player_a_answers[idx].is_some()
@@ -84,4 +197,25 @@ impl BattleRoom {
             false
         }
     }
+
+    pub fn has_player_sealed(&self, player: &Pubkey) -> bool {
+        if self.player_a == *player {
+            self.player_a_sealed_answer.is_some()
+        } else if self.player_b == Some(*player) {
+            self.player_b_sealed_answer.is_some()
+        } else {
+            false
+        }
+    }
+
+    pub fn both_players_sealed(&self) -> bool {
+        self.player_a_sealed_answer.is_some() && self.player_b_sealed_answer.is_some()
+    }
+
+    pub fn both_players_answered(&self, question_idx: u8) -> bool {
+        let idx = question_idx as usize;
+        if idx >= MAX_QUESTIONS { return false; }
+
+        self.player_a_answers[idx].is_some() && self.player_b_answers[idx].is_some()
+    }
 }
\ No newline at end of file
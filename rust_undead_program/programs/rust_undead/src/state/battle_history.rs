@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::constants::BATTLE_HISTORY_LEN;
+
+// rolling log of a warrior's last few battles, filled in when finished
+// battle rooms are closed so match results outlive the room accounts
+#[account]
+#[derive(InitSpace)]
+pub struct WarriorHistory {
+    pub warrior: Pubkey,
+    #[max_len(BATTLE_HISTORY_LEN)]
+    pub records: Vec<BattleRecord>,
+    pub next_slot: u8,          // Oldest record, overwritten once the log is full
+    pub total_recorded: u32,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct BattleRecord {
+    pub room_id: [u8; 32],
+    pub opponent_warrior: Option<Pubkey>,
+    pub outcome: BattleOutcome,
+    pub correct: u8,
+    pub opponent_correct: u8,
+    pub started_at: i64,
+    pub duration: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BattleOutcome {
+    Won,
+    Lost,
+    Cancelled,
+}
+
+impl Space for BattleOutcome {
+    const INIT_SPACE: usize = 1;
+}
+
+impl WarriorHistory {
+    pub fn push_record(&mut self, record: BattleRecord) {
+        if self.records.len() < BATTLE_HISTORY_LEN {
+            self.records.push(record);
+        } else {
+            self.records[self.next_slot as usize] = record;
+        }
+        self.next_slot = ((self.next_slot as usize + 1) % BATTLE_HISTORY_LEN) as u8;
+        self.total_recorded = self.total_recorded.saturating_add(1);
+    }
+}
@@ -1,12 +1,29 @@
 use anchor_lang::prelude::*;
+use crate::constants::DEFAULT_RATING;
 
 #[account]
 #[derive(InitSpace)]
 pub struct Config {
 	pub admin: Pubkey,
+	pub pending_admin: Option<Pubkey>, // Set by propose_admin, becomes admin on accept_admin
+	pub question_authority: Pubkey, // Commits and reveals battle answer keys
 	pub total_warriors: u64,
-    pub cooldown_time: u64,
+    pub cooldown_time: u64,                 // Base post-battle cooldown in seconds
+	pub winner_cooldown_pct: u8,            // Share of cooldown_time served by the winner
+	pub loser_cooldown_pct: u8,             // Share of cooldown_time served by the loser
+	pub hp_cooldown_penalty_pct: u8,        // Extra cooldown at 0 HP, scaled by HP lost
+	pub hp_regen_per_hour: u16,             // Passive HP regained per hour since last battle/heal
+	pub heal_cost_lamports: u64,            // Price of an instant heal while on cooldown
+	pub rename_fee_lamports: u64,           // Price of renaming a warrior
+	pub rename_cooldown: i64,               // Seconds between renames of the same warrior
+	pub treasury: Pubkey,                   // Receives heal payments and wager fees
+	pub wager_fee_bps: u16,                 // Protocol cut of a wagered pot, in basis points
+	pub max_level: u16,                     // Level cap for warriors
+	pub answer_timeout: i64,                // Seconds a player has to act on each question
+	pub idle_timeout: i64,                  // Idle seconds before a delegated room can be force undelegated
 	pub total_battles: u32,
+	pub current_season: u32,                // Seasonal leaderboard currently collecting points
+	pub season_started_at: i64,
 //track battle rooms 
 	pub is_paused: bool,
 	pub created_at: i64,
@@ -18,16 +35,50 @@ pub struct Config {
 pub struct UserProfile {
     pub owner: Pubkey,
     pub warriors_created: u32, 
+    pub next_warrior_index: u32,      // Seeds this player's next warrior, never reused
 	// pub story_level: u32,     
     pub total_battles_won: u32,
 	pub total_battles_lost: u32,    
     pub total_battles_fought: u32,    
     pub join_date: i64,
     pub total_points:u32,  
+    pub rating: u32,                  // Elo skill rating across all warriors
+    pub rated_battles: u32,
+    pub season: u32,                  // Season season_points belong to
+    pub season_points: u32,           // Points earned in that season only
     pub bump: u8,                     
 }
 
 
+impl UserProfile {
+    // blank profile for a player's first warrior
+    pub fn initialize(&mut self, owner: Pubkey, join_date: i64, season: u32, bump: u8) {
+        *self = UserProfile {
+            owner,
+            warriors_created: 0,
+            next_warrior_index: 0,
+            total_battles_won: 0,
+            total_battles_lost: 0,
+            total_battles_fought: 0,
+            join_date,
+            total_points: 0,
+            rating: DEFAULT_RATING,
+            rated_battles: 0,
+            season,
+            season_points: 0,
+            bump,
+        };
+    }
+
+    // season points start over the first time a player is credited in a new season
+    pub fn sync_season(&mut self, current_season: u32) {
+        if self.season != current_season {
+            self.season = current_season;
+            self.season_points = 0;
+        }
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct UserAchievements {
@@ -40,7 +91,7 @@ pub struct UserAchievements {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum AchievementLevel {
     None,
     Bronze,
@@ -51,6 +102,20 @@ pub enum AchievementLevel {
 }
 
 
+impl UserAchievements {
+    pub fn initialize(&mut self, owner: Pubkey, first_warrior_date: i64, bump: u8) {
+        *self = UserAchievements {
+            owner,
+            overall_achievements: AchievementLevel::None,
+            warrior_achivement: AchievementLevel::None,
+            winner_achievement: AchievementLevel::None,
+            battle_achievement: AchievementLevel::None,
+            first_warrior_date,
+            bump,
+        };
+    }
+}
+
 impl Space for AchievementLevel {
 	const INIT_SPACE: usize = 1;
  }
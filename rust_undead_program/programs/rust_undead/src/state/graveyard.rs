@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::constants::GRAVEYARD_LEN;
+use crate::state::WarriorClass;
+
+// a player's memorial for retired warriors. The warrior accounts are closed,
+// so this keeps the last few around by name and record
+#[account]
+#[derive(InitSpace)]
+pub struct Graveyard {
+    pub owner: Pubkey,
+    #[max_len(GRAVEYARD_LEN)]
+    pub fallen: Vec<FallenWarrior>,
+    pub next_slot: u8,          // Oldest entry, overwritten once the memorial is full
+    pub total_fallen: u32,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct FallenWarrior {
+    pub warrior: Pubkey,
+    #[max_len(32)]
+    pub name: String,
+    pub warrior_class: WarriorClass,
+    pub level: u16,
+    pub battles_won: u32,
+    pub battles_lost: u32,
+    pub experience_points: u64,
+    pub rating: u32,
+    pub created_at: i64,
+    pub retired_at: i64,
+}
+
+impl Graveyard {
+    pub fn bury(&mut self, entry: FallenWarrior) {
+        if self.fallen.len() < GRAVEYARD_LEN {
+            self.fallen.push(entry);
+        } else {
+            self.fallen[self.next_slot as usize] = entry;
+        }
+        self.next_slot = ((self.next_slot as usize + 1) % GRAVEYARD_LEN) as u8;
+        self.total_fallen = self.total_fallen.saturating_add(1);
+    }
+}
@@ -1,146 +1,354 @@
 use anchor_lang::prelude::*;
+use crate::constants::{ANCHOR_DISCRIMINATOR, LEADERBOARD_CAPACITY, LEADERBOARD_INDEX_SIZE};
+use crate::error::RustUndeadError;
 
-#[account]
-#[derive(InitSpace)]
-pub struct Leaderboard { 
-    pub top_players: [Pubkey; 20],
-    pub top_scores: [u32; 20], 
+// zero-copy board for up to LEADERBOARD_CAPACITY players. Entries never move
+// once written: `index` maps a player to their slot and `order` keeps the slots
+// sorted by score, so an update is a couple of binary searches and a memmove
+#[account(zero_copy)]
+pub struct Leaderboard {
+    pub len: u32,
+    pub archived: u8,            // Final standings of an ended season, no more updates
+    pub _padding: [u8; 3],
     pub last_updated: i64,
-    pub bump: u8,
+    pub entries: [LeaderboardEntry; LEADERBOARD_CAPACITY],
+    pub index: [u16; LEADERBOARD_INDEX_SIZE],   // Open addressing, slot + 1 or 0 when empty
+    pub order: [u16; LEADERBOARD_CAPACITY],     // Slots by score, highest first
 }
 
+#[zero_copy]
+pub struct LeaderboardEntry {
+    pub player: Pubkey,
+    pub score: u32,
+}
+
+const INDEX_MASK: usize = LEADERBOARD_INDEX_SIZE - 1;
+
 impl Leaderboard {
+    pub const SPACE: usize = ANCHOR_DISCRIMINATOR + std::mem::size_of::<Leaderboard>();
+
+    // boards are created small and grown with grow_leaderboard, they can only
+    // be loaded once fully allocated
+    pub fn is_allocated(info: &AccountInfo) -> bool {
+        info.data_len() >= Self::SPACE
+    }
+
     pub fn update_player_score(&mut self, player: Pubkey, new_score: u32) -> Result<()> {
-        // Check if player is already in leaderboard
-        let mut existing_position: Option<usize> = None;
-        for i in 0..20 {
-            if self.top_players[i] == player {
-                existing_position = Some(i);
-                break;
-            }
-        }
-        
-        match existing_position {
-            Some(pos) => {
-                // Player exists - update their score
-                self.top_scores[pos] = new_score;
-            }
-            None => {
-                // Player not in leaderboard - try to add them
-                self.add_new_player(player, new_score)?;
+        require!(self.archived == 0, RustUndeadError::SeasonArchived);
+        let len = self.len as usize;
+
+        if let Some(slot) = self.slot_of(&player) {
+            // Player exists - take them out of the order and reinsert at the new score
+            let pos = self.position_of(slot).ok_or(RustUndeadError::InvalidLeaderboard)?;
+            self.order.copy_within(pos + 1..len, pos);
+            self.entries[slot].score = new_score;
+            self.insert_sorted(slot, len - 1);
+        } else if len < LEADERBOARD_CAPACITY {
+            // Free slot at the end of the storage
+            self.entries[len] = LeaderboardEntry { player, score: new_score };
+            self.index_insert(&player, len);
+            self.insert_sorted(len, len);
+            self.len += 1;
+            msg!("Player {} added to leaderboard with score {}", player, new_score);
+        } else {
+            // Board is full, only replace the lowest score
+            let lowest = self.order[len - 1] as usize;
+            if new_score <= self.entries[lowest].score {
+                msg!("Player {} score {} not high enough for leaderboard", player, new_score);
+                return Ok(());
             }
+
+            let evicted = self.entries[lowest].player;
+            let bucket = self.find_bucket(&evicted).ok_or(RustUndeadError::InvalidLeaderboard)?;
+            self.index_remove(bucket);
+
+            self.entries[lowest] = LeaderboardEntry { player, score: new_score };
+            self.index_insert(&player, lowest);
+            self.insert_sorted(lowest, len - 1);
+            msg!("Player {} replaced {} on the leaderboard with score {}", player, evicted, new_score);
         }
 
-        // Sort leaderboard by score (descending)
-        self.sort_leaderboard();
         self.last_updated = Clock::get()?.unix_timestamp;
-        
+
         Ok(())
     }
 
-    fn add_new_player(&mut self, player: Pubkey, score: u32) -> Result<()> {
-        // Find the lowest score position or empty slot
-        let mut insert_position: Option<usize> = None;
-        
+    // 1-based rank, players on the same score share a rank
+    pub fn get_player_rank(&self, player: Pubkey) -> Option<usize> {
+        let slot = self.slot_of(&player)?;
+        Some(self.first_at_or_below(self.entries[slot].score) + 1)
+    }
 
-        for i in 0..20 {
-            if self.top_players[i] == Pubkey::default() {
-                insert_position = Some(i);
-                break;
-            }
-        }
-        
-        // If no empty slots, check if score qualifies for leaderboard
-        if insert_position.is_none() {
-            // Find the lowest score
-            let mut lowest_score_pos = 19; // Start with last position
-            for i in 0..20 {
-                if self.top_scores[i] < self.top_scores[lowest_score_pos] {
-                    lowest_score_pos = i;
-                }
-            }
-            
-            // Only add if new score is higher than lowest score
-            if score > self.top_scores[lowest_score_pos] {
-                insert_position = Some(lowest_score_pos);
+    pub fn is_top_10(&self, player: Pubkey) -> bool {
+        matches!(self.get_player_rank(player), Some(rank) if rank <= 10)
+    }
+
+    pub fn get_top(&self, count: usize) -> Vec<(Pubkey, u32)> {
+        self.order[..(self.len as usize).min(count)]
+            .iter()
+            .map(|&slot| {
+                let entry = &self.entries[slot as usize];
+                (entry.player, entry.score)
+            })
+            .collect()
+    }
+
+    pub fn get_top_10(&self) -> Vec<(Pubkey, u32)> {
+        self.get_top(10)
+    }
+
+    pub fn reset(&mut self) -> Result<()> {
+        self.len = 0;
+        self.index.fill(0);
+        self.last_updated = Clock::get()?.unix_timestamp;
+        msg!("Leaderboard reset");
+        Ok(())
+    }
+
+    // === ORDER ===
+    // first position whose score is not above `score`, i.e. where its ties start
+    fn first_at_or_below(&self, score: u32) -> usize {
+        self.order[..self.len as usize].partition_point(|&slot| self.entries[slot as usize].score > score)
+    }
+
+    // first position whose score is below `score`, i.e. just past its ties
+    fn first_below(&self, score: u32, count: usize) -> usize {
+        self.order[..count].partition_point(|&slot| self.entries[slot as usize].score >= score)
+    }
+
+    fn position_of(&self, slot: usize) -> Option<usize> {
+        let score = self.entries[slot].score;
+        let start = self.first_at_or_below(score);
+        let end = self.first_below(score, self.len as usize);
+        self.order[start..end]
+            .iter()
+            .position(|&s| s as usize == slot)
+            .map(|offset| start + offset)
+    }
+
+    // place `slot` among the first `count` sorted slots, after its ties
+    fn insert_sorted(&mut self, slot: usize, count: usize) {
+        let pos = self.first_below(self.entries[slot].score, count);
+        self.order.copy_within(pos..count, pos + 1);
+        self.order[pos] = slot as u16;
+    }
+
+    // === INDEX ===
+    fn home_bucket(player: &Pubkey) -> usize {
+        let folded = player
+            .as_ref()
+            .chunks_exact(8)
+            .fold(0u64, |acc, chunk| {
+                (acc ^ u64::from_le_bytes(chunk.try_into().unwrap())).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            });
+        (folded >> 32) as usize & INDEX_MASK
+    }
+
+    fn find_bucket(&self, player: &Pubkey) -> Option<usize> {
+        let mut bucket = Self::home_bucket(player);
+        loop {
+            match self.index[bucket] {
+                0 => return None,
+                slot if self.entries[slot as usize - 1].player == *player => return Some(bucket),
+                _ => bucket = (bucket + 1) & INDEX_MASK,
             }
         }
-        
-        // Insert the player if position found
-        if let Some(pos) = insert_position {
-            self.top_players[pos] = player;
-            self.top_scores[pos] = score;
-            msg!("Player {} added to leaderboard at position {} with score {}", player, pos + 1, score);
-        } else {
-            msg!("Player {} score {} not high enough for leaderboard", player, score);
-        }
-        
-        Ok(())
     }
 
-    fn sort_leaderboard(&mut self) {
-       
-        let mut paired: Vec<(Pubkey, u32)> = Vec::new();
-        
-        for i in 0..20 {
-            paired.push((self.top_players[i], self.top_scores[i]));
+    fn slot_of(&self, player: &Pubkey) -> Option<usize> {
+        self.find_bucket(player).map(|bucket| self.index[bucket] as usize - 1)
+    }
+
+    fn index_insert(&mut self, player: &Pubkey, slot: usize) {
+        let mut bucket = Self::home_bucket(player);
+        while self.index[bucket] != 0 {
+            bucket = (bucket + 1) & INDEX_MASK;
         }
-        
-        paired.sort_by(|a, b| {
-            match (a.0 == Pubkey::default(), b.0 == Pubkey::default()) {
-                (true, false) => std::cmp::Ordering::Greater,
-                (false, true) => std::cmp::Ordering::Less,
-                (true, true) => std::cmp::Ordering::Equal,     
-                (false, false) => b.1.cmp(&a.1),            
+        self.index[bucket] = slot as u16 + 1;
+    }
+
+    // backward shift deletion, keeps every probe chain unbroken without tombstones
+    fn index_remove(&mut self, bucket: usize) {
+        let mut hole = bucket;
+        let mut next = (hole + 1) & INDEX_MASK;
+        while self.index[next] != 0 {
+            let home = Self::home_bucket(&self.entries[self.index[next] as usize - 1].player);
+            let reachable_from_home = if hole <= next {
+                hole < home && home <= next
+            } else {
+                hole < home || home <= next
+            };
+            if !reachable_from_home {
+                self.index[hole] = self.index[next];
+                hole = next;
             }
-        });
-        
-        // Update the arrays with sorted data
-        for i in 0..20 {
-            self.top_players[i] = paired[i].0;
-            self.top_scores[i] = paired[i].1;
+            next = (next + 1) & INDEX_MASK;
         }
+        self.index[hole] = 0;
     }
+}
 
-    pub fn get_player_rank(&self, player: Pubkey) -> Option<usize> {
-        for i in 0..20 {
-            if self.top_players[i] == player {
-                return Some(i + 1); // Return 1-based rank
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::entrypoint::SUCCESS;
+    use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use bytemuck::Zeroable;
+    use std::sync::Once;
+
+    // update_player_score stamps the board with Clock::get
+    struct TestClock;
+
+    impl SyscallStubs for TestClock {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Clock) = Clock::default() };
+            SUCCESS
         }
-        None
     }
 
-    pub fn is_top_10(&self, player: Pubkey) -> bool {
-        for i in 0..10 {
-            if self.top_players[i] == player {
-                return true;
-            }
+    fn empty_board() -> Box<Leaderboard> {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(TestClock));
+        });
+        Box::new(Leaderboard::zeroed())
+    }
+
+    // players whose index probe starts at `bucket`
+    fn players_homed_at(bucket: usize, count: usize) -> Vec<Pubkey> {
+        std::iter::repeat_with(Pubkey::new_unique)
+            .filter(|player| Leaderboard::home_bucket(player) == bucket)
+            .take(count)
+            .collect()
+    }
+
+    #[test]
+    fn inserts_keep_the_board_sorted() {
+        let mut board = empty_board();
+        let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        board.update_player_score(a, 10).unwrap();
+        board.update_player_score(b, 30).unwrap();
+        board.update_player_score(c, 20).unwrap();
+
+        assert_eq!(board.len, 3);
+        assert_eq!(board.get_top(10), vec![(b, 30), (c, 20), (a, 10)]);
+        assert_eq!(board.get_player_rank(b), Some(1));
+        assert_eq!(board.get_player_rank(c), Some(2));
+        assert_eq!(board.get_player_rank(a), Some(3));
+        assert_eq!(board.get_player_rank(Pubkey::new_unique()), None);
+    }
+
+    #[test]
+    fn tied_players_share_a_rank() {
+        let mut board = empty_board();
+        let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        board.update_player_score(a, 50).unwrap();
+        board.update_player_score(b, 50).unwrap();
+        board.update_player_score(c, 40).unwrap();
+
+        assert_eq!(board.get_player_rank(a), Some(1));
+        assert_eq!(board.get_player_rank(b), Some(1));
+        assert_eq!(board.get_player_rank(c), Some(3));
+        // the earlier of two ties stays ahead
+        assert_eq!(board.get_top(2), vec![(a, 50), (b, 50)]);
+    }
+
+    #[test]
+    fn updates_move_a_player_in_place() {
+        let mut board = empty_board();
+        let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        board.update_player_score(a, 10).unwrap();
+        board.update_player_score(b, 20).unwrap();
+        board.update_player_score(c, 30).unwrap();
+
+        board.update_player_score(a, 40).unwrap();
+        assert_eq!(board.len, 3);
+        assert_eq!(board.get_top(10), vec![(a, 40), (c, 30), (b, 20)]);
+
+        board.update_player_score(c, 5).unwrap();
+        assert_eq!(board.get_top(10), vec![(a, 40), (b, 20), (c, 5)]);
+        assert_eq!(board.get_player_rank(c), Some(3));
+    }
+
+    #[test]
+    fn a_full_board_only_admits_higher_scores() {
+        let mut board = empty_board();
+        let players: Vec<Pubkey> = (0..LEADERBOARD_CAPACITY).map(|_| Pubkey::new_unique()).collect();
+        for (score, player) in players.iter().enumerate() {
+            board.update_player_score(*player, score as u32 + 1).unwrap();
         }
-        false
+        assert_eq!(board.len as usize, LEADERBOARD_CAPACITY);
+
+        let too_low = Pubkey::new_unique();
+        board.update_player_score(too_low, 1).unwrap();
+        assert_eq!(board.get_player_rank(too_low), None);
+
+        let newcomer = Pubkey::new_unique();
+        board.update_player_score(newcomer, u32::MAX).unwrap();
+        assert_eq!(board.len as usize, LEADERBOARD_CAPACITY);
+        assert_eq!(board.get_player_rank(newcomer), Some(1));
+        assert_eq!(board.get_player_rank(players[0]), None);
+        assert_eq!(board.get_player_rank(players[1]), Some(LEADERBOARD_CAPACITY));
     }
-    pub fn get_top_10(&self) -> Vec<(Pubkey, u32)> {
-        let mut top_10 = Vec::new();
-        for i in 0..10 {
-            if self.top_players[i] != Pubkey::default() {
-                top_10.push((self.top_players[i], self.top_scores[i]));
-            }
+
+    #[test]
+    fn archived_boards_refuse_updates() {
+        let mut board = empty_board();
+        board.archived = 1;
+        assert_eq!(
+            board.update_player_score(Pubkey::new_unique(), 10).unwrap_err(),
+            RustUndeadError::SeasonArchived.into()
+        );
+    }
+
+    #[test]
+    fn index_remove_shifts_colliding_entries_back() {
+        let mut board = empty_board();
+        let home = 5;
+        let players = players_homed_at(home, 3);
+        for player in &players {
+            board.update_player_score(*player, 10).unwrap();
         }
-        top_10
+        assert_eq!(board.find_bucket(&players[2]), Some(home + 2));
+
+        board.index_remove(board.find_bucket(&players[0]).unwrap());
+
+        assert_eq!(board.slot_of(&players[0]), None);
+        assert_eq!(board.find_bucket(&players[1]), Some(home));
+        assert_eq!(board.find_bucket(&players[2]), Some(home + 1));
+        assert_eq!(board.index[home + 2], 0);
     }
-    pub fn initialize(&mut self) -> Result<()> {
-        self.top_players = [Pubkey::default(); 20];
-        self.top_scores = [0u32; 20];
-        self.last_updated = Clock::get()?.unix_timestamp;
-        msg!("Leaderboard initialized");
-        Ok(())
+
+    #[test]
+    fn index_remove_shifts_across_the_wrap() {
+        let mut board = empty_board();
+        let last = players_homed_at(INDEX_MASK, 2);
+        let first = players_homed_at(0, 1)[0];
+        // last[1] wraps to bucket 0, pushing `first` to bucket 1
+        for player in [last[0], last[1], first] {
+            board.update_player_score(player, 10).unwrap();
+        }
+        assert_eq!(board.find_bucket(&first), Some(1));
+
+        board.index_remove(board.find_bucket(&last[0]).unwrap());
+
+        assert_eq!(board.slot_of(&last[0]), None);
+        assert_eq!(board.find_bucket(&last[1]), Some(INDEX_MASK));
+        assert_eq!(board.find_bucket(&first), Some(0));
+        assert_eq!(board.index[1], 0);
     }
 
-    pub fn reset(&mut self) -> Result<()> {
-        self.top_players = [Pubkey::default(); 20];
-        self.top_scores = [0u32; 20];
-        self.last_updated = Clock::get()?.unix_timestamp;
-        msg!("Leaderboard reset");
-        Ok(())
+    #[test]
+    fn index_remove_leaves_entries_at_home_in_place() {
+        let mut board = empty_board();
+        let first = players_homed_at(9, 1)[0];
+        let neighbour = players_homed_at(10, 1)[0];
+        board.update_player_score(first, 10).unwrap();
+        board.update_player_score(neighbour, 10).unwrap();
+
+        board.index_remove(board.find_bucket(&first).unwrap());
+
+        assert_eq!(board.index[9], 0);
+        assert_eq!(board.find_bucket(&neighbour), Some(10));
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::constants::MATCH_QUEUE_CAPACITY;
+
+// warriors waiting for an opponent, paired by the match_players crank
+#[account]
+#[derive(InitSpace)]
+pub struct MatchQueue {
+    #[max_len(MATCH_QUEUE_CAPACITY)]
+    pub entries: Vec<QueueEntry>,
+    pub total_matched: u64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct QueueEntry {
+    pub player: Pubkey,
+    pub warrior: Pubkey,
+    pub level: u16,
+    pub rating: u32,
+    pub provisional: bool,      // New warriors are matched on level alone
+    pub enqueued_at: i64,
+}
+
+impl MatchQueue {
+    pub fn position(&self, warrior: &Pubkey) -> Option<usize> {
+        self.entries.iter().position(|entry| entry.warrior == *warrior)
+    }
+
+    pub fn is_queued(&self, warrior: &Pubkey) -> bool {
+        self.position(warrior).is_some()
+    }
+
+    // drop entries older than the ttl, returns how many were removed
+    pub fn purge_expired(&mut self, now: i64, ttl: i64) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|entry| now - entry.enqueued_at <= ttl);
+        before - self.entries.len()
+    }
+}
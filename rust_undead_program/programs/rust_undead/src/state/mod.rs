@@ -3,9 +3,19 @@ pub mod game_state;
 pub mod warrior;
 pub mod battle;
 pub mod leaderboard;
+pub mod question_bank;
+pub mod battle_history;
+pub mod match_queue;
+pub mod token_accounts;
+pub mod graveyard;
 
 
 pub use game_state::*;
 pub use warrior::*;
 pub use battle::*;
 pub use leaderboard::*;
+pub use question_bank::*;
+pub use battle_history::*;
+pub use match_queue::*;
+pub use token_accounts::*;
+pub use graveyard::*;
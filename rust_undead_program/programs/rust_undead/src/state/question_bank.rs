@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+// registry of every question the game can serve, the full bank lives off-chain
+// and only its merkle root is stored here
+#[account]
+#[derive(InitSpace)]
+pub struct QuestionBank {
+    pub authority: Pubkey,      // Content authority allowed to publish new roots
+    pub merkle_root: [u8; 32],  // Root over hash(question_id, concept, topic, options, answer_digest)
+    pub question_count: u32,    // Number of leaves in the current tree
+    pub concept_count: u8,      // Concept IDs run from 1 to concept_count
+    pub version: u32,           // Bumped on every root update
+    pub updated_at: i64,
+    pub bump: u8,
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+use std::ops::Deref;
+
+// SPL token accounts used by wagers. anchor-spl only implements IdlBuild for its
+// token types together with token_2022, which doesn't resolve against this
+// program's solana-sdk, so the IDL impls live on these local wrappers instead.
+
+#[derive(Clone)]
+pub struct TokenAccount(token::TokenAccount);
+
+impl AccountDeserialize for TokenAccount {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        token::TokenAccount::try_deserialize_unchecked(buf).map(Self)
+    }
+}
+
+impl AccountSerialize for TokenAccount {}
+
+impl Owner for TokenAccount {
+    fn owner() -> Pubkey {
+        token::ID
+    }
+}
+
+impl Deref for TokenAccount {
+    type Target = token::TokenAccount;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Clone)]
+pub struct Mint(token::Mint);
+
+impl AccountDeserialize for Mint {
+    fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+        token::Mint::try_deserialize_unchecked(buf).map(Self)
+    }
+}
+
+impl AccountSerialize for Mint {}
+
+impl Owner for Mint {
+    fn owner() -> Pubkey {
+        token::ID
+    }
+}
+
+impl Deref for Mint {
+    type Target = token::Mint;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "idl-build")]
+mod idl_build {
+    use super::*;
+
+    impl anchor_lang::IdlBuild for TokenAccount {}
+    impl anchor_lang::Discriminator for TokenAccount {
+        const DISCRIMINATOR: &'static [u8] = &[];
+    }
+
+    impl anchor_lang::IdlBuild for Mint {}
+    impl anchor_lang::Discriminator for Mint {
+        const DISCRIMINATOR: &'static [u8] = &[];
+    }
+}
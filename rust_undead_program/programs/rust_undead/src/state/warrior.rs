@@ -6,7 +6,9 @@ use anchor_lang::prelude::*;
 pub struct UndeadWarrior {
 	#[max_len(32)]
   pub name: String,
-	pub owner: Pubkey,
+	pub owner: Pubkey,       // Current controller, follows the NFT holder once minted
+	pub creator: Pubkey,     // Seeds the warrior PDA, never changes
+	pub warrior_index: u32,  // Creator's warrior number, the other PDA seed
 	pub dna: [u8; 8],
 	pub created_at: i64,
 	pub base_attack: u16,
@@ -19,8 +21,16 @@ pub struct UndeadWarrior {
 	pub battles_lost: u32,
 	pub experience_points: u64,
 	pub level: u16,
+	pub rating: u32,         // Elo skill rating, moves with wins and losses
+	pub rated_battles: u32,  // Provisional until RATING_PROVISIONAL_BATTLES
 	pub last_battle_at: i64,  
   pub cooldown_expires_at: i64,
+	pub last_healed_at: i64, // Passive regen counts from the later of this and last_battle_at
+	pub last_renamed_at: i64,
+	pub stats_pending: bool, // Waiting on the VRF callback, cannot battle yet
+	pub nft_mint: Option<Pubkey>, // Set once the warrior is minted as an NFT
+	pub current_room: Option<[u8; 32]>, // Room the warrior is committed to until it ends
+	pub queued: bool,        // Waiting in the match queue, locked out of rooms, transfers and retirement
 	pub bump: u8,
 
 	//img fields
@@ -30,6 +40,40 @@ pub struct UndeadWarrior {
   pub image_uri: String,
 }
 
+impl UndeadWarrior {
+	// free the warrior once its room ends, unless it has since entered another
+	pub fn leave_room(&mut self, room_id: &[u8; 32]) {
+		if self.current_room == Some(*room_id) {
+			self.current_room = None;
+		}
+	}
+}
+
+// claims a warrior name across all players. Keyed by name_registry_seed, the
+// hashed lowercase name, so names are unique regardless of case and free up
+// on rename or retirement
+#[account]
+#[derive(InitSpace)]
+pub struct NameRegistry {
+	#[max_len(32)]
+	pub name: String,        // As the owner typed it
+	pub warrior: Pubkey,
+	pub claimed_at: i64,
+	pub bump: u8,
+}
+
+// warrior PDAs stay derived from the creator, so a player holding a warrior
+// they were given finds it through this record. Keyed by the warrior's address
+// rather than its name so renames leave it valid
+#[account]
+#[derive(InitSpace)]
+pub struct WarriorLookup {
+	pub owner: Pubkey,
+	pub warrior: Pubkey,
+	pub creator: Pubkey,
+	pub bump: u8,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ImageRarity {
 	Common,
@@ -1,11 +1,13 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { RustUndead } from "../target/types/rust_undead";
-import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL, ComputeBudgetProgram } from "@solana/web3.js";
+import { PublicKey, Keypair, LAMPORTS_PER_SOL, ComputeBudgetProgram } from "@solana/web3.js";
 import { expect } from "chai";
 import * as fs from "fs";
 import * as path from "path";
+import { createHash, randomBytes } from "crypto";
 import { GetCommitmentSignature } from "@magicblock-labs/ephemeral-rollups-sdk";
+import * as pda from "../app/program_client/pda";
 
 /**
  * Load keypair from JSON file
@@ -14,14 +16,14 @@ function loadKeypairFromFile(filename: string): Keypair {
   try {
     const walletDir = path.join(__dirname, "..", "test-wallet");
     const walletPath = path.join(walletDir, filename);
-    
+
     // Load existing wallet file
     const secretKeyString = fs.readFileSync(walletPath, "utf8");
     const secretKey = Uint8Array.from(JSON.parse(secretKeyString));
     const keypair = Keypair.fromSecretKey(secretKey);
     console.log(`📖 Loaded ${filename} with public key: ${keypair.publicKey.toString()}`);
     return keypair;
-    
+
   } catch (error) {
     console.error(`Failed to load keypair ${filename}:`, error);
     throw new Error(`Could not load wallet file: ${filename}`);
@@ -39,46 +41,46 @@ async function sendERTransaction(
   console.log(`🔧 [RAW] Building raw transaction for: ${description}`);
   console.log(`🔧 [RAW] Signer: ${signer.publicKey.toString()}`);
   console.log(`🔧 [RAW] Provider wallet: ${provider.wallet.publicKey.toString()}`);
-  
+
   // Build transaction WITHOUT signers first
   let tx = await methodBuilder.transaction();
-  
+
   // Configure for Ephemeral Rollup
   tx.feePayer = provider.wallet.publicKey;
   tx.recentBlockhash = (await provider.connection.getLatestBlockhash()).blockhash;
-  
+
   console.log(`🔧 [RAW] Transaction built, fee payer: ${tx.feePayer.toString()}`);
   console.log(`🔧 [RAW] Signatures before signing: ${tx.signatures?.length || 0}`);
-  
+
   // CRITICAL: Sign with the actual signer first (the player)
   tx.partialSign(signer);
   console.log(`🔧 [RAW] Signed with player: ${signer.publicKey.toString()}`);
-  
+
   // Then sign with provider wallet (fee payer)
   tx = await provider.wallet.signTransaction(tx);
   console.log(`🔧 [RAW] Signed with provider wallet`);
-  
+
   console.log(`🔧 [RAW] Final signatures: ${tx.signatures?.length || 0}`);
-  
+
   // Send raw transaction directly
   const rawTx = tx.serialize();
   const txHash = await provider.connection.sendRawTransaction(rawTx);
   await provider.connection.confirmTransaction(txHash);
-  
+
   console.log(`🔧 [RAW] Transaction sent: ${txHash}`);
-  
+
   // IMPROVED: Better commitment signature handling with timeout
   try {
     console.log(`🔧 [RAW] Waiting for commitment signature...`);
-    
+
     // Wait a bit for the transaction to be processed
     await new Promise(resolve => setTimeout(resolve, 2000));
-    
+
     const txCommitSgn = await GetCommitmentSignature(txHash, provider.connection);
     console.log(`🔧 [RAW] ✅ Commitment signature: ${txCommitSgn}`);
     console.log(`${description} (ER): ${txCommitSgn}`);
     return txCommitSgn;
-    
+
   } catch (commitError) {
     console.log(`🔧 [RAW] ⚠️ Commitment signature failed: ${commitError.message}`);
     console.log(`🔧 [RAW] Using transaction hash as fallback: ${txHash}`);
@@ -87,13 +89,112 @@ async function sendERTransaction(
   }
 }
 
+/**
+ * Expect a transaction to fail with one of the given program errors
+ */
+async function expectProgramError(promise: Promise<any>, ...errorNames: string[]): Promise<void> {
+  try {
+    await promise;
+  } catch (error) {
+    const logs = (error.logs || error.transactionLogs || []).join("\n");
+    const message = `${error.message}\n${logs}`;
+    const matched = errorNames.some(name => message.includes(name));
+    expect(matched, `expected one of ${errorNames.join(", ")} but got: ${error.message}`).to.be.true;
+    console.log(`✅ Rejected as expected (${errorNames.join(" / ")})`);
+    return;
+  }
+  throw new Error(`Transaction succeeded but should have failed with ${errorNames.join(" / ")}`);
+}
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+// ===== Question bank and answer commitments, mirroring helpers.rs =====
+
+const sha256 = (...parts: Array<Buffer | Uint8Array | number[]>): Buffer => {
+  const hasher = createHash("sha256");
+  parts.forEach(part => hasher.update(Buffer.from(part as Uint8Array)));
+  return hasher.digest();
+};
+
+const u16Le = (value: number): Buffer => {
+  const buffer = Buffer.alloc(2);
+  buffer.writeUInt16LE(value);
+  return buffer;
+};
+
+// compute_answer_digest: the salted answer published in each bank leaf
+const answerDigest = (questionId: number, answer: number, answerSalt: Buffer) =>
+  sha256(u16Le(questionId), [answer], answerSalt);
+
+// compute_question_leaf
+const questionLeaf = (questionId: number, concept: number, topic: number, answerOptions: number, digest: Buffer) =>
+  sha256(u16Le(questionId), [concept], [topic], [answerOptions], digest);
+
+// compute_answer_commitment: the answer key committed when the room is created
+const answerCommitment = (roomId: number[], questionIndex: number, answer: number, salt: Buffer) =>
+  sha256(roomId, [questionIndex], [answer], salt);
+
+// compute_sealed_answer: what a player submits before revealing
+const sealedAnswer = (roomId: number[], questionIndex: number, player: PublicKey, answer: number, salt: Buffer) =>
+  sha256(roomId, [questionIndex], player.toBuffer(), [answer], salt);
+
+// Sorted-pair merkle tree, an odd node is carried up unchanged
+function buildMerkleTree(leaves: Buffer[]): Buffer[][] {
+  const levels = [leaves];
+  while (levels[levels.length - 1].length > 1) {
+    const level = levels[levels.length - 1];
+    const next: Buffer[] = [];
+    for (let i = 0; i < level.length; i += 2) {
+      if (i + 1 === level.length) {
+        next.push(level[i]);
+        continue;
+      }
+      const [left, right] = Buffer.compare(level[i], level[i + 1]) <= 0
+        ? [level[i], level[i + 1]]
+        : [level[i + 1], level[i]];
+      next.push(sha256(left, right));
+    }
+    levels.push(next);
+  }
+  return levels;
+}
+
+function merkleProof(levels: Buffer[][], index: number): number[][] {
+  const proof: number[][] = [];
+  for (let level = 0; level < levels.length - 1; level++) {
+    const sibling = index ^ 1;
+    if (sibling < levels[level].length) {
+      proof.push(Array.from(levels[level][sibling]));
+    }
+    index >>= 1;
+  }
+  return proof;
+}
+
+type Question = {
+  questionId: number;
+  concept: number;
+  topic: number;
+  answerOptions: number;
+  answer: number;
+  answerSalt: Buffer;
+};
+
+type RoomContent = {
+  roomId: number[];
+  pda: PublicKey;
+  questions: Question[];
+  bankIndexes: number[];
+  keySalts: Buffer[];
+};
+
 describe("rust_undead", () => {
   // Base Layer Provider (Solana Devnet)
   const baseConnection = new anchor.web3.Connection(
     "https://devnet.helius-rpc.com/?api-key=4a2f7893-25a4-4014-a367-4f2fac75aa63",
     "confirmed"
   );
-  
+
   const provider = new anchor.AnchorProvider(
     baseConnection,
     anchor.Wallet.local(),
@@ -119,40 +220,435 @@ describe("rust_undead", () => {
   console.log("Base Layer Connection: ", provider.connection.rpcEndpoint);
   console.log("Ephemeral Rollup Connection: ", providerEphemeralRollup.connection.rpcEndpoint);
   console.log(`Authority Public Key: ${anchor.Wallet.local().publicKey}`);
-  
-  // Test accounts - loaded from JSON files
+
+  // Test accounts - loaded from JSON files. The local wallet is admin and question authority
   let authority: Keypair;
   let playerA: Keypair;
   let playerB: Keypair;
-  
+
   // PDAs
-  let configPda: PublicKey;
-  let leaderboardPda: PublicKey;
+  const configPda = pda.deriveConfigSeedPDA(program.programId)[0];
+  const leaderboardPda = pda.deriveLeaderboardSeedPDA(program.programId)[0];
+  const ratingLeaderboardPda = pda.deriveRatingLeaderboardSeedPDA(program.programId)[0];
+  const questionBankPda = pda.deriveQuestionBankSeedPDA(program.programId)[0];
+  let seasonLeaderboardPda: PublicKey;
   let warriorAPda: PublicKey;
   let warriorBPda: PublicKey;
-  let battleRoomPda: PublicKey;
   let userProfileAPda: PublicKey;
   let userProfileBPda: PublicKey;
   let userAchievementsAPda: PublicKey;
   let userAchievementsBPda: PublicKey;
+  let treasury: PublicKey;
+
+  // Config values changed by the tests, restored in after()
+  let originalConfig: any;
 
   // Test data
   const cooldownTime = new anchor.BN(300); // 5 minutes
-  const roomId = Array.from(crypto.getRandomValues(new Uint8Array(32)));
   const warriorAName = "King Mario";
   const warriorBName = "Bone Crusher";
-  const dna = Array.from(crypto.getRandomValues(new Uint8Array(8)));
-  
-  // Battle room data
+  const dna = Array.from(randomBytes(8));
+  const wagerAmount = new anchor.BN(0.01 * LAMPORTS_PER_SOL);
+
+  // Question bank: 20 questions over concepts 1-5, four options each
+  const conceptCount = 5;
   const selectedConcepts = [1, 2, 3, 4, 5];
-  const selectedQuestions = [10, 20, 30, 40, 50, 60, 70, 80, 90, 100];
-  const selectedtopics = [10, 20, 30, 40, 50, 60, 70, 80, 90, 100];
-  const correctAnswers = [true, false, true, false, true, false, true, false, true, false];
+  const bank: Question[] = Array.from({ length: 20 }, (_, i) => ({
+    questionId: 100 + i,
+    concept: (i % conceptCount) + 1,
+    topic: 10 + i,
+    answerOptions: 4,
+    answer: i % 4,
+    answerSalt: randomBytes(32),
+  }));
+  const bankTree = buildMerkleTree(
+    bank.map(q => questionLeaf(q.questionId, q.concept, q.topic, q.answerOptions, answerDigest(q.questionId, q.answer, q.answerSalt)))
+  );
+  const bankRoot = Array.from(bankTree[bankTree.length - 1][0]);
+
+  // Rooms used by the tests
+  const battleRoom = newRoomContent(10);
+  const timeoutRoom = newRoomContent(5);
+  const refundRoom = newRoomContent(5);
+
+  function newRoomContent(questionCount: number): RoomContent {
+    const roomId = Array.from(randomBytes(32));
+    const bankIndexes = Array.from({ length: questionCount }, (_, i) => (i * 3) % bank.length);
+    return {
+      roomId,
+      pda: pda.deriveBattleRoomSeedPDA({ roomId }, program.programId)[0],
+      questions: bankIndexes.map(i => bank[i]),
+      bankIndexes,
+      keySalts: bankIndexes.map(() => randomBytes(32)),
+    };
+  }
+
+  const wrongAnswer = (q: Question) => (q.answer + 1) % q.answerOptions;
+
+  const configUpdate = (fields: Record<string, any>) => ({
+    cooldownTime: null,
+    winnerCooldownPct: null,
+    loserCooldownPct: null,
+    hpCooldownPenaltyPct: null,
+    hpRegenPerHour: null,
+    healCostLamports: null,
+    renameFeeLamports: null,
+    renameCooldown: null,
+    treasury: null,
+    wagerFeeBps: null,
+    maxLevel: null,
+    answerTimeout: null,
+    idleTimeout: null,
+    questionAuthority: null,
+    ...fields,
+  });
+
+  async function updateConfig(fields: Record<string, any>): Promise<string> {
+    return program.methods
+      .updateConfig(configUpdate(fields) as any)
+      .accountsPartial({
+        admin: authority.publicKey,
+        config: configPda,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  // Create (or reuse on a rerun) a warrior, found through its name claim
+  async function createWarrior(player: Keypair, name: string, warriorClass: any, reuseExisting = true): Promise<PublicKey> {
+    const [nameRegistryPda] = pda.deriveNameRegistrySeedPDA({ name }, program.programId);
+    const registry = reuseExisting ? await program.account.nameRegistry.fetchNullable(nameRegistryPda) : null;
+    if (registry) {
+      const existing = await program.account.undeadWarrior.fetch(registry.warrior);
+      expect(existing.owner.toString()).to.equal(player.publicKey.toString());
+      console.log(`📋 ${name} already exists at ${registry.warrior.toString()}`);
+      return registry.warrior;
+    }
+
+    const [profilePda] = pda.deriveUserProfileSeedPDA({ owner: player.publicKey }, program.programId);
+    const profile = await program.account.userProfile.fetchNullable(profilePda);
+    const warriorIndex = profile ? profile.nextWarriorIndex : 0;
+    const [warriorPda] = pda.deriveWarriorSeedPDA({ creator: player.publicKey, warriorIndex }, program.programId);
+
+    const clientSeed = Math.floor(Math.random() * 256);
+    console.log(`🎲 Creating ${name} (#${warriorIndex}) with client seed ${clientSeed}`);
+
+    const tx = await program.methods
+      .createWarrior(name, dna, warriorClass, clientSeed)
+      .accountsPartial({
+        player: player.publicKey,
+        userProfile: profilePda,
+        warrior: warriorPda,
+        nameRegistry: nameRegistryPda,
+        userAchievements: pda.deriveUserAchievementsSeedPDA({ owner: player.publicKey }, program.programId)[0],
+        config: configPda,
+      })
+      .signers([player])
+      .rpc();
+
+    console.log(`✅ Transaction: ${tx}`);
+    console.log(`🔗 Explorer: https://explorer.solana.com/tx/${tx}?cluster=devnet`);
+    return warriorPda;
+  }
+
+  // Stats stay pending until the VRF oracle calls back
+  async function waitForStats(warriorPda: PublicKey): Promise<any> {
+    console.log("⏳ Polling for VRF callback completion...");
+    for (let poll = 1; poll <= 15; poll++) {
+      const warrior = await program.account.undeadWarrior.fetch(warriorPda);
+      if (!warrior.statsPending) {
+        console.log(`🎉 Stats rolled: ATK ${warrior.baseAttack}, DEF ${warrior.baseDefense}, KNOW ${warrior.baseKnowledge}`);
+        return warrior;
+      }
+      console.log(`📊 Poll ${poll}/15: stats still pending`);
+      await sleep(4000);
+    }
+    throw new Error("VRF callback timed out after 1 minute");
+  }
+
+  async function healIfNeeded(player: Keypair, warriorPda: PublicKey): Promise<void> {
+    const warrior = await program.account.undeadWarrior.fetch(warriorPda);
+    if (warrior.currentHp === warrior.maxHp) {
+      return;
+    }
+    console.log(`💚 Healing ${warrior.name} from ${warrior.currentHp}/${warrior.maxHp} HP`);
+    await program.methods
+      .healWarrior()
+      .accountsPartial({
+        owner: player.publicKey,
+        warrior: warriorPda,
+        warriorNftAccount: null,
+        config: configPda,
+        treasury,
+      })
+      .signers([player])
+      .rpc();
+  }
+
+  async function createRoom(room: RoomContent, wager: anchor.BN): Promise<string> {
+    return program.methods
+      .createBattleRoom(
+        room.roomId,
+        selectedConcepts,
+        Buffer.from(room.questions.map(q => q.topic)),
+        room.questions.map(q => q.questionId),
+        Buffer.from(room.questions.map(q => q.answerOptions)),
+        room.questions.map((q, i) => Array.from(answerCommitment(room.roomId, i, q.answer, room.keySalts[i]))),
+        wager
+      )
+      .accountsPartial({
+        playerA: playerA.publicKey,
+        warriorA: warriorAPda,
+        warriorNftAccount: null,
+        matchQueue: null,
+        questionAuthority: authority.publicKey,
+        config: configPda,
+        questionBank: questionBankPda,
+        battleRoom: room.pda,
+        wagerEscrow: wager.isZero() ? null : pda.deriveWagerEscrowSeedPDA({ roomId: room.roomId }, program.programId)[0],
+        wagerMint: null,
+        wagerVault: null,
+        playerTokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([playerA, authority])
+      .rpc();
+  }
+
+  async function verifyRoomQuestions(room: RoomContent): Promise<void> {
+    for (let i = 0; i < room.questions.length; i++) {
+      const q = room.questions[i];
+      await program.methods
+        .verifyRoomQuestion(
+          room.roomId,
+          i,
+          q.concept,
+          Array.from(answerDigest(q.questionId, q.answer, q.answerSalt)),
+          merkleProof(bankTree, room.bankIndexes[i])
+        )
+        .accountsPartial({
+          questionAuthority: authority.publicKey,
+          config: configPda,
+          battleRoom: room.pda,
+          questionBank: questionBankPda,
+        })
+        .signers([authority])
+        .rpc();
+      console.log(`  ✅ Question ${i + 1}/${room.questions.length} proven against the bank`);
+    }
+  }
+
+  async function joinRoom(room: RoomContent, wagered: boolean): Promise<string> {
+    return program.methods
+      .joinBattleRoom(room.roomId)
+      .accountsPartial({
+        playerB: playerB.publicKey,
+        warriorB: warriorBPda,
+        warriorNftAccount: null,
+        matchQueue: null,
+        battleRoom: room.pda,
+        config: configPda,
+        wagerEscrow: wagered ? pda.deriveWagerEscrowSeedPDA({ roomId: room.roomId }, program.programId)[0] : null,
+        wagerVault: null,
+        playerTokenAccount: null,
+        tokenProgram: null,
+      })
+      .signers([playerB])
+      .rpc();
+  }
+
+  async function signalReady(room: RoomContent, player: Keypair, warriorPda: PublicKey): Promise<string> {
+    return program.methods
+      .signalReady(room.roomId)
+      .accountsPartial({
+        player: player.publicKey,
+        battleRoom: room.pda,
+        warrior: warriorPda,
+        warriorNftAccount: null,
+        warriorA: warriorAPda,
+        warriorB: warriorBPda,
+        config: configPda,
+      })
+      .signers([player])
+      .rpc();
+  }
+
+  async function delegateRoom(room: RoomContent): Promise<string> {
+    const maxComputeBudgetIx = ComputeBudgetProgram.setComputeUnitLimit({
+      units: 350_000, // Conservative limit for battle room only
+    });
+    return program.methods
+      .delegateBattle(room.roomId)
+      .accountsPartial({
+        signer: authority.publicKey,
+        battleRoom: room.pda,
+        warriorA: warriorAPda,
+        warriorB: warriorBPda,
+        config: configPda,
+      })
+      .preInstructions([maxComputeBudgetIx])
+      .signers([authority])
+      .rpc();
+  }
+
+  async function startBattle(room: RoomContent): Promise<string> {
+    return sendERTransaction(
+      ephemeralProgram,
+      ephemeralProgram.methods
+        .startBattle(room.roomId)
+        .accountsPartial({
+          authority: authority.publicKey,
+          battleRoom: room.pda,
+          warriorA: warriorAPda,
+          warriorB: warriorBPda,
+          config: configPda,
+          program: program.programId,
+        }),
+      authority,
+      providerEphemeralRollup,
+      "Start Battle",
+    );
+  }
+
+  async function sealAnswer(room: RoomContent, questionIndex: number, player: Keypair, answer: number, salt: Buffer): Promise<string> {
+    const isPlayerA = player.publicKey.equals(playerA.publicKey);
+    return sendERTransaction(
+      ephemeralProgram,
+      ephemeralProgram.methods
+        .answerQuestion(room.roomId, Array.from(sealedAnswer(room.roomId, questionIndex, player.publicKey, answer, salt)))
+        .accountsPartial({
+          player: player.publicKey,
+          battleRoom: room.pda,
+          attackerWarrior: isPlayerA ? warriorAPda : warriorBPda,
+          defenderWarrior: isPlayerA ? warriorBPda : warriorAPda,
+          warriorNftAccount: null,
+          program: program.programId,
+        }),
+      player,
+      providerEphemeralRollup,
+      `${isPlayerA ? "Player A" : "Player B"} Seal Q${questionIndex + 1}`,
+    );
+  }
+
+  async function revealPlayerAnswer(room: RoomContent, questionIndex: number, player: Keypair, answer: number, salt: Buffer): Promise<string> {
+    return sendERTransaction(
+      ephemeralProgram,
+      ephemeralProgram.methods
+        .revealPlayerAnswer(room.roomId, answer, Array.from(salt))
+        .accountsPartial({
+          player: player.publicKey,
+          battleRoom: room.pda,
+        }),
+      player,
+      providerEphemeralRollup,
+      `${player.publicKey.equals(playerA.publicKey) ? "Player A" : "Player B"} Reveal Q${questionIndex + 1}`,
+    );
+  }
+
+  async function revealAnswerKey(room: RoomContent, questionIndex: number): Promise<string> {
+    const q = room.questions[questionIndex];
+    return sendERTransaction(
+      ephemeralProgram,
+      ephemeralProgram.methods
+        .revealAnswer(room.roomId, questionIndex, q.answer, Array.from(room.keySalts[questionIndex]), Array.from(q.answerSalt))
+        .accountsPartial({
+          questionAuthority: authority.publicKey,
+          config: configPda,
+          battleRoom: room.pda,
+          warriorA: warriorAPda,
+          warriorB: warriorBPda,
+          program: program.programId,
+        }),
+      authority,
+      providerEphemeralRollup,
+      `Answer Key Q${questionIndex + 1}`,
+    );
+  }
+
+  function settleBuilder(room: RoomContent) {
+    return ephemeralProgram.methods.settleBattleRoom(room.roomId)
+      .accountsPartial({
+        authority: authority.publicKey,
+        battleRoom: room.pda,
+        warriorA: warriorAPda,
+        warriorB: warriorBPda,
+        config: configPda,
+        program: program.programId,
+      });
+  }
+
+  async function settleAndUndelegate(room: RoomContent): Promise<void> {
+    await sendERTransaction(
+      ephemeralProgram,
+      settleBuilder(room),
+      authority,
+      providerEphemeralRollup,
+      "Settle Battle with XP",
+    );
+    await sleep(3000);
+
+    await sendERTransaction(
+      ephemeralProgram,
+      ephemeralProgram.methods.undelegateBattleRoom(room.roomId)
+        .accountsPartial({
+          authority: authority.publicKey,
+          battleRoom: room.pda,
+          warriorA: warriorAPda,
+          warriorB: warriorBPda,
+        }),
+      authority,
+      providerEphemeralRollup,
+      "Undelegate Battle Room",
+    );
+  }
+
+  // Magic Block needs a while to hand the accounts back to the program
+  async function waitForUndelegation(accounts: PublicKey[]): Promise<void> {
+    console.log("⏳ Waiting for Magic Block ownership transfer to complete...");
+    for (let retry = 1; retry <= 8; retry++) {
+      await sleep(10000);
+      const infos = await Promise.all(accounts.map(account => provider.connection.getAccountInfo(account)));
+      if (infos.every(info => info?.owner.equals(program.programId))) {
+        console.log("✅ Accounts are back under program ownership");
+        return;
+      }
+      console.log(`⏳ Retry ${retry}/8 - accounts still delegated, waiting...`);
+    }
+    throw new Error("Accounts were not undelegated in time");
+  }
+
+  function updateFinalStateBuilder(room: RoomContent) {
+    return program.methods
+      .updateFinalState(room.roomId)
+      .accountsPartial({
+        authority: authority.publicKey,
+        battleRoom: room.pda,
+        warriorA: warriorAPda,
+        warriorB: warriorBPda,
+        profileA: userProfileAPda,
+        profileB: userProfileBPda,
+        achievementsA: userAchievementsAPda,
+        achievementsB: userAchievementsBPda,
+        config: configPda,
+        leaderboard: leaderboardPda,
+        ratingLeaderboard: ratingLeaderboardPda,
+        seasonLeaderboard: seasonLeaderboardPda,
+        program: program.programId,
+      })
+      .signers([authority]);
+  }
+
+  async function queryRank(leaderboard: PublicKey, player: PublicKey): Promise<number | null> {
+    return program.methods
+      .queryRank(player)
+      .accountsPartial({ leaderboard })
+      .view();
+  }
 
   before(async () => {
     console.log("\n🔐 Loading wallet keypairs from JSON files...");
     console.log("🌐 Running tests on Solana Devnet");
-    
+
     try {
       // Load keypairs from JSON files
       authority = anchor.Wallet.local().payer;
@@ -164,38 +660,18 @@ describe("rust_undead", () => {
       console.log(`  Player A:  ${playerA.publicKey.toString()}`);
       console.log(`  Player B:  ${playerB.publicKey.toString()}`);
 
-      // Check current balances with error handling
-      let authorityBalance = 0;
-      let playerABalance = 0;
-      let playerBBalance = 0;
-
       try {
         console.log("\n💰 Checking wallet balances on devnet...");
-        authorityBalance = await provider.connection.getBalance(authority.publicKey);
-        playerABalance = await provider.connection.getBalance(playerA.publicKey);
-        playerBBalance = await provider.connection.getBalance(playerB.publicKey);
-        
-        console.log("💰 Current wallet balances:");
-        console.log(`  Authority: ${(authorityBalance / LAMPORTS_PER_SOL).toFixed(4)} SOL`);
-        console.log(`  Player A:  ${(playerABalance / LAMPORTS_PER_SOL).toFixed(4)} SOL`);
-        console.log(`  Player B:  ${(playerBBalance / LAMPORTS_PER_SOL).toFixed(4)} SOL`);
-        
-        // Check if any wallets need airdrop
-        const minBalance = 0.1 * LAMPORTS_PER_SOL; // Minimum 0.1 SOL
-        
-        if (authorityBalance < minBalance) {
-          console.log("🪂 Authority needs SOL - please run: solana airdrop 2 " + authority.publicKey.toString() + " --url devnet");
-        }
-        if (playerABalance < minBalance) {
-          console.log("🪂 Player A needs SOL - please run: solana airdrop 2 " + playerA.publicKey.toString() + " --url devnet");
-        }
-        if (playerBBalance < minBalance) {
-          console.log("🪂 Player B needs SOL - please run: solana airdrop 2 " + playerB.publicKey.toString() + " --url devnet");
+        const minBalance = 0.2 * LAMPORTS_PER_SOL; // Wagers, heals and a rename fee
+        for (const [label, wallet] of [["Authority", authority], ["Player A", playerA], ["Player B", playerB]] as const) {
+          const balance = await provider.connection.getBalance(wallet.publicKey);
+          console.log(`  ${label}: ${(balance / LAMPORTS_PER_SOL).toFixed(4)} SOL`);
+          if (balance < minBalance) {
+            console.log(`🪂 ${label} needs SOL - please run: solana airdrop 2 ${wallet.publicKey.toString()} --url devnet`);
+          }
         }
-        
       } catch (balanceError) {
         console.log("⚠️ Could not fetch balances from devnet:", balanceError.message);
-        console.log("💡 Make sure you have internet connection and devnet is accessible");
         console.log("💡 You can manually airdrop SOL using: solana airdrop 2 <wallet> --url devnet");
       }
 
@@ -204,531 +680,249 @@ describe("rust_undead", () => {
       throw error;
     }
 
-    // Derive PDAs - following the program's seed structure
-    [configPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("config"), authority.publicKey.toBuffer()],
-      program.programId
-    );
-
-    [leaderboardPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("leaderboard"), authority.publicKey.toBuffer()],
-      program.programId
-    );
-
-    [warriorAPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("undead_warrior"), playerA.publicKey.toBuffer(), Buffer.from(warriorAName)],
-      program.programId
-    );
-
-    [warriorBPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("undead_warrior"), playerB.publicKey.toBuffer(), Buffer.from(warriorBName)],
-      program.programId
-    );
-
-    [battleRoomPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("battleroom"), Buffer.from(roomId)],
-      program.programId
-    );
-
-    [userProfileAPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("user_profile"), playerA.publicKey.toBuffer()],
-      program.programId
-    );
-
-    [userProfileBPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("user_profile"), playerB.publicKey.toBuffer()],
-      program.programId
-    );
-
-    [userAchievementsAPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("user_achievements"), playerA.publicKey.toBuffer()],
-      program.programId
-    );
-
-    [userAchievementsBPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("user_achievements"), playerB.publicKey.toBuffer()],
-      program.programId
-    );
+    [userProfileAPda] = pda.deriveUserProfileSeedPDA({ owner: playerA.publicKey }, program.programId);
+    [userProfileBPda] = pda.deriveUserProfileSeedPDA({ owner: playerB.publicKey }, program.programId);
+    [userAchievementsAPda] = pda.deriveUserAchievementsSeedPDA({ owner: playerA.publicKey }, program.programId);
+    [userAchievementsBPda] = pda.deriveUserAchievementsSeedPDA({ owner: playerB.publicKey }, program.programId);
 
     console.log("\n🔑 Derived PDAs:");
-    console.log(`  Program ID:  ${program.programId.toString()}`);
-    console.log(`  Config:      ${configPda.toString()}`);
-    console.log(`  Leaderboard: ${leaderboardPda.toString()}`);
-    console.log(`  Warrior A:   ${warriorAPda.toString()}`);
-    console.log(`  Warrior B:   ${warriorBPda.toString()}`);
-    console.log(`  Battle Room: ${battleRoomPda.toString()}`);
+    console.log(`  Program ID:    ${program.programId.toString()}`);
+    console.log(`  Config:        ${configPda.toString()}`);
+    console.log(`  Leaderboard:   ${leaderboardPda.toString()}`);
+    console.log(`  Question Bank: ${questionBankPda.toString()}`);
+    console.log(`  Battle Room:   ${battleRoom.pda.toString()}`);
   });
 
   describe("Base Layer Setup", () => {
     it("Initialize the game", async () => {
       try {
         console.log("🎮 Checking if game is already initialized on Solana Devnet...");
-        
-        // First, check if the config account already exists
-        let configAccount;
-        let isAlreadyInitialized = false;
-        
-        try {
-          configAccount = await program.account.config.fetch(configPda);
-          isAlreadyInitialized = true;
+
+        let configAccount = await program.account.config.fetchNullable(configPda);
+
+        if (configAccount) {
           console.log("📋 Game already initialized! Skipping initialization...");
-          console.log(`  Admin: ${configAccount.admin.toString()}`);
-          console.log(`  Cooldown Time: ${configAccount.cooldownTime.toString()}s`);
-          console.log(`  Total Warriors: ${configAccount.totalWarriors.toString()}`);
-          console.log(`  Is Paused: ${configAccount.isPaused}`);
-        } catch (error) {
-          // Config account doesn't exist, so we need to initialize
+        } else {
           console.log("🎮 Game not initialized yet. Initializing...");
-        }
-        
-        if (!isAlreadyInitialized) {
           const tx = await program.methods
-            .initialize(cooldownTime)
+            .initialize(cooldownTime, authority.publicKey)
             .accountsPartial({
               authority: authority.publicKey,
               config: configPda,
               leaderboard: leaderboardPda,
-              systemProgram: SystemProgram.programId,
+              ratingLeaderboard: ratingLeaderboardPda,
+              seasonLeaderboard: pda.deriveSeasonLeaderboardSeedPDA({ season: 1 }, program.programId)[0],
             })
             .signers([authority])
             .rpc();
 
           console.log("Initialize transaction signature:", tx);
           console.log("Explorer link:", `https://explorer.solana.com/tx/${tx}?cluster=devnet`);
-          
-          // Fetch the newly created config account
+
           configAccount = await program.account.config.fetch(configPda);
+          expect(configAccount.cooldownTime.toString()).to.equal(cooldownTime.toString());
         }
 
-        // Verify config account (whether newly created or existing)
+        console.log(`  Admin: ${configAccount.admin.toString()}`);
+        console.log(`  Question Authority: ${configAccount.questionAuthority.toString()}`);
+        console.log(`  Cooldown Time: ${configAccount.cooldownTime.toString()}s`);
+        console.log(`  Season: ${configAccount.currentSeason}`);
+        console.log(`  Is Paused: ${configAccount.isPaused}`);
+
         expect(configAccount.admin.toString()).to.equal(authority.publicKey.toString());
-        expect(configAccount.cooldownTime.toString()).to.equal(cooldownTime.toString());
+        expect(configAccount.questionAuthority.toString()).to.equal(authority.publicKey.toString());
         expect(configAccount.isPaused).to.be.false;
 
+        originalConfig = configAccount;
+        treasury = configAccount.treasury;
+        [seasonLeaderboardPda] = pda.deriveSeasonLeaderboardSeedPDA({ season: configAccount.currentSeason }, program.programId);
+
         console.log("✅ Game ready for testing on devnet");
       } catch (error) {
         console.error("Error with game initialization:", error);
         throw error;
       }
     });
-it("Create Warrior A with VRF", async () => {
-  try {
-    console.log("⚔️ Creating Warrior A with enhanced VRF debugging...");
-    
-    // Check if warrior already exists
-    let warriorAccount;
-    let isAlreadyCreated = false;
-    
-    try {
-      warriorAccount = await program.account.undeadWarrior.fetch(warriorAPda);
-      isAlreadyCreated = true;
-      console.log("📋 Warrior A already exists!");
-      console.log(`  Name: ${warriorAccount.name}`);
-      console.log(`  ATK: ${warriorAccount.baseAttack}, DEF: ${warriorAccount.baseDefense}, KNOW: ${warriorAccount.baseKnowledge}`);
-      
-      if (warriorAccount.imageUri) {
-        console.log(`  🎨 Image: ${warriorAccount.imageUri}`);
-      }
-    } catch (error) {
-      console.log("⚔️ Creating fresh Warrior A with VRF...");
-    }
-    
-    if (!isAlreadyCreated) {
-      const clientSeed = Math.floor(Math.random() * 256);
-      console.log(`🎲 Using client seed: ${clientSeed}`);
-      
-      // 🔍 Debug VRF setup
-      console.log("🔍 VRF Debug Info:");
-      console.log(`   Program ID: ${program.programId.toString()}`);
-      console.log(`   Player: ${playerA.publicKey.toString()}`);
-      console.log(`   Warrior PDA: ${warriorAPda.toString()}`);
 
-      const tx = await program.methods
-        .createWarrior(
-          warriorAName,
-          dna,
-          { daemon: {} }, // WarriorClass::Daemon
-          clientSeed
-        )
-        .accountsPartial({
-          player: playerA.publicKey,
-          warrior: warriorAPda,
-          userProfile: userProfileAPda,
-          userAchievements: userAchievementsAPda,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([playerA])
-        .rpc();
-
-      console.log(`✅ Transaction: ${tx}`);
-      console.log(`🔗 Explorer: https://explorer.solana.com/tx/${tx}?cluster=devnet`);
-
-      // ✅ ENHANCED VRF POLLING WITH DETAILED LOGS
-      console.log("⏳ Polling for VRF callback completion...");
-      
-      let retryCount = 0;
-      const maxRetries = 15; // 15 attempts * 4 seconds = 1 minute
-      
-      while (retryCount < maxRetries) {
-        await new Promise(resolve => setTimeout(resolve, 4000)); // 4 second intervals
-        
-        try {
-          warriorAccount = await program.account.undeadWarrior.fetch(warriorAPda);
-          
-          console.log(`📊 Poll ${retryCount + 1}/${maxRetries}:`);
-          console.log(`   ATK: ${warriorAccount.baseAttack}`);
-          console.log(`   DEF: ${warriorAccount.baseDefense}`);
-          console.log(`   KNOW: ${warriorAccount.baseKnowledge}`);
-          console.log(`   Image Index: ${warriorAccount.imageIndex}`);
-          
-          // Check if VRF completed (all stats > 0)
-          if (warriorAccount.baseAttack > 0 && 
-              warriorAccount.baseDefense > 0 && 
-              warriorAccount.baseKnowledge > 0) {
-            console.log("🎉 VRF callback completed successfully!");
-            console.log(`🎨 Generated image: ${warriorAccount.imageUri}`);
+    it("Grow the leaderboards to full size", async () => {
+      // Boards start small and are grown in steps before battles can be recorded
+      for (const [label, board] of [
+        ["Points", leaderboardPda],
+        ["Rating", ratingLeaderboardPda],
+        ["Season", seasonLeaderboardPda],
+      ] as const) {
+        let steps = 0;
+        while (true) {
+          try {
+            await program.methods
+              .growLeaderboard()
+              .accountsPartial({
+                payer: authority.publicKey,
+                leaderboard: board,
+              })
+              .signers([authority])
+              .rpc();
+            steps++;
+          } catch (error) {
+            expect(error.message).to.include("LeaderboardAllocated");
             break;
           }
-          
-          retryCount++;
-        } catch (fetchError) {
-          console.log(`⚠️ Fetch failed on attempt ${retryCount + 1}: ${fetchError.message}`);
-          retryCount++;
         }
+        const info = await provider.connection.getAccountInfo(board);
+        console.log(`📈 ${label} leaderboard allocated (${info.data.length} bytes, ${steps} steps this run)`);
       }
-      
-      if (retryCount >= maxRetries) {
-        console.log("❌ VRF callback timed out after 1 minute");
-        console.log("🔧 This indicates VRF system issues on devnet");
-        
-        // Try one final fetch
-        warriorAccount = await program.account.undeadWarrior.fetch(warriorAPda);
-        
-        // Log current state for debugging
-        console.log("📊 Final State:");
-        console.log(`   ATK: ${warriorAccount.baseAttack}`);
-        console.log(`   DEF: ${warriorAccount.baseDefense}`);
-        console.log(`   KNOW: ${warriorAccount.baseKnowledge}`);
-        console.log(`   Image URI: ${warriorAccount.imageUri || "Not set"}`);
-      }
-    }
+    });
 
-    // ✅ CONDITIONAL VERIFICATION
-    expect(warriorAccount.name).to.equal(warriorAName);
-    expect(warriorAccount.owner.toString()).to.equal(playerA.publicKey.toString());
-    expect(warriorAccount.maxHp.toString()).to.equal("100");
-    
-    if (warriorAccount.baseAttack > 0) {
-      // VRF succeeded
-      expect(warriorAccount.baseAttack).to.be.greaterThan(0);
-      expect(warriorAccount.baseDefense).to.be.greaterThan(0);
-      expect(warriorAccount.baseKnowledge).to.be.greaterThan(0);
-      
-      console.log("✅ VRF stats verified:");
-      console.log(`   ATK: ${warriorAccount.baseAttack} (Daemon: expect 100-140)`);
-      console.log(`   DEF: ${warriorAccount.baseDefense} (Daemon: expect 40-60)`);
-      console.log(`   KNOW: ${warriorAccount.baseKnowledge} (Daemon: expect 50-99)`);
-      
-      if (warriorAccount.imageUri) {
-        console.log(`   🎨 Image: ${warriorAccount.imageUri}`);
-      }
-    } else {
-      console.log("⚠️ VRF failed - stats are 0 (known devnet issue)");
-      console.log("💡 Continuing test - in production implement retry logic");
-    }
+    it("Publish the question bank", async () => {
+      const bankAccount = await program.account.questionBank.fetchNullable(questionBankPda);
 
-    console.log("✅ Warrior A creation test completed");
-    
-  } catch (error) {
-    console.error("❌ Warrior A creation failed:", error);
-    throw error;
-  }
-});
+      const tx = bankAccount
+        ? await program.methods
+            .updateQuestionBank(bankRoot, bank.length, conceptCount)
+            .accountsPartial({
+              authority: authority.publicKey,
+              questionBank: questionBankPda,
+            })
+            .signers([authority])
+            .rpc()
+        : await program.methods
+            .initializeQuestionBank(authority.publicKey, bankRoot, bank.length, conceptCount)
+            .accountsPartial({
+              admin: authority.publicKey,
+              config: configPda,
+              questionBank: questionBankPda,
+            })
+            .signers([authority])
+            .rpc();
+      console.log(`📚 Question bank ${bankAccount ? "updated" : "initialized"}: ${tx}`);
 
-it("Create Warrior B with VRF", async () => {
-  try {
-    console.log("⚔️ Creating Warrior B with enhanced VRF debugging...");
-    
-    // Check if warrior already exists
-    let warriorAccount;
-    let isAlreadyCreated = false;
-    
-    try {
-      warriorAccount = await program.account.undeadWarrior.fetch(warriorBPda);
-      isAlreadyCreated = true;
-      console.log("📋 Warrior B already exists!");
-      console.log(`  Name: ${warriorAccount.name}`);
-      console.log(`  ATK: ${warriorAccount.baseAttack}, DEF: ${warriorAccount.baseDefense}, KNOW: ${warriorAccount.baseKnowledge}`);
-      
-      if (warriorAccount.imageUri) {
-        console.log(`  🎨 Image: ${warriorAccount.imageUri}`);
-      }
-    } catch (error) {
-      console.log("⚔️ Creating fresh Warrior B with VRF...");
-    }
-    
-    if (!isAlreadyCreated) {
-      const clientSeed = Math.floor(Math.random() * 256);
-      console.log(`🎲 Using client seed: ${clientSeed}`);
-      
-      // 🔍 Debug VRF setup
-      console.log("🔍 VRF Debug Info:");
-      console.log(`   Program ID: ${program.programId.toString()}`);
-      console.log(`   Player: ${playerB.publicKey.toString()}`);
-      console.log(`   Warrior PDA: ${warriorBPda.toString()}`);
+      const updated = await program.account.questionBank.fetch(questionBankPda);
+      expect(updated.merkleRoot).to.deep.equal(bankRoot);
+      expect(updated.questionCount).to.equal(bank.length);
+      expect(updated.conceptCount).to.equal(conceptCount);
+    });
 
-      const tx = await program.methods
-        .createWarrior(
-          warriorBName,
-          dna,
-          { guardian: {} }, // WarriorClass::guardian
-          clientSeed
-        )
-        .accountsPartial({
-          player: playerB.publicKey,
-          warrior: warriorBPda,
-          userProfile: userProfileBPda,
-          userAchievements: userAchievementsBPda,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([playerB])
-        .rpc();
+    it("Drop the base cooldown so warriors can battle again right away", async () => {
+      const tx = await updateConfig({ cooldownTime: new anchor.BN(0) });
+      console.log(`⚙️ Cooldown set to 0: ${tx}`);
+      const configAccount = await program.account.config.fetch(configPda);
+      expect(configAccount.cooldownTime.toNumber()).to.equal(0);
+    });
 
-      console.log(`✅ Transaction: ${tx}`);
-      console.log(`🔗 Explorer: https://explorer.solana.com/tx/${tx}?cluster=devnet`);
-
-      // ✅ ENHANCED VRF POLLING WITH DETAILED LOGS (same as Player A)
-      console.log("⏳ Polling for VRF callback completion...");
-      
-      let retryCount = 0;
-      const maxRetries = 15; // 15 attempts * 4 seconds = 1 minute
-      
-      while (retryCount < maxRetries) {
-        await new Promise(resolve => setTimeout(resolve, 4000)); // 4 second intervals
-        
-        try {
-          warriorAccount = await program.account.undeadWarrior.fetch(warriorBPda);
-          
-          console.log(`📊 Poll ${retryCount + 1}/${maxRetries}:`);
-          console.log(`   ATK: ${warriorAccount.baseAttack}`);
-          console.log(`   DEF: ${warriorAccount.baseDefense}`);
-          console.log(`   KNOW: ${warriorAccount.baseKnowledge}`);
-          console.log(`   Image Index: ${warriorAccount.imageIndex}`);
-          
-          // Check if VRF completed (all stats > 0)
-          if (warriorAccount.baseAttack > 0 && 
-              warriorAccount.baseDefense > 0 && 
-              warriorAccount.baseKnowledge > 0) {
-            console.log("🎉 VRF callback completed successfully!");
-            console.log(`🎨 Generated image: ${warriorAccount.imageUri}`);
-            break;
-          }
-          
-          retryCount++;
-        } catch (fetchError) {
-          console.log(`⚠️ Fetch failed on attempt ${retryCount + 1}: ${fetchError.message}`);
-          retryCount++;
-        }
-      }
-      
-      if (retryCount >= maxRetries) {
-        console.log("❌ VRF callback timed out after 1 minute");
-        console.log("🔧 This indicates VRF system issues on devnet");
-        
-        // Try one final fetch
-        warriorAccount = await program.account.undeadWarrior.fetch(warriorBPda);
-        
-        // Log current state for debugging
-        console.log("📊 Final State:");
-        console.log(`   ATK: ${warriorAccount.baseAttack}`);
-        console.log(`   DEF: ${warriorAccount.baseDefense}`);
-        console.log(`   KNOW: ${warriorAccount.baseKnowledge}`);
-        console.log(`   Image URI: ${warriorAccount.imageUri || "Not set"}`);
-      }
-    }
+    it("Create Warrior A with VRF", async () => {
+      try {
+        console.log("⚔️ Creating Warrior A...");
+        warriorAPda = await createWarrior(playerA, warriorAName, { daemon: {} });
+        const warriorAccount = await waitForStats(warriorAPda);
 
-    // ✅ CONDITIONAL VERIFICATION (same as Player A)
-    expect(warriorAccount.name).to.equal(warriorBName);
-    expect(warriorAccount.owner.toString()).to.equal(playerB.publicKey.toString());
-    expect(warriorAccount.maxHp.toString()).to.equal("100");
-    
-    if (warriorAccount.baseAttack > 0) {
-      // VRF succeeded
-      expect(warriorAccount.baseAttack).to.be.greaterThan(0);
-      expect(warriorAccount.baseDefense).to.be.greaterThan(0);
-      expect(warriorAccount.baseKnowledge).to.be.greaterThan(0);
-      
-      console.log("✅ VRF stats verified:");
-      console.log(`   ATK: ${warriorAccount.baseAttack} (Validator: expect 70-110)`);
-      console.log(`   DEF: ${warriorAccount.baseDefense} (Validator: expect 70-110)`);
-      console.log(`   KNOW: ${warriorAccount.baseKnowledge} (Validator: expect 70-110)`);
-      
-      if (warriorAccount.imageUri) {
-        console.log(`   🎨 Image: ${warriorAccount.imageUri}`);
-      }
-    } else {
-      console.log("⚠️ VRF failed - stats are 0 (known devnet issue)");
-      console.log("💡 Continuing test - in production implement retry logic");
-    }
+        expect(warriorAccount.name).to.equal(warriorAName);
+        expect(warriorAccount.owner.toString()).to.equal(playerA.publicKey.toString());
+        expect(warriorAccount.creator.toString()).to.equal(playerA.publicKey.toString());
+        expect(warriorAccount.baseAttack).to.be.greaterThan(0);
 
-    console.log("✅ Warrior B creation test completed");
-    
-  } catch (error) {
-    console.error("❌ Warrior B creation failed:", error);
-    throw error;
-  }
-});
+        const registry = await program.account.nameRegistry.fetch(
+          pda.deriveNameRegistrySeedPDA({ name: warriorAName }, program.programId)[0]
+        );
+        expect(registry.warrior.toString()).to.equal(warriorAPda.toString());
 
+        console.log("✅ Warrior A creation test completed");
+      } catch (error) {
+        console.error("❌ Warrior A creation failed:", error);
+        throw error;
+      }
+    });
 
-    it("Create Battle Room", async () => {
-  try {
-    console.log("🏛️ Creating battle room with fixed parameters...");
-    
-    // ✅ Debug parameter order before sending
-    console.log("📋 Battle Room Parameters:");
-    console.log(`   Room ID: ${Array.from(roomId).slice(0, 8)}...`);
-    console.log(`   Warrior: ${warriorAName}`);
-    console.log(`   Concepts: [${selectedConcepts.join(', ')}]`);
-    console.log(`   Topics: [${selectedtopics.join(', ')}]`);
-    console.log(`   Questions: [${selectedQuestions.join(', ')}]`);
-    console.log(`   Answers: [${correctAnswers.join(', ')}]`);
-    
-    const tx = await program.methods
-      .createBattleRoom(
-        roomId,
-        warriorAName,
-        selectedConcepts,
-        selectedtopics,      // ✅ Fixed: topics before questions
-        selectedQuestions,   // ✅ Fixed: questions after topics
-        correctAnswers
-      )
-      .accountsPartial({
-        playerA: playerA.publicKey,
-        warriorA: warriorAPda,
-        battleRoom: battleRoomPda,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([playerA])
-      .rpc();
+    it("Create Warrior B with VRF", async () => {
+      try {
+        console.log("⚔️ Creating Warrior B...");
+        warriorBPda = await createWarrior(playerB, warriorBName, { guardian: {} });
+        const warriorAccount = await waitForStats(warriorBPda);
 
-    console.log(`✅ Battle Room created: ${tx}`);
-    console.log(`🔗 Explorer: https://explorer.solana.com/tx/${tx}?cluster=devnet`);
+        expect(warriorAccount.name).to.equal(warriorBName);
+        expect(warriorAccount.owner.toString()).to.equal(playerB.publicKey.toString());
+        expect(warriorAccount.baseAttack).to.be.greaterThan(0);
 
-    // ✅ Verify battle room account
-    const battleRoom = await program.account.battleRoom.fetch(battleRoomPda);
-    
-    console.log("📊 Battle Room Verification:");
-    console.log(`   Player A: ${battleRoom.playerA.toString()}`);
-    console.log(`   Warrior A: ${battleRoom.warriorA.toString()}`);
-    console.log(`   Player B: ${battleRoom.playerB ? battleRoom.playerB.toString() : "None"}`);
-    console.log(`   State: ${JSON.stringify(battleRoom.state)}`);
-    console.log(`   Concepts: [${battleRoom.selectedConcepts.join(', ')}]`);
-    
-    // Verify values
-    expect(battleRoom.playerA.toString()).to.equal(playerA.publicKey.toString());
-    expect(battleRoom.warriorA.toString()).to.equal(warriorAPda.toString());
-    expect(battleRoom.playerB).to.be.null;
-    expect(battleRoom.state).to.deep.equal({ questionsSelected: {} });
-    expect(battleRoom.selectedConcepts).to.deep.equal(selectedConcepts);
-
-    console.log("✅ Battle room created and verified successfully");
-    
-  } catch (error) {
-    console.error("❌ Battle room creation failed:", error);
-    
-    // Enhanced error debugging
-    if (error.message.includes("InstructionDidNotDeserialize")) {
-      console.log("🔧 Deserialization Error Debug:");
-      console.log("   This usually means parameter types/order don't match the program");
-      console.log("   Check that the test parameters match the Rust function signature");
-      console.log("   Expected order: room_id, warrior_name, concepts, topics, questions, answers");
-    }
-    
-    throw error;
-  }
-});
+        console.log("✅ Warrior B creation test completed");
+      } catch (error) {
+        console.error("❌ Warrior B creation failed:", error);
+        throw error;
+      }
+    });
 
-   
-    it("Join Battle Room", async () => {
+    it("Name claims ignore case", async () => {
+      // "KING MARIO" hashes to the same claim as "King Mario"
+      await expectProgramError(
+        createWarrior(playerB, warriorAName.toUpperCase(), { validator: {} }, false),
+        "already in use"
+      );
+    });
+
+    it("Create Battle Room", async () => {
       try {
-        const tx = await program.methods
-          .joinBattleRoom(roomId, warriorBName)
-          .accountsPartial({
-            playerB: playerB.publicKey,
-            warriorB: warriorBPda,
-            battleRoom: battleRoomPda,
-          })
-          .signers([playerB])
-          .rpc();
+        await healIfNeeded(playerA, warriorAPda);
+        await healIfNeeded(playerB, warriorBPda);
 
-        console.log("Join Battle Room transaction signature:", tx);
-        console.log("Explorer link:", `https://explorer.solana.com/tx/${tx}?cluster=devnet`);
+        console.log("🏛️ Creating battle room...");
+        console.log(`   Room ID: ${battleRoom.roomId.slice(0, 8)}...`);
+        console.log(`   Questions: [${battleRoom.questions.map(q => q.questionId).join(", ")}]`);
 
-        // Verify battle room updated
-        const battleRoom = await program.account.battleRoom.fetch(battleRoomPda);
-        expect(battleRoom.playerB.toString()).to.equal(playerB.publicKey.toString());
-        expect(battleRoom.warriorB.toString()).to.equal(warriorBPda.toString());
+        const tx = await createRoom(battleRoom, new anchor.BN(0));
+        console.log(`✅ Battle Room created: ${tx}`);
+        console.log(`🔗 Explorer: https://explorer.solana.com/tx/${tx}?cluster=devnet`);
 
-        console.log("✅ Player B joined battle room successfully");
+        const room = await program.account.battleRoom.fetch(battleRoom.pda);
+        expect(room.playerA.toString()).to.equal(playerA.publicKey.toString());
+        expect(room.warriorA.toString()).to.equal(warriorAPda.toString());
+        expect(room.playerB).to.be.null;
+        expect(room.state).to.deep.equal({ created: {} });
+        expect(room.questionCount).to.equal(battleRoom.questions.length);
+
+        console.log("✅ Battle room created, waiting on question proofs");
       } catch (error) {
-        console.error("Error joining battle room:", error);
+        console.error("❌ Battle room creation failed:", error);
         throw error;
       }
     });
 
-    it("Signal Ready - Player A", async () => {
+    it("Verify room questions against the bank", async () => {
+      await verifyRoomQuestions(battleRoom);
+      const room = await program.account.battleRoom.fetch(battleRoom.pda);
+      expect(room.state).to.deep.equal({ questionsSelected: {} });
+      console.log("✅ All questions verified, room open for Player B");
+    });
+
+    it("Join Battle Room", async () => {
       try {
-        const tx = await program.methods
-          .signalReady(roomId, warriorAName)
-          .accountsPartial({
-            player: playerA.publicKey,
-            warrior: warriorAPda,
-            battleRoom: battleRoomPda,
-            warriorA: warriorAPda, 
-            warriorB: warriorBPda,  
-          })
-          .signers([playerA])
-          .rpc();
+        const tx = await joinRoom(battleRoom, false);
+        console.log("Join Battle Room transaction signature:", tx);
 
-        console.log("Signal Ready A transaction signature:", tx);
-        console.log("Explorer link:", `https://explorer.solana.com/tx/${tx}?cluster=devnet`);
+        const room = await program.account.battleRoom.fetch(battleRoom.pda);
+        expect(room.playerB.toString()).to.equal(playerB.publicKey.toString());
+        expect(room.warriorB.toString()).to.equal(warriorBPda.toString());
 
-        // Verify ready status
-        const battleRoom = await program.account.battleRoom.fetch(battleRoomPda);
-        expect(battleRoom.playerAReady).to.be.true;
+        const warriorB = await program.account.undeadWarrior.fetch(warriorBPda);
+        expect(warriorB.currentRoom).to.deep.equal(battleRoom.roomId);
 
-        console.log("✅ Player A signaled ready");
+        console.log("✅ Player B joined battle room successfully");
       } catch (error) {
-        console.error("Error signaling ready A:", error);
+        console.error("Error joining battle room:", error);
         throw error;
       }
     });
 
-    it("Signal Ready - Player B", async () => {
+    it("Signal Ready - both players", async () => {
       try {
-        const tx = await program.methods
-          .signalReady(roomId, warriorBName)
-          .accountsPartial({
-            player: playerB.publicKey,
-            warrior: warriorBPda,
-            battleRoom: battleRoomPda,
-            warriorA: warriorAPda,
-            warriorB: warriorBPda,  
-          })
-          .signers([playerB])
-          .rpc();
+        await signalReady(battleRoom, playerA, warriorAPda);
+        await signalReady(battleRoom, playerB, warriorBPda);
 
-        console.log("Signal Ready B transaction signature:", tx);
-        console.log("Explorer link:", `https://explorer.solana.com/tx/${tx}?cluster=devnet`);
-
-        // Verify ready status and state change
-        const battleRoom = await program.account.battleRoom.fetch(battleRoomPda);
-        expect(battleRoom.playerBReady).to.be.true;
-        expect(battleRoom.state).to.deep.equal({ readyForDelegation: {} });
+        const room = await program.account.battleRoom.fetch(battleRoom.pda);
+        expect(room.playerAReady).to.be.true;
+        expect(room.playerBReady).to.be.true;
+        expect(room.state).to.deep.equal({ readyForDelegation: {} });
 
-        console.log("✅ Player B signaled ready - battle ready for delegation");
+        console.log("✅ Both players signaled ready");
       } catch (error) {
-        console.error("Error signaling ready B:", error);
+        console.error("Error signaling ready:", error);
         throw error;
       }
     });
@@ -738,711 +932,639 @@ it("Create Warrior B with VRF", async () => {
     it("Delegate Battle to Ephemeral Rollup", async () => {
       try {
         console.log("🚀 Delegating battle accounts to Ephemeral Rollup...");
-        const maxComputeBudgetIx = ComputeBudgetProgram.setComputeUnitLimit({
-          units: 350_000, // Conservative limit for battle room only
-        });
-       const tx = await program.methods
-            .delegateBattle(
-              roomId,
-              playerA.publicKey,
-              warriorAName,
-              playerB.publicKey,
-              warriorBName
-            )
-            .accountsPartial({
-              signer: authority.publicKey,
-              battleRoom: battleRoomPda,
-              warriorA: warriorAPda,
-              warriorB: warriorBPda,
-            })
-            .preInstructions([maxComputeBudgetIx])
-            .signers([authority])
-            .rpc();
+        const tx = await delegateRoom(battleRoom);
         console.log("Battle Room delegation tx:", tx);
         console.log("Explorer link:", `https://explorer.solana.com/tx/${tx}?cluster=devnet`);
-        
-        // Wait for delegation to complete
-        await new Promise(resolve => setTimeout(resolve, 5000));
-        
-        // Verify delegation by checking battle room state
-        const battleRoom = await program.account.battleRoom.fetch(battleRoomPda);
-        console.log(`Battle room state after delegation: ${JSON.stringify(battleRoom.state)}`);
-        
+
+        await sleep(5000);
+        const roomInfo = await provider.connection.getAccountInfo(battleRoom.pda);
+        expect(roomInfo.owner.toString()).to.equal(pda.DELEGATION_PROGRAM_ID.toString());
       } catch (error) {
         console.error("Error delegating battle:", error);
-        console.log("⚠️  Both delegation strategies failed - this will affect subsequent ER tests");
-        console.log("💡 Consider optimizing the delegation instruction in your Rust program");
-    
+        throw error;
       }
     });
 
     it("Start Battle on Ephemeral Rollup", async () => {
       try {
         console.log("⚔️ Starting battle on Ephemeral Rollup...");
-        
-        const txCommitSgn = await sendERTransaction(
-          ephemeralProgram,
-          ephemeralProgram.methods
-            .startBattle(roomId)
-            .accountsPartial({
-              authority: authority.publicKey,
-              battleRoom: battleRoomPda,
-              warriorA: warriorAPda,
-              warriorB: warriorBPda,
-            }),
-            authority,
-          providerEphemeralRollup,
-          "Start Battle",
-         
-        );
-
-        // Wait a bit for state to update
-        await new Promise(resolve => setTimeout(resolve, 2000));
+        const txCommitSgn = await startBattle(battleRoom);
+        await sleep(2000);
 
-        // Verify battle started on ER
-        const battleRoom = await ephemeralProgram.account.battleRoom.fetch(battleRoomPda);
-        expect(battleRoom.state).to.deep.equal({ inProgress: {} });
-        expect(battleRoom.currentQuestion).to.equal(0);
-        console.log("✅ Battle started successfully on EphemeralRollup with tx:", txCommitSgn);   
-        console.log("✅ Battle started successfully on Ephemeral Rollup");
+        const room = await ephemeralProgram.account.battleRoom.fetch(battleRoom.pda);
+        expect(room.state).to.deep.equal({ inProgress: {} });
+        expect(room.currentQuestion).to.equal(0);
+        console.log("✅ Battle started successfully on Ephemeral Rollup with tx:", txCommitSgn);
       } catch (error) {
         console.error("Error starting battle on ER:", error);
-        console.log("⚠️  Battle start may require delegated accounts");
+        throw error;
       }
     });
 
-    // Modified test with longer wait times and proper account structure
-it("Real-time Battle: Complete 10 Questions on ER", async () => {
-  try {
-    console.log("🎮 Processing complete real-time battle on Ephemeral Rollup...");
-    
-    // Check if battle is actually in progress on ER
-    let battleRoom = await ephemeralProgram.account.battleRoom.fetch(battleRoomPda);
-    console.log(`Current ER battle state: ${JSON.stringify(battleRoom.state)}`);
-    
-    if (Object.keys(battleRoom.state)[0] !== 'inProgress') {
-      console.log(`⚠️ Battle not in progress - skipping battle simulation`);
-      return;
-    }
-    
-    console.log("✅ Battle is in progress on ER - proceeding with COMPLETE 10-question simulation");
-    console.log(`📊 Initial Battle Info:`);
-    console.log(`   Room ID: ${battleRoom.roomId.toString()}`);
-    console.log(`   Player A: ${battleRoom.playerA.toString().slice(0, 8)}... (Warrior A)`);
-    console.log(`   Player B: ${battleRoom.playerB.toString().slice(0, 8)}... (Warrior B)`);
-    console.log(`   Warrior A Key: ${battleRoom.warriorA.toString().slice(0, 8)}...`);
-    console.log(`   Warrior B Key: ${battleRoom.warriorB.toString().slice(0, 8)}...`);
-    
-    // Get initial warrior states
-    let initialWarriorA = await ephemeralProgram.account.undeadWarrior.fetch(warriorAPda);
-    let initialWarriorB = await ephemeralProgram.account.undeadWarrior.fetch(warriorBPda);
-    
-    console.log(`   ${initialWarriorA.name}: ${initialWarriorA.currentHp}/${initialWarriorA.maxHp} HP (ATK: ${initialWarriorA.baseAttack}, DEF: ${initialWarriorA.baseDefense})`);
-    console.log(`   ${initialWarriorB.name}: ${initialWarriorB.currentHp}/${initialWarriorB.maxHp} HP (ATK: ${initialWarriorB.baseAttack}, DEF: ${initialWarriorB.baseDefense})`);
-    
-    // Complete all 10 questions to finish the battle
-    for (let round = 0; round < 10; round++) {
-      console.log(`\n📋 ================ Question ${round + 1}/10 ================`);
-      
+    it("Real-time Battle: seal, reveal and score every question on ER", async () => {
       try {
-        // Generate client seeds like the frontend
-        const clientSeedA = Math.floor(Math.random() * 256);
-        const clientSeedB = Math.floor(Math.random() * 256);
-        
-        // Get current battle state
-        const currentBattleRoom = await ephemeralProgram.account.battleRoom.fetch(battleRoomPda);
-        console.log(`   Current Question Index: ${currentBattleRoom.currentQuestion}`);
-        console.log(`   Scores: A=${currentBattleRoom.playerACorrect}, B=${currentBattleRoom.playerBCorrect}`);
-        
-        // Player A answers (follows correctAnswers pattern)
-        console.log(`  👤 Player A answering question ${round + 1}...`);
-        console.log(`     Answer: ${correctAnswers[round]} (Expected: ${correctAnswers[round] ? "CORRECT" : "WRONG"})`);
-        
-        await sendERTransaction(
-          ephemeralProgram,
-          ephemeralProgram.methods
-            .answerQuestion(
-              roomId,
-              correctAnswers[round], // Follow the correctAnswers pattern
-              clientSeedA
-            )
-            .accountsPartial({
-              player: playerA.publicKey,
-              battleRoom: battleRoomPda,
-              attackerWarrior: warriorAPda,
-              defenderWarrior: warriorBPda,
-            }),
-          playerA,
-          providerEphemeralRollup,
-          `Player A Answer Q${round + 1}`,
-        );
+        console.log("🎮 Processing complete real-time battle on Ephemeral Rollup...");
 
-        console.log(`  ✅ Player A answered successfully`);
-
-        // Wait between answers
-        await new Promise(resolve => setTimeout(resolve, 1500));
-
-        // Player B answers (opposite of correct answer to create alternating wins)
-        console.log(`  👤 Player B answering question ${round + 1}...`);
-        console.log(`     Answer: ${!correctAnswers[round]} (Expected: ${!correctAnswers[round] ? "WRONG" : "CORRECT"})`);
-        
-        await sendERTransaction(
-          ephemeralProgram,
-          ephemeralProgram.methods
-            .answerQuestion(
-              roomId,
-              !correctAnswers[round], // Opposite of correct answer for variety
-              clientSeedB
-            )
-            .accountsPartial({
-              player: playerB.publicKey,
-              battleRoom: battleRoomPda,
-              attackerWarrior: warriorBPda,
-              defenderWarrior: warriorAPda,  
-            }),
-          playerB,
-          providerEphemeralRollup,
-          `Player B Answer Q${round + 1}`
-        );
+        for (let round = 0; round < battleRoom.questions.length; round++) {
+          const q = battleRoom.questions[round];
+          // Player A always knows the answer, Player B misses every other question
+          const answerA = q.answer;
+          const answerB = round % 2 === 0 ? q.answer : wrongAnswer(q);
+          const saltA = randomBytes(32);
+          const saltB = randomBytes(32);
+
+          console.log(`\n📋 ================ Question ${round + 1}/${battleRoom.questions.length} ================`);
+
+          await sealAnswer(battleRoom, round, playerA, answerA, saltA);
 
-        console.log(`  ✅ Player B answered successfully`);
+          // Revealing before both answers are sealed would let the opponent copy
+          await expectProgramError(
+            revealPlayerAnswer(battleRoom, round, playerA, answerA, saltA),
+            "AnswersNotSealed"
+          );
 
-        // ✅ EXTENDED WAIT FOR DETERMINISTIC DAMAGE PROCESSING
-        console.log("  ⏳ Waiting for deterministic damage calculation... (3 seconds)");
-        await new Promise(resolve => setTimeout(resolve, 3000)); // Extended wait for processing
+          await sealAnswer(battleRoom, round, playerB, answerB, saltB);
+          await revealPlayerAnswer(battleRoom, round, playerA, answerA, saltA);
+          await revealPlayerAnswer(battleRoom, round, playerB, answerB, saltB);
+          await revealAnswerKey(battleRoom, round);
 
-        // Check battle state after damage processing
-        try {
-          const battleRoomAfter = await ephemeralProgram.account.battleRoom.fetch(battleRoomPda);
+          const room = await ephemeralProgram.account.battleRoom.fetch(battleRoom.pda);
           const warriorA = await ephemeralProgram.account.undeadWarrior.fetch(warriorAPda);
           const warriorB = await ephemeralProgram.account.undeadWarrior.fetch(warriorBPda);
-          
-          console.log(`  📊 After Q${round + 1} Results:`);
-          console.log(`    Battle State: ${JSON.stringify(battleRoomAfter.state)}`);
-          console.log(`    Current Question: ${battleRoomAfter.currentQuestion}`);
-          console.log(`    Scores: A=${battleRoomAfter.playerACorrect}, B=${battleRoomAfter.playerBCorrect}`);
-          console.log(`    HP Status:`);
-          console.log(`      ${warriorA.name}: ${warriorA.currentHp}/${warriorA.maxHp} HP`);
-          console.log(`      ${warriorB.name}: ${warriorB.currentHp}/${warriorB.maxHp} HP`);
-          
-          // Calculate expected damage based on answers
-          const playerACorrect = correctAnswers[round];
-          const playerBCorrect = !correctAnswers[round];
-          
-          console.log(`  🎯 Expected Battle Results:`);
-          console.log(`    Player A (${playerACorrect ? "CORRECT" : "WRONG"}): ${playerACorrect ? "Should damage " + warriorB.name : "No damage"}`);
-          console.log(`    Player B (${playerBCorrect ? "CORRECT" : "WRONG"}): ${playerBCorrect ? "Should damage " + warriorA.name : "No damage"}`);
-          
-          // Detect HP changes from previous round
-          if (round > 0) {
-            const prevWarriorA = initialWarriorA;
-            const prevWarriorB = initialWarriorB;
-            
-            if (warriorA.currentHp < prevWarriorA.currentHp) {
-              const damage = prevWarriorA.currentHp - warriorA.currentHp;
-              console.log(`  🩸 DAMAGE DETECTED! ${warriorA.name} took ${damage} damage!`);
-            }
-            if (warriorB.currentHp < prevWarriorB.currentHp) {
-              const damage = prevWarriorB.currentHp - warriorB.currentHp;
-              console.log(`  🩸 DAMAGE DETECTED! ${warriorB.name} took ${damage} damage!`);
-            }
-          }
-          
-          // Update references for next round
-          initialWarriorA = warriorA;
-          initialWarriorB = warriorB;
-          
-          // Check if battle ended
-          const currentState = Object.keys(battleRoomAfter.state)[0];
-          if (currentState === 'completed') {
+          console.log(`  📊 Scores: A=${room.playerACorrect}, B=${room.playerBCorrect}`);
+          console.log(`  💗 HP: ${warriorA.name}=${warriorA.currentHp}, ${warriorB.name}=${warriorB.currentHp}`);
+          expect(room.revealedAnswers[round]).to.equal(q.answer);
+
+          if (Object.keys(room.state)[0] === "completed") {
             console.log("\n🏁 ================ BATTLE COMPLETED ================");
-            console.log(`🏆 Winner: ${battleRoomAfter.winner ? battleRoomAfter.winner.toString().slice(0, 8) + '...' : 'Tie/No Winner'}`);
-            console.log(`⏱️ Battle Duration: ${battleRoomAfter.battleDuration} seconds`);
-            console.log(`📊 Final Scores: A=${battleRoomAfter.playerACorrect}, B=${battleRoomAfter.playerBCorrect}`);
-            console.log(`💗 Final HP: ${warriorA.name}=${warriorA.currentHp}, ${warriorB.name}=${warriorB.currentHp}`);
-            
-            if (warriorA.currentHp === 0) {
-              console.log(`💀 ${warriorA.name} was eliminated!`);
-            } else if (warriorB.currentHp === 0) {
-              console.log(`💀 ${warriorB.name} was eliminated!`);
-            } else {
-              console.log(`🏆 Winner determined by HP advantage`);
-            }
-            break;
-          } else if (warriorA.currentHp === 0 || warriorB.currentHp === 0) {
-            console.log("\n🏁 Battle ended early - warrior eliminated!");
-            console.log(`💀 ${warriorA.currentHp === 0 ? warriorA.name : warriorB.name} was defeated!`);
             break;
           }
-          
-          // Show progress
-          const progressBar = "█".repeat(round + 1) + "░".repeat(9 - round);
-          console.log(`  📈 Progress: [${progressBar}] ${round + 1}/10`);
-          
-        } catch (fetchError) {
-          console.log("  ⚠️ Could not fetch battle state - continuing...", fetchError.message);
         }
-        
-      } catch (roundError) {
-        console.log(`  ❌ Round ${round + 1} failed:`, roundError.message);
-        
-        // Handle specific error cases
-        if (roundError.message.includes("AlreadyAnswered")) {
-          console.log("  ℹ️  Answer already submitted for this question - continuing...");
-          continue;
-        } else if (roundError.message.includes("InvalidBattleState")) {
-          console.log("  ℹ️  Battle state changed - checking final results...");
-          break;
-        } else if (roundError.message.includes("AllQuestionsAnswered")) {
-          console.log("  ℹ️  All questions completed - checking final results...");
-          break;
-        }
-        
-        console.log("  ℹ️  Stopping battle simulation due to unexpected error...");
-        break;
-      }
-    }
-
-    // Final battle state check
-    try {
-      const finalBattleRoom = await ephemeralProgram.account.battleRoom.fetch(battleRoomPda);
-      const finalWarriorA = await ephemeralProgram.account.undeadWarrior.fetch(warriorAPda);
-      const finalWarriorB = await ephemeralProgram.account.undeadWarrior.fetch(warriorBPda);
-      
-      console.log("\n🎉 ================ FINAL BATTLE RESULTS ================");
-      console.log(`🏆 Winner: ${finalBattleRoom.winner ? finalBattleRoom.winner.toString() : 'No winner yet'}`);
-      console.log(`📊 Final Scores: A=${finalBattleRoom.playerACorrect}, B=${finalBattleRoom.playerBCorrect}`);
-      console.log(`💗 Final HP: ${finalWarriorA.name}=${finalWarriorA.currentHp}/${finalWarriorA.maxHp}, ${finalWarriorB.name}=${finalWarriorB.currentHp}/${finalWarriorB.maxHp}`);
-      console.log(`⏱️ Battle Duration: ${finalBattleRoom.battleDuration} seconds`);
-      console.log(`❓ Questions Completed: ${finalBattleRoom.currentQuestion}/10`);
-      console.log(`🔥 Battle State: ${JSON.stringify(finalBattleRoom.state)}`);
-      
-      if (finalWarriorA.experiencePoints > 0 || finalWarriorB.experiencePoints > 0) {
-        console.log(`🌟 Experience Gained:`);
-        console.log(`   ${finalWarriorA.name}: ${finalWarriorA.experiencePoints} XP`);
-        console.log(`   ${finalWarriorB.name}: ${finalWarriorB.experiencePoints} XP`);
-      }
-      
-    } catch (finalError) {
-      console.log("⚠️ Could not fetch final battle state:", finalError.message);
-    }
-
-    console.log("\n✅ Complete real-time battle simulation finished on ER");
-    console.log("🎉 Magic Block Ephemeral Rollup integration working with deterministic damage!");
-    console.log("⚔️ Ready for mainnet settlement and NFT updates!");
-    
-  } catch (error) {
-    console.error("❌ Error in complete ER battle:", error);
-    throw error; // Re-throw to fail the test properly
-  }
-});
 
-    it("Settle Battle with XP Rewards on ER", async () => {
-      try {
-        console.log("💎 Settling battle with XP rewards on Ephemeral Rollup...");
-        
-        const txCommitSgn = await sendERTransaction(
-          ephemeralProgram,
-          ephemeralProgram.methods.settleBattleRoom(roomId)
-            .accountsPartial({
-              authority: authority.publicKey,
-              battleRoom: battleRoomPda,
-              warriorA: warriorAPda,
-              warriorB: warriorBPda,
-            }),
-            authority,
-          providerEphemeralRollup,
-          "Settle Battle with XP",
-        );
-        
-        console.log("✅ Battle settled with XP rewards:", txCommitSgn);
-        
-        // Wait for settlement to complete
-        await new Promise(resolve => setTimeout(resolve, 3000));
-        
-        // Verify XP was awarded
-        try {
-          const finalWarriorA = await ephemeralProgram.account.undeadWarrior.fetch(warriorAPda);
-          const finalWarriorB = await ephemeralProgram.account.undeadWarrior.fetch(warriorBPda);
-          
-          console.log("💎 Post-Settlement XP Status:");
-          console.log(`   ${finalWarriorA.name}: ${finalWarriorA.experiencePoints} XP (Battles: ${finalWarriorA.battlesWon}W/${finalWarriorA.battlesLost}L)`);
-          console.log(`   ${finalWarriorB.name}: ${finalWarriorB.experiencePoints} XP (Battles: ${finalWarriorB.battlesWon}W/${finalWarriorB.battlesLost}L)`);
-          
-          // Verify XP was actually awarded
-          expect(finalWarriorA.experiencePoints).to.be.greaterThan(0);
-          expect(finalWarriorB.experiencePoints).to.be.greaterThan(0);
-          
-        } catch (fetchError) {
-          console.log("⚠️ Could not verify XP awards:", fetchError.message);
-        }
-        
+        const finalRoom = await ephemeralProgram.account.battleRoom.fetch(battleRoom.pda);
+        expect(finalRoom.state).to.deep.equal({ completed: {} });
+        expect(finalRoom.winner.toString()).to.equal(playerA.publicKey.toString());
+        console.log(`🏆 Winner: ${finalRoom.winner.toString()}`);
       } catch (error) {
-        console.error("Error settling battle with XP:", error);
-        console.log("⚠️ Settlement may require completed battle state");
+        console.error("❌ Error in complete ER battle:", error);
+        throw error;
       }
     });
 
-    it("Undelegate Battle Results to Base Layer", async () => {
+    it("Settle and undelegate battle results", async () => {
       try {
-        console.log("🔄 Undelegating battle results back to Solana...");
-        
-        const txCommitSgn = await sendERTransaction(
-          ephemeralProgram,
-          ephemeralProgram.methods.undelegateBattleRoom(roomId) // Updated function name
-            .accountsPartial({
-              authority: authority.publicKey,
-              battleRoom: battleRoomPda,
-              warriorA: warriorAPda,
-              warriorB: warriorBPda,
-            }),
+        await settleAndUndelegate(battleRoom);
+
+        // Settling twice on the rollup is refused
+        await expectProgramError(
+          sendERTransaction(
+            ephemeralProgram,
+            settleBuilder(battleRoom),
             authority,
-          providerEphemeralRollup,
-          "Undelegate Battle Room",
+            providerEphemeralRollup,
+            "Settle Battle again",
+          ),
+          "BattleAlreadySettled",
+          "InvalidBattleState",
+          "AccountNotDelegated",
+          "InvalidWritableAccount"
         );
-        
-        console.log("✅ Undelegation transaction sent:", txCommitSgn);
-        console.log("✅ Battle results committed back to Solana mainnet");
-        
-        // EXTENDED WAIT for Magic Block ownership transfer to complete
-        console.log("⏳ Waiting for Magic Block ownership transfer to complete...");
-        await new Promise(resolve => setTimeout(resolve, 20000)); // 20 seconds wait
-        
-        // Verify accounts are back under program ownership
-        try {
-          const battleRoomInfo = await provider.connection.getAccountInfo(battleRoomPda);
-          const warriorAInfo = await provider.connection.getAccountInfo(warriorAPda);
-          const warriorBInfo = await provider.connection.getAccountInfo(warriorBPda);
-          
-          console.log("📊 Account Ownership After Undelegation:");
-          console.log(`  Battle Room owner: ${battleRoomInfo?.owner.toString()}`);
-          console.log(`  Warrior A owner: ${warriorAInfo?.owner.toString()}`);
-          console.log(`  Warrior B owner: ${warriorBInfo?.owner.toString()}`);
-          console.log(`  Expected Program ID: ${program.programId.toString()}`);
-          
-          // Check if still owned by delegation program
-          const delegationProgramId = "DELeGGvXpWV2fqJUhqcF5ZSYMS4JTLjteaAMARRSaeSh";
-          if (battleRoomInfo?.owner.toString() === delegationProgramId) {
-            console.log("⚠️  Accounts still delegated - waiting longer...");
-            await new Promise(resolve => setTimeout(resolve, 30000)); // Additional 30 seconds
-          } else {
-            console.log("✅ Accounts are back under program ownership");
-          }
-          
-        } catch (checkError) {
-          console.log("ℹ️  Could not verify account ownership, proceeding...");
-        }
-        
+
+        await waitForUndelegation([battleRoom.pda, warriorAPda, warriorBPda]);
       } catch (error) {
-        console.error("Error undelegating battle:", error);
-        console.log("⚠️  Undelegation may need more time or different approach");
-        
-        // Don't throw error - let the test continue to see if update_final_state works later
-        console.log("ℹ️  Continuing test to check if accounts become available later...");
+        console.error("Error settling battle:", error);
+        throw error;
       }
     });
   });
 
   describe("Final State Update on Base Layer", () => {
-    it("Update Final State on Mainnet", async () => {
+    it("Update Final State on base layer", async () => {
       try {
-        console.log("📊 Updating final state after undelegation...");
-        
-        // Additional wait at the start of this test
-        console.log("⏳ Extra wait to ensure undelegation is fully complete...");
-        await new Promise(resolve => setTimeout(resolve, 15000)); // Extra 15 seconds
-        
-        // Check account ownership before proceeding with retry logic
-        let retryCount = 0;
-        const maxRetries = 6; // Increased retries
-        
-        while (retryCount < maxRetries) {
-          try {
-            const battleRoomInfo = await provider.connection.getAccountInfo(battleRoomPda);
-            
-            if (battleRoomInfo?.owner.toString() === program.programId.toString()) {
-              console.log("✅ Accounts are back under program ownership");
-              break;
-            } else {
-              console.log(`⏳ Retry ${retryCount + 1}/${maxRetries} - accounts still delegated, waiting...`);
-              console.log(`   Current owner: ${battleRoomInfo?.owner.toString()}`);
-              console.log(`   Expected owner: ${program.programId.toString()}`);
-              await new Promise(resolve => setTimeout(resolve, 15000)); // Wait 15 more seconds
-              retryCount++;
-            }
-          } catch (fetchError) {
-            console.log(`⚠️  Could not fetch account info, retry ${retryCount + 1}/${maxRetries}`);
-            retryCount++;
-            await new Promise(resolve => setTimeout(resolve, 10000));
-          }
-        }
-        
-        if (retryCount >= maxRetries) {
-          console.log("⚠️  Max retries reached - accounts may still be delegated");
-          console.log("ℹ️  Skipping final state update");
-          return;
-        }
-        
-        // Now try to fetch battle room state
-        let battleRoom;
-        try {
-          battleRoom = await program.account.battleRoom.fetch(battleRoomPda);
-          console.log(`Current battle state: ${JSON.stringify(battleRoom.state)}`);
-        } catch (error) {
-          console.log("⚠️ Could not fetch battle room - may still be delegated");
-          console.log("ℹ️  Skipping final state update");
-          return;
-        }
-        
-        // Only proceed if battle is completed
-        const currentState = Object.keys(battleRoom.state)[0];
-        if (currentState !== 'completed') {
-          console.log("⚠️ Battle not completed - skipping final state update");
-          return;
-        }
-        
-        console.log("🔄 Updating final state on Solana mainnet...");
-        
-        const tx = await program.methods
-          .updateFinalState(roomId)
-          .accountsPartial({
-            authority: authority.publicKey,
-            battleRoom: battleRoomPda,
-            warriorA: warriorAPda,
-            warriorB: warriorBPda,
-            profileA: userProfileAPda,
-            profileB: userProfileBPda,
-            achievementsA: userAchievementsAPda,
-            achievementsB: userAchievementsBPda,
-            config: configPda,
-            leaderboard: leaderboardPda,
-          })
-          .signers([authority])
-          .rpc();
+        const profileABefore = await program.account.userProfile.fetch(userProfileAPda);
 
+        const tx = await updateFinalStateBuilder(battleRoom).rpc();
         console.log("Update Final State transaction signature:", tx);
         console.log("Explorer link:", `https://explorer.solana.com/tx/${tx}?cluster=devnet`);
 
-        // Verify final state updates
-        const updatedBattleRoom = await program.account.battleRoom.fetch(battleRoomPda);
-        const configAccount = await program.account.config.fetch(configPda);
+        const room = await program.account.battleRoom.fetch(battleRoom.pda);
         const profileA = await program.account.userProfile.fetch(userProfileAPda);
-        const profileB = await program.account.userProfile.fetch(userProfileBPda);
-        
-        console.log("📈 Final State Results:");
-        console.log(`  Winner: ${updatedBattleRoom.winner ? updatedBattleRoom.winner.toString().slice(0, 8) + '...' : 'None'}`);
-        console.log(`  Total Battles in Config: ${configAccount.totalBattles}`);
-        console.log(`  Battle Duration: ${updatedBattleRoom.battleDuration}s`);
-        console.log(`  Player A Total Battles: ${profileA.totalBattlesFought}`);
-        console.log(`  Player B Total Battles: ${profileB.totalBattlesFought}`);
-        console.log(`  Player A Points: ${profileA.totalPoints}`);
-        console.log(`  Player B Points: ${profileB.totalPoints}`);
+        const warriorA = await program.account.undeadWarrior.fetch(warriorAPda);
+        const warriorB = await program.account.undeadWarrior.fetch(warriorBPda);
+
+        expect(room.settlement).to.deep.equal({ settled: {} });
+        expect(profileA.totalBattlesWon).to.equal(profileABefore.totalBattlesWon + 1);
+        expect(warriorA.currentRoom).to.be.null;
+        expect(warriorB.currentRoom).to.be.null;
 
         console.log("✅ Final state updated successfully on devnet");
-        
       } catch (error) {
         console.error("Error updating final state:", error);
-        
-        // If it's still an ownership error, provide helpful info
-        if (error.message.includes("instruction modified data of an account it does not own")) {
-          console.log("💡 The accounts may still be delegated to Magic Block");
-          console.log("💡 Try increasing the wait time or checking account ownership");
-          console.log("💡 This is expected behavior during the transition period");
-        }
+        throw error;
       }
     });
 
-    it("Verify Warrior Cooldowns and Stats", async () => {
-      try {
-        // Wait a bit more to ensure we can read the accounts
-        await new Promise(resolve => setTimeout(resolve, 5000));
-        
-        const warriorA = await program.account.undeadWarrior.fetch(warriorAPda);
-        const warriorB = await program.account.undeadWarrior.fetch(warriorBPda);
+    it("Refuses to settle the same battle twice", async () => {
+      const profileBefore = await program.account.userProfile.fetch(userProfileAPda);
 
-        console.log("⚔️ Post-Battle Warrior Status:");
-        console.log(`  ${warriorA.name}: HP=${warriorA.currentHp}/${warriorA.maxHp}, Wins=${warriorA.battlesWon}, Losses=${warriorA.battlesLost}`);
-        console.log(`  ${warriorB.name}: HP=${warriorB.currentHp}/${warriorB.maxHp}, Wins=${warriorB.battlesWon}, Losses=${warriorB.battlesLost}`);
+      await expectProgramError(
+        updateFinalStateBuilder(battleRoom).rpc(),
+        "BattleAlreadySettled",
+        "InvalidBattleState"
+      );
 
-        console.log("📊 Cooldown Status:");
-        console.log(`  ${warriorA.name} cooldown: ${warriorA.cooldownExpiresAt.toNumber()}`);
-        console.log(`  ${warriorB.name} cooldown: ${warriorB.cooldownExpiresAt.toNumber()}`);
+      const profileAfter = await program.account.userProfile.fetch(userProfileAPda);
+      expect(profileAfter.totalBattlesFought).to.equal(profileBefore.totalBattlesFought);
+      expect(profileAfter.totalPoints.toString()).to.equal(profileBefore.totalPoints.toString());
+    });
 
-        console.log("💎 Experience Points:");
-        console.log(`  ${warriorA.name}: ${warriorA.experiencePoints} XP`);
-        console.log(`  ${warriorB.name}: ${warriorB.experiencePoints} XP`);
+    it("Ranks the winner on every leaderboard", async () => {
+      for (const [label, board] of [
+        ["Points", leaderboardPda],
+        ["Rating", ratingLeaderboardPda],
+        ["Season", seasonLeaderboardPda],
+      ] as const) {
+        const rank = await queryRank(board, playerA.publicKey);
+        console.log(`📈 ${label} rank of Player A: #${rank}`);
+        expect(rank).to.be.a("number");
+        expect(rank).to.be.greaterThan(0);
+      }
 
-        // Only verify cooldown if battle was completed (i.e., if there were actual battle outcomes)
-        if (warriorA.battlesWon > 0 || warriorA.battlesLost > 0 || warriorB.battlesWon > 0 || warriorB.battlesLost > 0) {
-          expect(warriorA.cooldownExpiresAt.toNumber()).to.be.greaterThan(0);
-          expect(warriorB.cooldownExpiresAt.toNumber()).to.be.greaterThan(0);
-          console.log("✅ Warrior cooldowns verified (battle completed)");
-        } else {
-          console.log("ℹ️  No battles completed - cooldowns remain at 0 (expected)");
-          console.log("✅ Warrior stats verified (no battles fought)");
-        }
+      const stranger = Keypair.generate().publicKey;
+      expect(await queryRank(leaderboardPda, stranger)).to.be.null;
+    });
 
-      } catch (error) {
-        console.error("Error verifying warrior status:", error);
-      }
+    it("Close the finished room", async () => {
+      const tx = await program.methods
+        .closeBattleRoom(battleRoom.roomId)
+        .accountsPartial({
+          authority: authority.publicKey,
+          rentPayer: playerA.publicKey,
+          battleRoom: battleRoom.pda,
+          historyA: null,
+          historyB: null,
+        })
+        .signers([authority])
+        .rpc();
+      console.log(`🧹 Battle room closed: ${tx}`);
+      expect(await provider.connection.getAccountInfo(battleRoom.pda)).to.be.null;
     });
   });
 
-  describe("Battle Room Management", () => {
-    it("Verify Battle Flow Completion", async () => {
+  describe("Pause", () => {
+    it("Blocks new rooms while paused", async () => {
+      await program.methods
+        .pauseGame()
+        .accountsPartial({ admin: authority.publicKey, config: configPda })
+        .signers([authority])
+        .rpc();
+
       try {
-        console.log("🏆 Verifying complete battle flow...");
-        
-        // Wait a bit more to ensure we can read the accounts
-        await new Promise(resolve => setTimeout(resolve, 5000));
-        
-        // Check final battle room state
-        const battleRoom = await program.account.battleRoom.fetch(battleRoomPda);
-        console.log(`Final battle state: ${JSON.stringify(battleRoom.state)}`);
-        
-        // Check warrior states
-        const warriorA = await program.account.undeadWarrior.fetch(warriorAPda);
-        const warriorB = await program.account.undeadWarrior.fetch(warriorBPda);
-        
-        console.log("⚔️ Final Warrior Status:");
-        console.log(`  ${warriorA.name}: HP=${warriorA.currentHp}/${warriorA.maxHp}, Battles: ${warriorA.battlesWon}W/${warriorA.battlesLost}L, XP: ${warriorA.experiencePoints}`);
-        console.log(`  ${warriorB.name}: HP=${warriorB.currentHp}/${warriorB.maxHp}, Battles: ${warriorB.battlesWon}W/${warriorB.battlesLost}L, XP: ${warriorB.experiencePoints}`);
-        
-        // Verify delegation was successful
-        expect(battleRoom.playerA.toString()).to.equal(playerA.publicKey.toString());
-        expect(battleRoom.playerB.toString()).to.equal(playerB.publicKey.toString());
-        
-        console.log("✅ Battle flow verification completed");
-        console.log("🚀 Magic Block delegation and ER integration working correctly");
-        
-      } catch (error) {
-        console.error("Error verifying battle flow:", error);
-        console.log("⚠️  This may be expected if accounts are still in transition");
+        await expectProgramError(createRoom(newRoomContent(5), new anchor.BN(0)), "GamePaused");
+        await expectProgramError(
+          createWarrior(playerA, `Paused ${Date.now() % 100000}`, { oracle: {} }, false),
+          "GamePaused"
+        );
+      } finally {
+        await program.methods
+          .unpauseGame()
+          .accountsPartial({ admin: authority.publicKey, config: configPda })
+          .signers([authority])
+          .rpc();
       }
+
+      const configAccount = await program.account.config.fetch(configPda);
+      expect(configAccount.isPaused).to.be.false;
     });
 
-    it("Check Account Ownership After Delegation", async () => {
-      try {
-        console.log("🔍 Checking account ownership after Magic Block delegation...");
-        
-        // Final wait to ensure everything is settled
-        await new Promise(resolve => setTimeout(resolve, 5000));
-        
-        // Check who owns the warrior accounts now
-        const accountInfoA = await provider.connection.getAccountInfo(warriorAPda);
-        const accountInfoB = await provider.connection.getAccountInfo(warriorBPda);
-        const battleRoomInfo = await provider.connection.getAccountInfo(battleRoomPda);
-        
-        console.log("📊 Account Ownership Status:");
-        console.log(`  Warrior A owner: ${accountInfoA?.owner.toString()}`);
-        console.log(`  Warrior B owner: ${accountInfoB?.owner.toString()}`);
-        console.log(`  Battle Room owner: ${battleRoomInfo?.owner.toString()}`);
-        console.log(`  Your Program ID: ${program.programId.toString()}`);
-        console.log(`  Magic Block Delegation ID: DELeGGvXpWV2fqJUhqcF5ZSYMS4JTLjteaAMARRSaeSh`);
-        
-        // Check if accounts are back under program ownership
-        if (accountInfoA?.owner.toString() === program.programId.toString()) {
-          console.log("✅ Warriors successfully returned to program ownership!");
-          console.log("🎯 This demonstrates successful undelegation flow");
-        } else if (accountInfoA?.owner.toString() === "DELeGGvXpWV2fqJUhqcF5ZSYMS4JTLjteaAMARRSaeSh") {
-          console.log("ℹ️  Warriors still delegated to Magic Block");
-          console.log("🔄 Undelegation may need more time to complete");
-        } else {
-          console.log("ℹ️  Warriors have different ownership state");
-        }
-        
-        console.log("✅ Account ownership verification completed");
-        
-      } catch (error) {
-        console.error("Error checking account ownership:", error);
-        console.log("⚠️  This may be expected during ownership transition");
+    it("Only the admin can pause", async () => {
+      await expectProgramError(
+        program.methods
+          .pauseGame()
+          .accountsPartial({ admin: playerA.publicKey, config: configPda })
+          .signers([playerA])
+          .rpc(),
+        "NotAuthorized"
+      );
+    });
+
+    it("Keeps the idle timeout longer than the answer timeout", async () => {
+      const configAccount = await program.account.config.fetch(configPda);
+      await expectProgramError(
+        updateConfig({ idleTimeout: configAccount.answerTimeout }),
+        "IdleTimeoutTooShort"
+      );
+    });
+  });
+
+  describe("Wagered battle won by timeout", () => {
+    const answerTimeout = 5;
+
+    before(async () => {
+      await updateConfig({ answerTimeout: new anchor.BN(answerTimeout) });
+      // give the rollup a moment to pick up the new config
+      await sleep(5000);
+      await healIfNeeded(playerA, warriorAPda);
+      await healIfNeeded(playerB, warriorBPda);
+    });
+
+    it("Both players stake the wager", async () => {
+      const escrowPda = pda.deriveWagerEscrowSeedPDA({ roomId: timeoutRoom.roomId }, program.programId)[0];
+
+      await createRoom(timeoutRoom, wagerAmount);
+      await verifyRoomQuestions(timeoutRoom);
+      await joinRoom(timeoutRoom, true);
+
+      const room = await program.account.battleRoom.fetch(timeoutRoom.pda);
+      expect(room.wagerAmount.toString()).to.equal(wagerAmount.toString());
+      expect(room.wagerStatus).to.deep.equal({ escrowed: {} });
+
+      const escrowBalance = await provider.connection.getBalance(escrowPda);
+      expect(escrowBalance).to.be.at.least(2 * wagerAmount.toNumber());
+      console.log(`💰 Escrow holds ${(escrowBalance / LAMPORTS_PER_SOL).toFixed(4)} SOL`);
+    });
+
+    it("Warriors in a room can't be transferred or retired", async () => {
+      await expectProgramError(
+        program.methods
+          .transferWarrior(playerB.publicKey)
+          .accountsPartial({
+            owner: playerA.publicKey,
+            warrior: warriorAPda,
+            previousLookup: null,
+            newLookup: pda.deriveWarriorLookupSeedPDA({ owner: playerB.publicKey, warrior: warriorAPda }, program.programId)[0],
+            matchQueue: null,
+            config: configPda,
+          })
+          .signers([playerA])
+          .rpc(),
+        "WarriorInRoom"
+      );
+
+      await expectProgramError(
+        program.methods
+          .retireWarrior()
+          .accountsPartial({
+            owner: playerA.publicKey,
+            warrior: warriorAPda,
+            warriorNftAccount: null,
+            nftMint: null,
+            nameRegistry: pda.deriveNameRegistrySeedPDA({ name: warriorAName }, program.programId)[0],
+            lookup: null,
+            matchQueue: null,
+            history: null,
+            graveyard: pda.deriveGraveyardSeedPDA({ owner: playerA.publicKey }, program.programId)[0],
+            config: configPda,
+            tokenProgram: null,
+          })
+          .signers([playerA])
+          .rpc(),
+        "WarriorInRoom"
+      );
+    });
+
+    it("The absent player forfeits once the question times out", async () => {
+      await signalReady(timeoutRoom, playerA, warriorAPda);
+      await signalReady(timeoutRoom, playerB, warriorBPda);
+      await delegateRoom(timeoutRoom);
+      await sleep(5000);
+      await startBattle(timeoutRoom);
+
+      // Only Player A answers the first question
+      const q = timeoutRoom.questions[0];
+      await sealAnswer(timeoutRoom, 0, playerA, q.answer, randomBytes(32));
+
+      const claimTimeout = () => sendERTransaction(
+        ephemeralProgram,
+        ephemeralProgram.methods
+          .claimTimeout(timeoutRoom.roomId)
+          .accountsPartial({
+            caller: authority.publicKey,
+            battleRoom: timeoutRoom.pda,
+            warriorA: warriorAPda,
+            warriorB: warriorBPda,
+            program: program.programId,
+          }),
+        authority,
+        providerEphemeralRollup,
+        "Claim Timeout",
+      );
+
+      const room = await ephemeralProgram.account.battleRoom.fetch(timeoutRoom.pda);
+      const now = Math.floor(Date.now() / 1000);
+      if (now <= room.questionDeadline.toNumber()) {
+        await expectProgramError(claimTimeout(), "DeadlineNotReached");
       }
+
+      await sleep((answerTimeout + 3) * 1000);
+      await claimTimeout();
+
+      const finished = await ephemeralProgram.account.battleRoom.fetch(timeoutRoom.pda);
+      expect(finished.state).to.deep.equal({ completed: {} });
+      expect(finished.winner.toString()).to.equal(playerA.publicKey.toString());
+      console.log("🏳️ Player B forfeited, Player A wins");
+
+      await settleAndUndelegate(timeoutRoom);
+      await waitForUndelegation([timeoutRoom.pda, warriorAPda, warriorBPda]);
+      await updateFinalStateBuilder(timeoutRoom).rpc();
+    });
+
+    it("Pays the pot to the winner minus the protocol fee", async () => {
+      const configAccount = await program.account.config.fetch(configPda);
+      const pot = 2 * wagerAmount.toNumber();
+      const fee = Math.floor((pot * configAccount.wagerFeeBps) / 10_000);
+
+      const settleWager = () => program.methods
+        .settleWager(timeoutRoom.roomId)
+        .accountsPartial({
+          authority: authority.publicKey,
+          battleRoom: timeoutRoom.pda,
+          winner: playerA.publicKey,
+          creator: playerA.publicKey,
+          config: configPda,
+          treasury,
+          wagerEscrow: pda.deriveWagerEscrowSeedPDA({ roomId: timeoutRoom.roomId }, program.programId)[0],
+          wagerVault: null,
+          winnerTokenAccount: null,
+          treasuryTokenAccount: null,
+          tokenProgram: null,
+        })
+        .signers([authority])
+        .rpc();
+
+      const winnerBefore = await provider.connection.getBalance(playerA.publicKey);
+      const tx = await settleWager();
+      console.log(`💰 Wager settled: ${tx}`);
+      const winnerAfter = await provider.connection.getBalance(playerA.publicKey);
+
+      // The escrow's rent goes back to the creator, who is also the winner here
+      expect(winnerAfter - winnerBefore).to.be.at.least(pot - fee);
+      const room = await program.account.battleRoom.fetch(timeoutRoom.pda);
+      expect(room.wagerStatus).to.deep.equal({ paid: {} });
+
+      // The pot can't be paid out twice
+      await expectProgramError(settleWager(), "WagerNotEscrowed", "AccountNotInitialized");
     });
   });
 
-  describe("Performance Benefits Demonstration", () => {
-    it("Magic Block Ephemeral Rollups Benefits", async () => {
-      console.log("\n=== MAGIC BLOCK EPHEMERAL ROLLUPS BENEFITS ===");
-      console.log("🚀 Ultra-low latency for real-time battle gameplay");
-      console.log("⛽ Gasless transactions within the rollup session");
-      console.log("🎲 VRF integration for provably fair randomness");
-      console.log("🔗 Seamless composability with Solana ecosystem");
-      console.log("💰 Cost-effective for high-frequency interactions");
-      console.log("⚔️ Perfect for turn-based strategy games");
-      console.log("🎮 Real-time multiplayer gaming experience");
-      console.log("🔄 Automatic state commitment back to mainnet");
-      console.log("🌐 Running on Solana Devnet for testing");
-      console.log("⏰ Extended wait times for proper undelegation");
-      console.log("💎 XP settlement and reward distribution");
-      console.log("===============================================\n");
+  describe("Wager refund", () => {
+    it("Refunds the stake of a cancelled room", async () => {
+      await healIfNeeded(playerA, warriorAPda);
+      await createRoom(refundRoom, wagerAmount);
+      await healIfNeeded(playerB, warriorBPda);
+      await verifyRoomQuestions(refundRoom);
+      await joinRoom(refundRoom, true);
+
+      const cancelRoom = (player: Keypair, warriorB: PublicKey | null) => program.methods
+        .cancelBattle(refundRoom.roomId)
+        .accountsPartial({
+          player: player.publicKey,
+          battleRoom: refundRoom.pda,
+          warriorA: warriorAPda,
+          warriorB,
+          config: configPda,
+        })
+        .signers([player])
+        .rpc();
+
+      // The opponent has to wait out the idle timeout, and its warrior has to come along
+      await expectProgramError(cancelRoom(playerB, warriorBPda), "RoomNotIdle");
+      await expectProgramError(cancelRoom(playerA, null), "InvalidWarrior");
+      await cancelRoom(playerA, warriorBPda);
+
+      const cancelled = await program.account.battleRoom.fetch(refundRoom.pda);
+      expect(cancelled.state).to.deep.equal({ cancelled: {} });
+
+      const refundWager = () => program.methods
+        .refundWager(refundRoom.roomId)
+        .accountsPartial({
+          authority: authority.publicKey,
+          battleRoom: refundRoom.pda,
+          playerA: playerA.publicKey,
+          playerB: playerB.publicKey,
+          wagerEscrow: pda.deriveWagerEscrowSeedPDA({ roomId: refundRoom.roomId }, program.programId)[0],
+          wagerVault: null,
+          playerATokenAccount: null,
+          playerBTokenAccount: null,
+          tokenProgram: null,
+        })
+        .signers([authority])
+        .rpc();
+
+      const balanceBefore = await provider.connection.getBalance(playerA.publicKey);
+      await refundWager();
+      const balanceAfter = await provider.connection.getBalance(playerA.publicKey);
+      expect(balanceAfter - balanceBefore).to.be.at.least(wagerAmount.toNumber());
+
+      const refunded = await program.account.battleRoom.fetch(refundRoom.pda);
+      expect(refunded.wagerStatus).to.deep.equal({ refunded: {} });
+      await expectProgramError(refundWager(), "WagerNotEscrowed", "AccountNotInitialized");
+
+      const warriorA = await program.account.undeadWarrior.fetch(warriorAPda);
+      expect(warriorA.currentRoom).to.be.null;
+      const warriorB = await program.account.undeadWarrior.fetch(warriorBPda);
+      expect(warriorB.currentRoom).to.be.null;
+      console.log("✅ Stakes refunded and warriors released");
+    });
+  });
+
+  describe("Warrior ownership guards", () => {
+    const retireeName = `Grave Walker ${Date.now() % 100000}`;
+    const renamed = `Bone Walker ${Date.now() % 100000}`;
+    let retireePda: PublicKey;
+
+    before(async () => {
+      retireePda = await createWarrior(playerA, retireeName, { oracle: {} });
+    });
+
+    // retireePda was created by playerA, every other holder keeps a lookup
+    const lookupFor = (holder: PublicKey) => holder.equals(playerA.publicKey)
+      ? null
+      : pda.deriveWarriorLookupSeedPDA({ owner: holder, warrior: retireePda }, program.programId)[0];
+
+    const transferBuilder = (owner: Keypair, newOwner: PublicKey, matchQueue: PublicKey | null = null) => program.methods
+      .transferWarrior(newOwner)
+      .accountsPartial({
+        owner: owner.publicKey,
+        warrior: retireePda,
+        previousLookup: lookupFor(owner.publicKey),
+        newLookup: lookupFor(newOwner),
+        matchQueue,
+        config: configPda,
+      })
+      .signers([owner]);
+
+    it("Refuses a transfer to the current owner", async () => {
+      await expectProgramError(
+        transferBuilder(playerA, playerA.publicKey).rpc(),
+        "InvalidTransferRecipient"
+      );
     });
 
-    it("Test Coverage Summary", async () => {
-      console.log("\n📊 RUST UNDEAD TEST COVERAGE SUMMARY");
-      console.log("=====================================");
-      console.log("✅ FULLY TESTED (Base Layer - Devnet):");
-      console.log("  - initialize");
-      console.log("  - create_warrior (with VRF)");
-      console.log("  - create_battle_room");
-      console.log("  - join_battle_room");
-      console.log("  - signal_ready");
-      
-      console.log("\n✅ TESTED (Ephemeral Rollup):");
-      console.log("  - delegate_battle");
-      console.log("  - start_battle");
-      console.log("  - answer_question (with deterministic damage)");
-      console.log("  - settle_end_game (XP rewards)");
-      console.log("  - undelegate_battle_room (proper timing)");
-      
-      console.log("\n✅ TESTED (Final Settlement):");
-      console.log("  - update_final_state (with ownership checks)");
-      console.log("  - warrior stat verification");
-      console.log("  - cooldown management");
-      console.log("  - battle flow completion");
-      console.log("  - account ownership verification");
-      console.log("  - XP distribution verification");
-      
-      console.log("\n🎯 COVERAGE: 14/15 instructions fully tested");
-      console.log("📝 NOTE: VRF callbacks tested via integration");
-      console.log("🚀 Magic Block ER integration working perfectly");
-      console.log("🔄 Proper transaction patterns for ER usage");
-      console.log("🌐 All tests running on Solana Devnet");
-      console.log("✅ Extended timing for undelegation flow verified");
-      console.log("⏰ 20-50 second wait times for ownership transfer");
-      console.log("💎 XP settlement and reward distribution tested");
-      console.log("🔧 Updated function names matching lib.rs");
-      console.log("=====================================\n");
+    it("Only the owner can transfer", async () => {
+      await expectProgramError(
+        transferBuilder(playerB, playerB.publicKey).rpc(),
+        "NotWarriorOwner",
+        "ConstraintSeeds",
+        "AccountNotInitialized"
+      );
+    });
+
+    it("Records a lookup for the recipient and drops it on return", async () => {
+      const lookupPda = lookupFor(playerB.publicKey)!;
+      await transferBuilder(playerA, playerB.publicKey).rpc();
+
+      const lookup = await program.account.warriorLookup.fetch(lookupPda);
+      expect(lookup.owner.toString()).to.equal(playerB.publicKey.toString());
+      expect(lookup.warrior.toString()).to.equal(retireePda.toString());
+      expect(lookup.creator.toString()).to.equal(playerA.publicKey.toString());
+
+      await transferBuilder(playerB, playerA.publicKey).rpc();
+      expect(await program.account.warriorLookup.fetchNullable(lookupPda)).to.be.null;
+      const warrior = await program.account.undeadWarrior.fetch(retireePda);
+      expect(warrior.owner.toString()).to.equal(playerA.publicKey.toString());
+    });
+
+    it("Takes a queued warrior out of the match queue before a transfer", async () => {
+      const [matchQueuePda] = pda.deriveMatchQueueSeedPDA(program.programId);
+      if (!(await program.account.matchQueue.fetchNullable(matchQueuePda))) {
+        await program.methods
+          .initializeMatchQueue()
+          .accountsPartial({ admin: authority.publicKey, config: configPda, matchQueue: matchQueuePda })
+          .signers([authority])
+          .rpc();
+      }
+
+      await waitForStats(retireePda);
+      await program.methods
+        .enqueueWarrior()
+        .accountsPartial({
+          player: playerA.publicKey,
+          warrior: retireePda,
+          warriorNftAccount: null,
+          config: configPda,
+          matchQueue: matchQueuePda,
+        })
+        .signers([playerA])
+        .rpc();
+      expect((await program.account.undeadWarrior.fetch(retireePda)).queued).to.be.true;
+
+      // Without the queue the lock holds
+      await expectProgramError(transferBuilder(playerA, playerB.publicKey).rpc(), "WarriorQueued");
+
+      await transferBuilder(playerA, playerB.publicKey, matchQueuePda).rpc();
+      const queue = await program.account.matchQueue.fetch(matchQueuePda);
+      expect(queue.entries.some(entry => entry.warrior.equals(retireePda))).to.be.false;
+      expect((await program.account.undeadWarrior.fetch(retireePda)).queued).to.be.false;
+
+      // Hand it back for the tests below
+      await transferBuilder(playerB, playerA.publicKey).rpc();
+    });
+
+    const renameWarrior = (from: string, to: string, claimsNewName: boolean) => program.methods
+      .renameWarrior(to)
+      .accountsPartial({
+        owner: playerA.publicKey,
+        warrior: retireePda,
+        warriorNftAccount: null,
+        nameRegistry: pda.deriveNameRegistrySeedPDA({ name: from }, program.programId)[0],
+        newNameRegistry: claimsNewName ? pda.deriveNameRegistrySeedPDA({ name: to }, program.programId)[0] : null,
+        config: configPda,
+        treasury,
+      })
+      .signers([playerA])
+      .rpc();
+
+    it("Renames for a fee and releases the old name", async () => {
+      await renameWarrior(retireeName, renamed, true);
+
+      const warrior = await program.account.undeadWarrior.fetch(retireePda);
+      expect(warrior.name).to.equal(renamed);
+      expect(
+        await program.account.nameRegistry.fetchNullable(pda.deriveNameRegistrySeedPDA({ name: retireeName }, program.programId)[0])
+      ).to.be.null;
+    });
+
+    it("Rate limits renames", async () => {
+      await expectProgramError(renameWarrior(renamed, `${renamed} II`, true), "RenameOnCooldown");
+    });
+
+    it("Allows a case-only rename without a new claim", async () => {
+      await updateConfig({ renameCooldown: new anchor.BN(0) });
+      try {
+        await renameWarrior(renamed, renamed.toUpperCase(), false);
+      } finally {
+        await updateConfig({ renameCooldown: originalConfig.renameCooldown });
+      }
+
+      const warrior = await program.account.undeadWarrior.fetch(retireePda);
+      expect(warrior.name).to.equal(renamed.toUpperCase());
+      const registry = await program.account.nameRegistry.fetch(
+        pda.deriveNameRegistrySeedPDA({ name: renamed }, program.programId)[0]
+      );
+      expect(registry.name).to.equal(renamed.toUpperCase());
+      expect(registry.warrior.toString()).to.equal(retireePda.toString());
+    });
+
+    it("Retires the warrior into the graveyard and frees its name", async () => {
+      const graveyardPda = pda.deriveGraveyardSeedPDA({ owner: playerA.publicKey }, program.programId)[0];
+      const nameRegistryPda = pda.deriveNameRegistrySeedPDA({ name: renamed }, program.programId)[0];
+
+      await program.methods
+        .retireWarrior()
+        .accountsPartial({
+          owner: playerA.publicKey,
+          warrior: retireePda,
+          warriorNftAccount: null,
+          nftMint: null,
+          nameRegistry: nameRegistryPda,
+          lookup: null,
+          matchQueue: null,
+          history: null,
+          graveyard: graveyardPda,
+          config: configPda,
+          tokenProgram: null,
+        })
+        .signers([playerA])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(retireePda)).to.be.null;
+      expect(await provider.connection.getAccountInfo(nameRegistryPda)).to.be.null;
+
+      const graveyard = await program.account.graveyard.fetch(graveyardPda);
+      const memorial = graveyard.fallen.find(entry => entry.warrior.equals(retireePda));
+      expect(memorial.name).to.equal(renamed.toUpperCase());
+      console.log(`🪦 ${memorial.name} rests in the graveyard (${graveyard.totalFallen} fallen)`);
     });
   });
 
-  after(() => {
+  after(async () => {
+    // Put back the tunables changed above
+    if (originalConfig) {
+      try {
+        await updateConfig({
+          cooldownTime: originalConfig.cooldownTime,
+          answerTimeout: originalConfig.answerTimeout,
+          renameCooldown: originalConfig.renameCooldown,
+        });
+        console.log("⚙️ Config restored");
+      } catch (error) {
+        console.log("⚠️ Could not restore config:", error.message);
+      }
+    }
+
     console.log("\n=== RUST UNDEAD DEVNET TEST SUMMARY ===");
-    console.log("✅ Base layer warrior creation with VRF stats");
-    console.log("✅ Battle room creation and management");
-    console.log("✅ Account delegation to Magic Block ER");
-    console.log("✅ Real-time battle gameplay in ER");
-    console.log("✅ Deterministic damage calculations");
-    console.log("✅ Proper ER transaction patterns");
-    console.log("✅ XP settlement with rewards on ER");
-    console.log("✅ State commitment back to Solana");
-    console.log("✅ Extended wait times for undelegation");
-    console.log("✅ Final state updates and cooldowns");
-    console.log("✅ Battle flow completion verification");
-    console.log("✅ Account ownership after delegation");
-    console.log("✅ Devnet explorer links for verification");
-    console.log("✅ Network-specific error handling");
-    console.log("✅ Proper Magic Block timing patterns");
-    console.log("✅ Updated function names (settleEndGame, undelegateBattleRoom)");
-    console.log("✅ XP distribution and warrior stat updates");
-    console.log("🎯 Complete educational battle game flow!");
-    console.log("🚀 Magic Block Ephemeral Rollups working perfectly!");
-    console.log("🌐 Successfully tested on Solana Devnet");
-    console.log("🔄 Following proper ER transaction patterns");
-    console.log("⚔️ Fresh warriors ready for epic battles!");
-    console.log("⏰ Optimized wait times: 20s undelegation + 15s verification");
-    console.log("💡 Key Learning: Magic Block needs 35+ seconds for ownership transfer");
-    console.log("💎 XP rewards and battle settlement working perfectly");
-    console.log("🔧 All function names updated to match current lib.rs structure");
+    console.log("✅ Config, leaderboards and question bank setup");
+    console.log("✅ Warriors keyed by creator and index, names claimed case-insensitively");
+    console.log("✅ Rooms proven against the question bank merkle root");
+    console.log("✅ Sealed answers, player reveals and answer key reveals on the ER");
+    console.log("✅ Settlement on the ER and final state on the base layer");
+    console.log("✅ Repeat settlement refused");
+    console.log("✅ Leaderboard rank queries");
+    console.log("✅ Pause switch");
+    console.log("✅ Timeout forfeit and wager payout");
+    console.log("✅ Wager refund for cancelled rooms");
+    console.log("✅ Transfer, rename and retire guards");
     console.log("=======================================");
   });
-});
\ No newline at end of file
+});